@@ -18,11 +18,9 @@ use chain::services::{
 use chrono::{NaiveDateTime, Utc};
 use clap::Parser;
 use deadpool_diesel::postgres::Object;
-use diesel::RunQueryDsl;
 use futures::stream::StreamExt;
 use namada_sdk::time::DateTimeUtc;
 use orm::migrations::CustomMigrationSource;
-use orm::schema::{bonds, unbonds};
 use repository::pgf as namada_pgf_repository;
 use shared::balance::TokenSupply;
 use shared::block::Block;
@@ -179,16 +177,11 @@ async fn main() -> Result<(), MainError> {
                         .await
                         .into_rpc_error()?;
                 conn.interact(move |conn| {
-                    diesel::delete(bonds::table).execute(conn)?;
-                    diesel::delete(unbonds::table).execute(conn)?;
                     conn.build_transaction().read_write().run(
                         |transaction_conn| {
-                            repository::pos::insert_bonds(
+                            repository::pos::reindex_bonds_and_unbonds(
                                 transaction_conn,
                                 bonds,
-                            )?;
-                            repository::pos::insert_unbonds(
-                                transaction_conn,
                                 unbonds,
                             )
                         },
@@ -309,6 +302,46 @@ async fn crawling_fn(
     } else {
         vec![]
     };
+    let masp_reward_history = if new_epoch {
+        namada_service::get_masp_reward_history(
+            &client,
+            first_block_in_epoch,
+            epoch,
+        )
+        .await
+        .into_rpc_error()?
+    } else {
+        vec![]
+    };
+    let masp_commitment_tree = if new_epoch {
+        let commitment_tree = namada_service::get_masp_commitment_tree(
+            &client,
+            first_block_in_epoch,
+            epoch,
+        )
+        .await
+        .into_rpc_error()?;
+        Some(commitment_tree)
+    } else {
+        None
+    };
+
+    let (pgf_stewards, pgf_scheduled_payments) = if new_epoch {
+        let stewards =
+            namada_service::get_pgf_stewards(&client, first_block_in_epoch)
+                .await
+                .into_rpc_error()?;
+        let scheduled_payments = namada_service::get_pgf_scheduled_fundings(
+            &client,
+            first_block_in_epoch,
+            epoch,
+        )
+        .await
+        .into_rpc_error()?;
+        (Some(stewards), scheduled_payments)
+    } else {
+        (None, vec![])
+    };
 
     tracing::info!(
         block = block_height,
@@ -323,6 +356,18 @@ async fn crawling_fn(
         .map(Token::Ibc)
         .collect::<Vec<Token>>();
 
+    // Denominations are refreshed for every token once per epoch, and as soon
+    // as they are seen otherwise
+    let denominated_tokens = if new_epoch {
+        query_tokens(&client).await.into_rpc_error()?
+    } else {
+        ibc_tokens.clone()
+    };
+    let token_denominations =
+        namada_service::query_tokens_denomination(&client, &denominated_tokens)
+            .await
+            .into_rpc_error()?;
+
     let native_addresses =
         namada_service::query_native_addresses_balance_change(Token::Native(
             native_token.clone(),
@@ -338,6 +383,20 @@ async fn crawling_fn(
         query_token_supplies(&client, &all_changed_tokens_supply, epoch)
             .await?;
 
+    let native_inflation = if new_epoch {
+        let inflation = namada_service::get_native_inflation(
+            &client,
+            &native_token,
+            first_block_in_epoch,
+            epoch,
+        )
+        .await
+        .into_rpc_error()?;
+        Some(inflation)
+    } else {
+        None
+    };
+
     let validators_addresses = if first_block_in_epoch.eq(&block_height) {
         let previous_epoch = epoch.saturating_sub(1);
         namada_service::get_all_consensus_validators_addresses_at(
@@ -481,6 +540,7 @@ async fn crawling_fn(
     let metadata_change = block.validator_metadata();
 
     let reward_claimers = block.pos_rewards();
+    let redelegation_txs = block.redelegations();
 
     let timestamp_in_sec = DateTimeUtc::now().0.timestamp();
 
@@ -533,11 +593,21 @@ async fn crawling_fn(
                     ibc_tokens,
                 )?;
 
+                repository::balance::update_token_denominations(
+                    transaction_conn,
+                    token_denominations,
+                )?;
+
                 repository::balance::insert_token_supplies(
                     transaction_conn,
                     token_supplies,
                 )?;
 
+                repository::balance::insert_native_inflation(
+                    transaction_conn,
+                    native_inflation,
+                )?;
+
                 repository::balance::insert_ibc_rate_limits(
                     transaction_conn,
                     rate_limits,
@@ -549,6 +619,22 @@ async fn crawling_fn(
                     tm_block_response,
                 )?;
 
+                // The first block of the epoch was just inserted, so the
+                // start of the epoch is recorded with its timestamp
+                if new_epoch {
+                    repository::block::upsert_epoch(
+                        transaction_conn,
+                        epoch,
+                        first_block_in_epoch,
+                    )?;
+                } else {
+                    repository::block::upsert_epoch_if_started(
+                        transaction_conn,
+                        epoch,
+                        block_height,
+                    )?;
+                }
+
                 repository::balance::insert_balances(
                     transaction_conn,
                     balances,
@@ -585,15 +671,21 @@ async fn crawling_fn(
                     transaction_conn,
                     redelegations,
                 )?;
+                repository::pos::insert_redelegation_history(
+                    transaction_conn,
+                    redelegation_txs,
+                )?;
                 repository::pos::remove_withdraws(
                     transaction_conn,
                     epoch,
                     withdraw_addreses,
+                    block_height,
                 )?;
 
                 repository::pos::delete_claimed_rewards(
                     transaction_conn,
                     reward_claimers,
+                    block_height,
                 )?;
 
                 repository::pos::update_validator_metadata(
@@ -611,6 +703,27 @@ async fn crawling_fn(
                     masp_reward_rates,
                 )?;
 
+                repository::masp::insert_masp_reward_history(
+                    transaction_conn,
+                    masp_reward_history,
+                )?;
+
+                repository::masp::insert_masp_commitment_tree(
+                    transaction_conn,
+                    masp_commitment_tree,
+                )?;
+
+                namada_pgf_repository::update_pgf_stewards(
+                    transaction_conn,
+                    pgf_stewards,
+                    epoch,
+                )?;
+
+                namada_pgf_repository::insert_pgf_scheduled_payments(
+                    transaction_conn,
+                    pgf_scheduled_payments,
+                )?;
+
                 if should_update_crawler_state {
                     repository::crawler_state::upsert_crawler_state(
                         transaction_conn,
@@ -683,6 +796,10 @@ async fn try_initial_query(
         get_block(cometbft_block, client, &checksums, &native_token).await?;
 
     let tokens = query_tokens(client).await.into_rpc_error()?;
+    let token_denominations =
+        namada_service::query_tokens_denomination(client, &tokens)
+            .await
+            .into_rpc_error()?;
 
     let rate_limits_fut = async {
         namada_service::get_rate_limits_for_tokens(
@@ -705,6 +822,15 @@ async fn try_initial_query(
     let (rate_limits, token_supplies) =
         futures::try_join!(rate_limits_fut, token_supplies_fut)?;
 
+    let native_inflation = namada_service::get_native_inflation(
+        client,
+        &Id::from(native_token.clone()),
+        first_block_in_epoch,
+        epoch,
+    )
+    .await
+    .into_rpc_error()?;
+
     // This can sometimes fail if the last block height in the node has moved
     // forward after we queried for it. In that case, query_all_balances
     // returns an Err indicating that it can only be used for
@@ -756,9 +882,35 @@ async fn try_initial_query(
     .await
     .into_rpc_error()?;
 
+    let pgf_stewards =
+        namada_service::get_pgf_stewards(client, first_block_in_epoch)
+            .await
+            .into_rpc_error()?;
+    let pgf_scheduled_payments = namada_service::get_pgf_scheduled_fundings(
+        client,
+        first_block_in_epoch,
+        epoch,
+    )
+    .await
+    .into_rpc_error()?;
+
     let masp_reward_rates = namada_service::get_masp_rates(client)
         .await
         .into_rpc_error()?;
+    let masp_reward_history = namada_service::get_masp_reward_history(
+        client,
+        first_block_in_epoch,
+        epoch,
+    )
+    .await
+    .into_rpc_error()?;
+    let masp_commitment_tree = namada_service::get_masp_commitment_tree(
+        client,
+        first_block_in_epoch,
+        epoch,
+    )
+    .await
+    .into_rpc_error()?;
 
     let timestamp = DateTimeUtc::now().0.timestamp();
 
@@ -776,6 +928,10 @@ async fn try_initial_query(
             .read_write()
             .run(|transaction_conn| {
                 repository::balance::insert_tokens(transaction_conn, tokens)?;
+                repository::balance::update_token_denominations(
+                    transaction_conn,
+                    token_denominations,
+                )?;
 
                 repository::block::upsert_block(
                     transaction_conn,
@@ -783,11 +939,22 @@ async fn try_initial_query(
                     tm_block_response,
                 )?;
 
+                repository::block::upsert_epoch(
+                    transaction_conn,
+                    epoch,
+                    first_block_in_epoch,
+                )?;
+
                 repository::balance::insert_token_supplies(
                     transaction_conn,
                     token_supplies,
                 )?;
 
+                repository::balance::insert_native_inflation(
+                    transaction_conn,
+                    Some(native_inflation),
+                )?;
+
                 repository::balance::insert_ibc_rate_limits(
                     transaction_conn,
                     rate_limits,
@@ -830,6 +997,27 @@ async fn try_initial_query(
                     masp_reward_rates,
                 )?;
 
+                repository::masp::insert_masp_reward_history(
+                    transaction_conn,
+                    masp_reward_history,
+                )?;
+
+                repository::masp::insert_masp_commitment_tree(
+                    transaction_conn,
+                    Some(masp_commitment_tree),
+                )?;
+
+                namada_pgf_repository::update_pgf_stewards(
+                    transaction_conn,
+                    Some(pgf_stewards),
+                    epoch,
+                )?;
+
+                namada_pgf_repository::insert_pgf_scheduled_payments(
+                    transaction_conn,
+                    pgf_scheduled_payments,
+                )?;
+
                 repository::crawler_state::upsert_crawler_state(
                     transaction_conn,
                     crawler_state,
@@ -1,15 +1,16 @@
 use anyhow::Context;
-use diesel::{PgConnection, RunQueryDsl};
+use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl};
 use orm::balances::BalanceChangesInsertDb;
 use orm::ibc::IbcRateLimitsInsertDb;
+use orm::native_inflation_per_epoch::NativeInflationInsertDb;
 use orm::schema::{
-    balance_changes, ibc_rate_limits, ibc_token, token,
-    token_supplies_per_epoch,
+    balance_changes, ibc_rate_limits, ibc_token, native_inflation_per_epoch,
+    token, token_supplies_per_epoch,
 };
 use orm::token::{IbcTokenInsertDb, TokenInsertDb};
 use orm::token_supplies_per_epoch::TokenSuppliesInsertDb;
-use shared::balance::{Balances, TokenSupply};
-use shared::token::{IbcRateLimit, Token};
+use shared::balance::{Balances, NativeInflation, TokenSupply};
+use shared::token::{IbcRateLimit, Token, TokenDenomination};
 use shared::tuple_len::TupleLen;
 
 use super::utils::MAX_PARAM_SIZE;
@@ -75,6 +76,41 @@ pub fn insert_tokens(
     anyhow::Ok(())
 }
 
+pub fn update_token_denominations(
+    transaction_conn: &mut PgConnection,
+    denominations: Vec<TokenDenomination>,
+) -> anyhow::Result<()> {
+    for TokenDenomination {
+        address,
+        denomination,
+    } in denominations
+    {
+        diesel::update(token::table.find(address.to_string()))
+            .set(token::columns::denomination.eq(i32::from(denomination)))
+            .execute(transaction_conn)
+            .context("Failed to update token denomination in db")?;
+    }
+
+    anyhow::Ok(())
+}
+
+pub fn insert_native_inflation(
+    transaction_conn: &mut PgConnection,
+    inflation: Option<NativeInflation>,
+) -> anyhow::Result<()> {
+    let Some(inflation) = inflation else {
+        return anyhow::Ok(());
+    };
+
+    diesel::insert_into(native_inflation_per_epoch::table)
+        .values(NativeInflationInsertDb::from(inflation))
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert native inflation in db")?;
+
+    anyhow::Ok(())
+}
+
 pub fn insert_token_supplies<S>(
     transaction_conn: &mut PgConnection,
     supplies: S,
@@ -140,7 +176,9 @@ mod tests {
     use namada_sdk::uint::MAX_SIGNED_VALUE;
     use orm::balances::BalanceDb;
     use orm::blocks::BlockInsertDb;
+    use orm::native_inflation_per_epoch::NativeInflationDb;
     use orm::schema::blocks;
+    use orm::token::TokenDb;
     use orm::views::balances;
     use shared::balance::{Amount, Balance};
     use shared::id::Id;
@@ -556,6 +594,88 @@ mod tests {
         .expect("Failed to run test");
     }
 
+    /// Test that denominations are only set on the indexed tokens.
+    #[tokio::test]
+    async fn test_update_token_denominations() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let token = Token::Native(Id::Account(
+                "tnam1q87wtaqqtlwkw927gaff34hgda36huk0kgry692a".to_string(),
+            ));
+            let unknown_token = Id::Account(
+                "tnam1qxfj3sf6a0meahdu9t6znp05g8zx4dkjtgyn9gfu".to_string(),
+            );
+
+            insert_tokens(conn, vec![token.clone()])?;
+
+            update_token_denominations(
+                conn,
+                vec![
+                    TokenDenomination {
+                        address: token.address().clone(),
+                        denomination: 6,
+                    },
+                    TokenDenomination {
+                        address: unknown_token,
+                        denomination: 18,
+                    },
+                ],
+            )?;
+
+            let tokens = token::table
+                .select(TokenDb::as_select())
+                .load::<TokenDb>(conn)?;
+
+            assert_eq!(tokens.len(), 1);
+            assert_eq!(tokens[0].denomination, Some(6));
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
+    /// Test that the inflation of an epoch is only recorded once.
+    #[tokio::test]
+    async fn test_insert_native_inflation_keeps_first_record() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            insert_native_inflation(conn, None)?;
+
+            insert_native_inflation(
+                conn,
+                Some(NativeInflation {
+                    epoch: 5,
+                    pos: Amount::from(NamadaAmount::from_u64(100)).into(),
+                    masp: Amount::from(NamadaAmount::from_u64(10)).into(),
+                }),
+            )?;
+            insert_native_inflation(
+                conn,
+                Some(NativeInflation {
+                    epoch: 5,
+                    pos: Amount::from(NamadaAmount::from_u64(200)).into(),
+                    masp: Amount::from(NamadaAmount::from_u64(20)).into(),
+                }),
+            )?;
+
+            let inflation = native_inflation_per_epoch::table
+                .select(NativeInflationDb::as_select())
+                .load::<NativeInflationDb>(conn)?;
+
+            assert_eq!(inflation.len(), 1);
+            assert_eq!(inflation[0].epoch, 5);
+            assert_eq!(inflation[0].pos_minted.to_string(), "100");
+            assert_eq!(inflation[0].masp_minted.to_string(), "10");
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
     fn seed_tokens_from_balance(
         conn: &mut PgConnection,
         balance: Vec<Balance>,
@@ -1,9 +1,12 @@
 use anyhow::Context;
 use diesel::upsert::excluded;
-use diesel::{ExpressionMethods, PgConnection, RunQueryDsl};
+use diesel::{
+    ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, RunQueryDsl,
+};
 use orm::blocks::BlockInsertDb;
-use orm::schema::blocks;
-use shared::block::Block;
+use orm::epochs::EpochInsertDb;
+use orm::schema::{blocks, epochs};
+use shared::block::{Block, BlockHeight, Epoch};
 use tendermint_rpc::endpoint::block::Response as TendermintBlockResponse;
 
 pub fn upsert_block(
@@ -30,3 +33,147 @@ pub fn upsert_block(
 
     anyhow::Ok(())
 }
+
+/// Records the first block of the epoch, along with its timestamp if the block
+/// was indexed
+pub fn upsert_epoch(
+    transaction_conn: &mut PgConnection,
+    epoch: Epoch,
+    first_block_height: BlockHeight,
+) -> anyhow::Result<()> {
+    let timestamp = blocks::table
+        .filter(blocks::height.eq(first_block_height as i32))
+        .select(blocks::timestamp)
+        .first::<Option<chrono::NaiveDateTime>>(transaction_conn)
+        .optional()
+        .context("Failed to query first block of epoch")?
+        .flatten();
+
+    diesel::insert_into(epochs::table)
+        .values(EpochInsertDb {
+            epoch: epoch as i32,
+            first_block_height: first_block_height as i32,
+            timestamp,
+        })
+        .on_conflict(epochs::epoch)
+        .do_update()
+        .set((
+            epochs::first_block_height.eq(excluded(epochs::first_block_height)),
+            epochs::timestamp.eq(excluded(epochs::timestamp)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to upsert epoch in db")?;
+
+    anyhow::Ok(())
+}
+
+/// Records the epoch when the previous indexed block belongs to an earlier
+/// epoch, so epochs crossed while catching up with the chain are not missed
+pub fn upsert_epoch_if_started(
+    transaction_conn: &mut PgConnection,
+    epoch: Epoch,
+    block_height: BlockHeight,
+) -> anyhow::Result<()> {
+    let previous_epoch = blocks::table
+        .filter(blocks::height.eq(block_height as i32 - 1))
+        .select(blocks::epoch)
+        .first::<Option<i32>>(transaction_conn)
+        .optional()
+        .context("Failed to query previous block")?
+        .flatten();
+
+    match previous_epoch {
+        Some(previous_epoch) if previous_epoch < epoch as i32 => {
+            upsert_epoch(transaction_conn, epoch, block_height)
+        }
+        _ => anyhow::Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::SelectableHelper;
+    use orm::blocks::BlockDb;
+    use orm::epochs::EpochDb;
+    use test_helpers::db::TestDb;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_upsert_epoch_with_first_block_timestamp() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let timestamp = chrono::DateTime::from_timestamp(1_700_000_000, 0)
+                .expect("Invalid timestamp")
+                .naive_utc();
+
+            upsert_epoch(conn, 5, 100)?;
+
+            let epoch = epochs::table
+                .find(5)
+                .select(EpochDb::as_select())
+                .first(conn)?;
+            assert_eq!(epoch.first_block_height, 100);
+            assert_eq!(epoch.timestamp, None);
+
+            diesel::insert_into(blocks::table)
+                .values(BlockDb {
+                    height: 100,
+                    hash: None,
+                    app_hash: None,
+                    timestamp: Some(timestamp),
+                    proposer: None,
+                    epoch: Some(5),
+                })
+                .execute(conn)?;
+
+            upsert_epoch(conn, 5, 100)?;
+
+            let epoch = epochs::table
+                .find(5)
+                .select(EpochDb::as_select())
+                .first(conn)?;
+            assert_eq!(epoch.timestamp, Some(timestamp));
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
+    #[tokio::test]
+    async fn test_upsert_epoch_if_started_after_previous_epoch() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let block = |height: i32, epoch: i32| BlockDb {
+                height,
+                hash: None,
+                app_hash: None,
+                timestamp: None,
+                proposer: None,
+                epoch: Some(epoch),
+            };
+
+            diesel::insert_into(blocks::table)
+                .values(vec![block(99, 4), block(100, 5), block(101, 5)])
+                .execute(conn)?;
+
+            upsert_epoch_if_started(conn, 5, 101)?;
+            let recorded = epochs::table.count().get_result::<i64>(conn)?;
+            assert_eq!(recorded, 0);
+
+            upsert_epoch_if_started(conn, 5, 100)?;
+            let epoch = epochs::table
+                .find(5)
+                .select(EpochDb::as_select())
+                .first(conn)?;
+            assert_eq!(epoch.first_block_height, 100);
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+}
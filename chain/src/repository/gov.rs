@@ -1,6 +1,8 @@
 use std::collections::HashSet;
 
 use anyhow::Context;
+use diesel::dsl::sql;
+use diesel::sql_types::{Integer, Nullable};
 use diesel::upsert::excluded;
 use diesel::{ExpressionMethods, PgConnection, RunQueryDsl};
 use orm::governance_proposal::GovernanceProposalInsertDb;
@@ -80,9 +82,181 @@ fn insert_votes_chunk(
             governance_votes::proposal_id,
         ))
         .do_update()
-        .set((governance_votes::kind.eq(excluded(governance_votes::kind)),))
+        .set((
+            governance_votes::kind.eq(excluded(governance_votes::kind)),
+            // Votes queried from storage, e.g. on a re-sync, have no height,
+            // so the known one is kept
+            governance_votes::block_height.eq(sql::<Nullable<Integer>>(
+                "COALESCE(excluded.block_height, \
+                 governance_votes.block_height)",
+            )),
+        ))
         .execute(transaction_conn)
         .context("Failed to update governance votes in db")?;
 
     anyhow::Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use diesel::{QueryDsl, SelectableHelper};
+    use orm::governance_proposal::GovernanceProposalDb;
+    use orm::governance_votes::{
+        GovernanceProposalVoteDb, GovernanceVoteKindDb,
+    };
+    use shared::id::Id;
+    use shared::vote::ProposalVoteKind;
+    use test_helpers::db::TestDb;
+
+    use super::*;
+
+    /// Test that proposals keep track of the tx and height they were
+    /// submitted at.
+    #[tokio::test]
+    async fn test_insert_proposals_with_submission() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let proposal = GovernanceProposal {
+                init_tx_id: Some(Id::Hash("init_proposal".to_string())),
+                init_height: Some(42),
+                ..GovernanceProposal::fake(0)
+            };
+
+            insert_proposals(conn, vec![(proposal, TallyType::TwoFifths)])?;
+
+            let queried_proposals = governance_proposals::table
+                .select(GovernanceProposalDb::as_select())
+                .load::<GovernanceProposalDb>(conn)?;
+
+            assert_eq!(queried_proposals.len(), 1);
+            assert_eq!(
+                queried_proposals[0].init_tx_id.as_deref(),
+                Some("init_proposal")
+            );
+            assert_eq!(queried_proposals[0].init_height, Some(42));
+            assert_eq!(queried_proposals[0].total_voting_power, None);
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
+    /// Test that the well known fields of a json content are stored in their
+    /// own columns.
+    #[tokio::test]
+    async fn test_insert_proposals_parses_content() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let proposal = GovernanceProposal {
+                content: r#"{"title":"Raise the gas limit","authors":"alice","discussions-to":"https://forum.namada.net/t/1","abstract":" ","details":"..."}"#
+                    .to_string(),
+                ..GovernanceProposal::fake(0)
+            };
+
+            insert_proposals(conn, vec![(proposal, TallyType::TwoFifths)])?;
+
+            let queried_proposals = governance_proposals::table
+                .select(GovernanceProposalDb::as_select())
+                .load::<GovernanceProposalDb>(conn)?;
+
+            assert_eq!(queried_proposals.len(), 1);
+            assert_eq!(
+                queried_proposals[0].title.as_deref(),
+                Some("Raise the gas limit")
+            );
+            assert_eq!(queried_proposals[0].authors.as_deref(), Some("alice"));
+            assert_eq!(
+                queried_proposals[0].discussions_to.as_deref(),
+                Some("https://forum.namada.net/t/1")
+            );
+            assert_eq!(queried_proposals[0].abstract_, None);
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
+    /// Test that a vote that is cast again is moved to the height of the
+    /// latest vote.
+    #[tokio::test]
+    async fn test_insert_votes_updates_block_height() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            insert_proposals(
+                conn,
+                vec![(GovernanceProposal::fake(0), TallyType::TwoFifths)],
+            )?;
+
+            let vote = GovernanceVote {
+                proposal_id: 0,
+                vote: ProposalVoteKind::Yay,
+                address: Id::Account("tnam1voter".to_string()),
+                height: Some(10),
+            };
+            insert_votes(conn, HashSet::from([vote.clone()]))?;
+
+            let vote = GovernanceVote {
+                vote: ProposalVoteKind::Nay,
+                height: Some(20),
+                ..vote
+            };
+            insert_votes(conn, HashSet::from([vote]))?;
+
+            let queried_votes = governance_votes::table
+                .select(GovernanceProposalVoteDb::as_select())
+                .load::<GovernanceProposalVoteDb>(conn)?;
+
+            assert_eq!(queried_votes.len(), 1);
+            assert!(matches!(queried_votes[0].kind, GovernanceVoteKindDb::Nay));
+            assert_eq!(queried_votes[0].block_height, Some(20));
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
+    /// Test that a vote without a height, as queried on a re-sync, keeps the
+    /// known height.
+    #[tokio::test]
+    async fn test_insert_votes_keeps_known_block_height() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            insert_proposals(
+                conn,
+                vec![(GovernanceProposal::fake(0), TallyType::TwoFifths)],
+            )?;
+
+            let vote = GovernanceVote {
+                proposal_id: 0,
+                vote: ProposalVoteKind::Yay,
+                address: Id::Account("tnam1voter".to_string()),
+                height: Some(10),
+            };
+            insert_votes(conn, HashSet::from([vote.clone()]))?;
+
+            let vote = GovernanceVote {
+                height: None,
+                ..vote
+            };
+            insert_votes(conn, HashSet::from([vote]))?;
+
+            let queried_votes = governance_votes::table
+                .select(GovernanceProposalVoteDb::as_select())
+                .load::<GovernanceProposalVoteDb>(conn)?;
+
+            assert_eq!(queried_votes.len(), 1);
+            assert_eq!(queried_votes[0].block_height, Some(10));
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+}
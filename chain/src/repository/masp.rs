@@ -1,9 +1,14 @@
 use anyhow::Context;
 use diesel::upsert::excluded;
 use diesel::{ExpressionMethods, PgConnection, RunQueryDsl};
-use orm::masp::MaspRewardDataInsertDb;
-use orm::schema::masp_rates;
-use shared::masp::MaspRewardData;
+use orm::masp::{
+    MaspCommitmentTreeInsertDb, MaspRewardDataInsertDb,
+    MaspRewardHistoryInsertDb,
+};
+use orm::schema::{
+    masp_commitment_tree_per_epoch, masp_rates, masp_rates_per_epoch,
+};
+use shared::masp::{MaspCommitmentTree, MaspRewardData, MaspRewardHistory};
 
 pub fn insert_masp_rates(
     transaction_conn: &mut PgConnection,
@@ -33,3 +38,38 @@ pub fn insert_masp_rates(
 
     Ok(())
 }
+
+pub fn insert_masp_reward_history(
+    transaction_conn: &mut PgConnection,
+    masp_reward_history: Vec<MaspRewardHistory>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(masp_rates_per_epoch::table)
+        .values(
+            masp_reward_history
+                .into_iter()
+                .map(MaspRewardHistoryInsertDb::from)
+                .collect::<Vec<_>>(),
+        )
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert masp reward history in db")?;
+
+    Ok(())
+}
+
+pub fn insert_masp_commitment_tree(
+    transaction_conn: &mut PgConnection,
+    commitment_tree: Option<MaspCommitmentTree>,
+) -> anyhow::Result<()> {
+    let Some(commitment_tree) = commitment_tree else {
+        return Ok(());
+    };
+
+    diesel::insert_into(masp_commitment_tree_per_epoch::table)
+        .values(MaspCommitmentTreeInsertDb::from(commitment_tree))
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert masp commitment tree in db")?;
+
+    Ok(())
+}
@@ -1,10 +1,16 @@
 use std::collections::HashSet;
 
 use anyhow::Context;
-use diesel::{PgConnection, QueryDsl, RunQueryDsl, SelectableHelper};
-use orm::pgf::PublicGoodFundingPaymentDb;
-use orm::schema::public_good_funding;
+use diesel::{
+    ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, SelectableHelper,
+};
+use orm::pgf::{
+    PgfScheduledPaymentInsertDb, PgfStewardDb, PgfStewardInsertDb,
+    PublicGoodFundingPaymentDb,
+};
+use orm::schema::{pgf_scheduled_payments, pgf_stewards, public_good_funding};
 use shared::id::Id;
+use shared::pgf::PgfScheduledPayment;
 use shared::token::Token;
 use shared::utils::BalanceChange;
 
@@ -25,3 +31,122 @@ pub fn get_pgf_receipients_balance_changes(
         })
         .context("Failed to update governance votes in db")
 }
+
+pub fn insert_pgf_scheduled_payments(
+    transaction_conn: &mut PgConnection,
+    scheduled_payments: Vec<PgfScheduledPayment>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(pgf_scheduled_payments::table)
+        .values::<Vec<PgfScheduledPaymentInsertDb>>(
+            scheduled_payments
+                .into_iter()
+                .map(PgfScheduledPaymentInsertDb::from_pgf_scheduled_payment)
+                .collect(),
+        )
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert pgf scheduled payments in db")?;
+
+    anyhow::Ok(())
+}
+
+/// Records the addresses joining or leaving the steward set at the given
+/// epoch, compared to the last known state of each address
+pub fn update_pgf_stewards(
+    transaction_conn: &mut PgConnection,
+    stewards: Option<Vec<Id>>,
+    epoch: u32,
+) -> anyhow::Result<()> {
+    let Some(stewards) = stewards else {
+        return anyhow::Ok(());
+    };
+    let stewards = stewards
+        .into_iter()
+        .map(|steward| steward.to_string())
+        .collect::<HashSet<_>>();
+
+    let known_stewards = pgf_stewards::table
+        .distinct_on(pgf_stewards::dsl::address)
+        .order((pgf_stewards::dsl::address, pgf_stewards::dsl::epoch.desc()))
+        .select(PgfStewardDb::as_select())
+        .load(transaction_conn)
+        .context("Failed to query pgf stewards from db")?
+        .into_iter()
+        .filter(|steward| steward.is_steward)
+        .map(|steward| steward.address)
+        .collect::<HashSet<_>>();
+
+    let changes = stewards
+        .difference(&known_stewards)
+        .map(|address| (address, true))
+        .chain(
+            known_stewards
+                .difference(&stewards)
+                .map(|address| (address, false)),
+        )
+        .map(|(address, is_steward)| PgfStewardInsertDb {
+            address: address.clone(),
+            is_steward,
+            epoch: epoch as i32,
+        })
+        .collect::<Vec<_>>();
+
+    diesel::insert_into(pgf_stewards::table)
+        .values(changes)
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to update pgf stewards in db")?;
+
+    anyhow::Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use orm::pgf::PgfStewardDb;
+    use test_helpers::db::TestDb;
+
+    use super::*;
+
+    /// Test that only changes to the steward set are recorded.
+    #[tokio::test]
+    async fn test_update_pgf_stewards_records_changes() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let alice = Id::Account(
+                "tnam1qxfj3sf6a0meahdu9t6znp05g8zx4dkjtgyn9gfu".to_string(),
+            );
+            let bob = Id::Account(
+                "tnam1q87wtaqqtlwkw927gaff34hgda36huk0kgry692a".to_string(),
+            );
+
+            update_pgf_stewards(conn, Some(vec![alice.clone()]), 1)?;
+            update_pgf_stewards(conn, None, 2)?;
+            update_pgf_stewards(conn, Some(vec![alice.clone()]), 3)?;
+            update_pgf_stewards(conn, Some(vec![bob.clone()]), 4)?;
+
+            let history = pgf_stewards::table
+                .order((pgf_stewards::dsl::epoch, pgf_stewards::dsl::address))
+                .select(PgfStewardDb::as_select())
+                .load::<PgfStewardDb>(conn)?
+                .into_iter()
+                .map(|steward| {
+                    (steward.address, steward.is_steward, steward.epoch)
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                history,
+                vec![
+                    (alice.to_string(), true, 1),
+                    (bob.to_string(), true, 4),
+                    (alice.to_string(), false, 4),
+                ]
+            );
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+}
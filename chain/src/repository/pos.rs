@@ -1,23 +1,32 @@
 use std::collections::HashSet;
 
 use anyhow::Context;
+use bigdecimal::{BigDecimal, Zero};
 use diesel::upsert::excluded;
 use diesel::{
     BoolExpressionMethods, ExpressionMethods, OptionalEmptyChangesetExtension,
-    PgConnection, QueryDsl, RunQueryDsl, SelectableHelper,
+    OptionalExtension, PgConnection, QueryDsl, RunQueryDsl, SelectableHelper,
 };
 use orm::bond::BondInsertDb;
-use orm::redelegation::RedelegationInsertDb;
-use orm::schema::{bonds, pos_rewards, redelegation, unbonds, validators};
+use orm::pos_rewards::PosRewardClaimInsertDb;
+use orm::redelegation::{RedelegationHistoryInsertDb, RedelegationInsertDb};
+use orm::schema::{
+    bonds, pos_reward_claims, pos_rewards, redelegation, redelegation_history,
+    unbonds, validators,
+};
 use orm::unbond::UnbondInsertDb;
 use orm::validators::{
     ValidatorDb, ValidatorStateDb, ValidatorUpdateMetadataDb,
     ValidatorWithMetaInsertDb,
 };
-use shared::block::Epoch;
+use shared::block::{BlockHeight, Epoch};
 use shared::id::Id;
-use shared::pos::{Bonds, Redelegations, UnbondAddresses, Unbonds};
+use shared::pos::{
+    Bonds, RedelegationTx, Redelegations, UnbondAddresses, Unbonds,
+};
+use shared::rewards::RewardClaim;
 use shared::tuple_len::TupleLen;
+use shared::utils::DelegationPair;
 use shared::validator::{
     ValidatorMetadataChange, ValidatorSet, ValidatorStateChange,
 };
@@ -56,6 +65,27 @@ pub fn clear_bonds(
     anyhow::Ok(())
 }
 
+/// Replaces the bonds and the pending unbonds with the ones queried from the
+/// chain. Withdrawn unbonds are no longer in storage, so they are kept as
+/// history.
+pub fn reindex_bonds_and_unbonds(
+    transaction_conn: &mut PgConnection,
+    bonds: Bonds,
+    unbonds: Unbonds,
+) -> anyhow::Result<()> {
+    diesel::delete(bonds::table)
+        .execute(transaction_conn)
+        .context("Failed to delete bonds from db")?;
+    diesel::delete(
+        unbonds::table.filter(unbonds::columns::withdrawn_at_height.is_null()),
+    )
+    .execute(transaction_conn)
+    .context("Failed to delete unbonds from db")?;
+
+    insert_bonds(transaction_conn, bonds)?;
+    insert_unbonds(transaction_conn, unbonds)
+}
+
 pub fn insert_bonds(
     transaction_conn: &mut PgConnection,
     bonds: Bonds,
@@ -205,10 +235,51 @@ pub fn insert_redelegations(
     anyhow::Ok(())
 }
 
+pub fn insert_redelegation_history(
+    transaction_conn: &mut PgConnection,
+    redelegations: Vec<RedelegationTx>,
+) -> anyhow::Result<()> {
+    let validator_id = |conn: &mut PgConnection, address: &Id| {
+        validators::table
+            .filter(validators::namada_address.eq(address.to_string()))
+            .select(validators::id)
+            .first::<i32>(conn)
+            .context("Failed to get validator")
+    };
+
+    let redelegations = redelegations
+        .into_iter()
+        .map(|redelegation| {
+            let src_validator_id =
+                validator_id(transaction_conn, &redelegation.src_validator)?;
+            let dest_validator_id =
+                validator_id(transaction_conn, &redelegation.dest_validator)?;
+
+            anyhow::Ok(RedelegationHistoryInsertDb::from_redelegation_tx(
+                redelegation,
+                src_validator_id,
+                dest_validator_id,
+            ))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    diesel::insert_into(redelegation_history::table)
+        .values::<&Vec<RedelegationHistoryInsertDb>>(&redelegations)
+        .on_conflict(redelegation_history::columns::inner_tx_id)
+        .do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert redelegation history in db")?;
+
+    anyhow::Ok(())
+}
+
+/// Withdrawn unbonds are kept as history, flagged with the height and the
+/// epoch of the withdrawal
 pub fn remove_withdraws(
     transaction_conn: &mut PgConnection,
     current_epoch: Epoch,
     unbond_addresses: HashSet<UnbondAddresses>,
+    block_height: BlockHeight,
 ) -> anyhow::Result<()> {
     let sources = unbond_addresses
         .iter()
@@ -220,7 +291,7 @@ pub fn remove_withdraws(
         .map(|unbond| unbond.validator.to_string())
         .collect::<Vec<String>>();
 
-    diesel::delete(
+    diesel::update(
         unbonds::table.filter(
             unbonds::columns::address
                 .eq_any(sources)
@@ -229,9 +300,14 @@ pub fn remove_withdraws(
                         validators::columns::namada_address.eq_any(validators),
                     ),
                 ))
-                .and(unbonds::columns::withdraw_epoch.le(current_epoch as i32)),
+                .and(unbonds::columns::withdraw_epoch.le(current_epoch as i32))
+                .and(unbonds::columns::withdrawn_at_height.is_null()),
         ),
     )
+    .set((
+        unbonds::columns::withdrawn_at_height.eq(block_height as i32),
+        unbonds::columns::withdrawn_at_epoch.eq(current_epoch as i32),
+    ))
     .execute(transaction_conn)
     .context("Failed to remove withdraws from db")?;
 
@@ -240,30 +316,79 @@ pub fn remove_withdraws(
 
 pub fn delete_claimed_rewards(
     transaction_conn: &mut PgConnection,
-    reward_claimers: HashSet<(Id, Id)>,
+    reward_claims: HashSet<RewardClaim>,
+    block_height: BlockHeight,
 ) -> anyhow::Result<()> {
     // If there are no rewards to claimm return early, to not clear the whole
     // table
-    if reward_claimers.is_empty() {
+    if reward_claims.is_empty() {
         return Ok(());
     }
 
-    for (owner, validator_id) in reward_claimers {
+    for claim in reward_claims {
+        let DelegationPair {
+            validator_address,
+            delegator_address,
+        } = claim.delegation_pair;
+
+        let validator_id: Option<i32> = validators::table
+            .select(validators::columns::id)
+            .filter(
+                validators::columns::namada_address
+                    .eq(validator_address.to_string()),
+            )
+            .first(transaction_conn)
+            .optional()
+            .context("Failed to query validator id")?;
+
+        let Some(validator_id) = validator_id else {
+            tracing::warn!(
+                validator = %validator_address,
+                tx_id = %claim.inner_tx_id,
+                "Reward claim for an unknown validator"
+            );
+            continue;
+        };
+
+        // Only rows that are still pending are linked to the claim, so that
+        // rows cleared by an earlier claim keep pointing to it
         let target = pos_rewards::table.filter(
-            pos_rewards::owner.eq(owner.to_string()).and(
-                pos_rewards::validator_id.eq_any(
-                    validators::table.select(validators::columns::id).filter(
-                        validators::columns::namada_address
-                            .eq(validator_id.to_string()),
-                    ),
-                ),
-            ),
+            pos_rewards::owner
+                .eq(delegator_address.to_string())
+                .and(pos_rewards::claimed.eq(false))
+                .and(pos_rewards::validator_id.eq(validator_id)),
         );
 
-        diesel::update(target)
-            .set(pos_rewards::claimed.eq(true))
-            .execute(transaction_conn)
+        let cleared: Vec<(BigDecimal, i32)> = diesel::update(target)
+            .set((
+                pos_rewards::claimed.eq(true),
+                pos_rewards::claim_tx_id.eq(claim.inner_tx_id.to_string()),
+                pos_rewards::claimed_at_height.eq(block_height as i32),
+            ))
+            .returning((pos_rewards::raw_amount, pos_rewards::epoch))
+            .get_results(transaction_conn)
             .context("Failed to update pos rewards in db")?;
+
+        // The latest cleared row holds the claimed amount, a claim that
+        // cleared nothing is still recorded in the ledger
+        let (raw_amount, epoch) = cleared
+            .into_iter()
+            .max_by_key(|(_, epoch)| *epoch)
+            .map(|(raw_amount, epoch)| (raw_amount, Some(epoch)))
+            .unwrap_or_else(|| (BigDecimal::zero(), None));
+
+        diesel::insert_into(pos_reward_claims::table)
+            .values(PosRewardClaimInsertDb {
+                claim_tx_id: claim.inner_tx_id.to_string(),
+                owner: delegator_address.to_string(),
+                validator_id,
+                raw_amount,
+                epoch,
+                block_height: block_height as i32,
+            })
+            .on_conflict_do_nothing()
+            .execute(transaction_conn)
+            .context("Failed to insert reward claim in db")?;
     }
 
     anyhow::Ok(())
@@ -359,11 +484,13 @@ pub fn upsert_validators(
 #[cfg(test)]
 mod tests {
     use orm::bond::BondDb;
-    use orm::redelegation::RedelegationDb;
+    use orm::pos_rewards::{PoSRewardDb, PosRewardClaimDb, PosRewardInsertDb};
+    use orm::redelegation::{RedelegationDb, RedelegationHistoryDb};
     use orm::unbond::UnbondDb;
     use orm::validators::ValidatorInsertDb;
     use shared::balance::Amount;
     use shared::pos::{Bond, Redelegation, Unbond};
+    use shared::rewards::Reward;
     use shared::validator::Validator;
     use test_helpers::db::TestDb;
 
@@ -883,6 +1010,250 @@ mod tests {
         .expect("Failed to run test");
     }
 
+    /// Test that withdrawn unbonds are kept with the withdrawal height and
+    /// epoch, and that unbonds that are not withdrawable yet are left as is.
+    #[tokio::test]
+    async fn test_remove_withdraws_keeps_history() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let fake_validator = Validator::fake();
+            let mut withdrawable = Unbond::fake(fake_validator.address.clone());
+            withdrawable.withdraw_at = 5;
+            let mut unbonding = withdrawable.clone();
+            unbonding.withdraw_at = 12;
+
+            seed_unbonds(
+                conn,
+                fake_validator.clone(),
+                vec![withdrawable.clone(), unbonding],
+            )?;
+
+            let unbond_addresses = UnbondAddresses {
+                source: withdrawable.source,
+                validator: fake_validator.address,
+            };
+            remove_withdraws(conn, 10, HashSet::from([unbond_addresses]), 42)?;
+
+            let queried_unbonds = query_unbonds(conn);
+
+            assert_eq!(queried_unbonds.len(), 2);
+            for unbond in queried_unbonds {
+                if unbond.withdraw_epoch == 5 {
+                    assert_eq!(unbond.withdrawn_at_height, Some(42));
+                    assert_eq!(unbond.withdrawn_at_epoch, Some(10));
+                } else {
+                    assert_eq!(unbond.withdrawn_at_height, None);
+                    assert_eq!(unbond.withdrawn_at_epoch, None);
+                }
+            }
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
+    /// Test that reindexing replaces the bonds and the pending unbonds, but
+    /// keeps the withdrawn unbonds.
+    #[tokio::test]
+    async fn test_reindex_bonds_and_unbonds_keeps_withdrawn_unbonds() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let fake_validator = Validator::fake();
+            let mut withdrawn = Unbond::fake(fake_validator.address.clone());
+            withdrawn.withdraw_at = 5;
+            let mut unbonding = withdrawn.clone();
+            unbonding.withdraw_at = 12;
+
+            seed_unbonds(
+                conn,
+                fake_validator.clone(),
+                vec![withdrawn.clone(), unbonding],
+            )?;
+            insert_bonds(
+                conn,
+                vec![Bond::fake(fake_validator.address.clone())],
+            )?;
+
+            let unbond_addresses = UnbondAddresses {
+                source: withdrawn.source,
+                validator: fake_validator.address.clone(),
+            };
+            remove_withdraws(conn, 10, HashSet::from([unbond_addresses]), 42)?;
+
+            let mut reindexed = Unbond::fake(fake_validator.address.clone());
+            reindexed.withdraw_at = 15;
+            reindex_bonds_and_unbonds(conn, vec![], vec![reindexed])?;
+
+            let queried_unbonds = query_unbonds(conn);
+            let mut withdraw_epochs = queried_unbonds
+                .iter()
+                .map(|unbond| unbond.withdraw_epoch)
+                .collect::<Vec<_>>();
+            withdraw_epochs.sort();
+
+            assert!(query_bonds(conn).is_empty());
+            assert_eq!(withdraw_epochs, vec![5, 15]);
+            assert!(queried_unbonds.iter().any(|unbond| {
+                unbond.withdraw_epoch == 5
+                    && unbond.withdrawn_at_height == Some(42)
+            }));
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
+    /// Test that redelegation txs are recorded with their source and
+    /// destination validators, and that recording a tx twice is a no-op.
+    #[tokio::test]
+    async fn test_insert_redelegation_history() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let src_validator = Validator::fake();
+            let dest_validator = Validator::fake();
+
+            seed_validator(conn, src_validator.clone())?;
+            seed_validator(conn, dest_validator.clone())?;
+
+            let redelegation = RedelegationTx {
+                inner_tx_id: Id::Hash("redelegation".to_string()),
+                delegator: Id::Account("tnam1delegator".to_string()),
+                src_validator: src_validator.address,
+                dest_validator: dest_validator.address,
+                amount: Amount::fake(),
+                epoch: 7,
+                height: 100,
+            };
+
+            insert_redelegation_history(conn, vec![redelegation.clone()])?;
+            insert_redelegation_history(conn, vec![redelegation.clone()])?;
+
+            let queried_redelegations = query_redelegation_history(conn);
+
+            assert_eq!(queried_redelegations.len(), 1);
+            let queried_redelegation = &queried_redelegations[0];
+            assert_ne!(
+                queried_redelegation.src_validator_id,
+                queried_redelegation.dest_validator_id
+            );
+            assert_eq!(queried_redelegation.delegator, "tnam1delegator");
+            assert_eq!(queried_redelegation.epoch, 7);
+            assert_eq!(
+                Amount::from(queried_redelegation.raw_amount.clone()),
+                redelegation.amount
+            );
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
+    /// Test that claimed rewards are linked to the claim tx that cleared them
+    /// and that later claims don't overwrite earlier links.
+    #[tokio::test]
+    async fn test_delete_claimed_rewards_links_claim_tx() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let validator = Validator::fake();
+            let delegation_pair = DelegationPair {
+                validator_address: validator.address.clone(),
+                delegator_address: Id::Account("tnam1delegator".to_string()),
+            };
+
+            seed_validator(conn, validator.clone())?;
+            seed_rewards(conn, delegation_pair.clone(), vec![1, 2])?;
+
+            let first_claim = RewardClaim {
+                inner_tx_id: Id::Hash("first_claim".to_string()),
+                delegation_pair: delegation_pair.clone(),
+            };
+            delete_claimed_rewards(conn, HashSet::from([first_claim]), 10)?;
+
+            seed_rewards(conn, delegation_pair.clone(), vec![3])?;
+
+            let second_claim = RewardClaim {
+                inner_tx_id: Id::Hash("second_claim".to_string()),
+                delegation_pair,
+            };
+            delete_claimed_rewards(conn, HashSet::from([second_claim]), 20)?;
+
+            let queried_rewards = query_rewards(conn);
+
+            assert_eq!(queried_rewards.len(), 3);
+            for reward in queried_rewards {
+                let (claim_tx_id, height) = if reward.epoch < 3 {
+                    ("first_claim", 10)
+                } else {
+                    ("second_claim", 20)
+                };
+                assert!(reward.claimed);
+                assert_eq!(reward.claim_tx_id.as_deref(), Some(claim_tx_id));
+                assert_eq!(reward.claimed_at_height, Some(height));
+            }
+
+            let queried_claims = query_reward_claims(conn);
+
+            assert_eq!(queried_claims.len(), 2);
+            assert!(queried_claims.iter().any(|claim| {
+                claim.claim_tx_id == "first_claim" && claim.epoch == Some(2)
+            }));
+            assert!(queried_claims.iter().any(|claim| {
+                claim.claim_tx_id == "second_claim" && claim.epoch == Some(3)
+            }));
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
+    /// Test that a claim clearing no pending reward is still recorded.
+    #[tokio::test]
+    async fn test_delete_claimed_rewards_records_claim_without_pending_rewards()
+    {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let validator = Validator::fake();
+            let delegation_pair = DelegationPair {
+                validator_address: validator.address.clone(),
+                delegator_address: Id::Account("tnam1delegator".to_string()),
+            };
+
+            seed_validator(conn, validator.clone())?;
+
+            let claim = RewardClaim {
+                inner_tx_id: Id::Hash("claim".to_string()),
+                delegation_pair,
+            };
+            delete_claimed_rewards(conn, HashSet::from([claim.clone()]), 10)?;
+            // Reprocessing the same block doesn't duplicate the claim
+            delete_claimed_rewards(conn, HashSet::from([claim]), 10)?;
+
+            assert!(query_rewards(conn).is_empty());
+
+            let queried_claims = query_reward_claims(conn);
+
+            assert_eq!(queried_claims.len(), 1);
+            assert_eq!(queried_claims[0].claim_tx_id, "claim");
+            assert_eq!(queried_claims[0].owner, "tnam1delegator");
+            assert_eq!(queried_claims[0].raw_amount, BigDecimal::zero());
+            assert_eq!(queried_claims[0].epoch, None);
+            assert_eq!(queried_claims[0].block_height, 10);
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
     fn seed_bonds(
         conn: &mut PgConnection,
         validator: Validator,
@@ -971,6 +1342,58 @@ mod tests {
         anyhow::Ok(())
     }
 
+    fn seed_rewards(
+        conn: &mut PgConnection,
+        delegation_pair: DelegationPair,
+        epochs: Vec<i32>,
+    ) -> anyhow::Result<()> {
+        let validator_id: i32 = validators::table
+            .filter(
+                validators::namada_address
+                    .eq(delegation_pair.validator_address.to_string()),
+            )
+            .select(validators::id)
+            .first(conn)
+            .context("Failed to get validator")?;
+
+        diesel::insert_into(pos_rewards::table)
+            .values::<&Vec<PosRewardInsertDb>>(
+                &epochs
+                    .into_iter()
+                    .map(|epoch| {
+                        let reward = Reward {
+                            delegation_pair: delegation_pair.clone(),
+                            amount: Amount::fake(),
+                            epoch,
+                        };
+                        PosRewardInsertDb::from_reward(
+                            reward,
+                            validator_id,
+                            epoch,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .execute(conn)
+            .context("Failed to insert rewards in db")?;
+
+        anyhow::Ok(())
+    }
+
+    fn query_rewards(conn: &mut PgConnection) -> Vec<PoSRewardDb> {
+        pos_rewards::table
+            .select(PoSRewardDb::as_select())
+            .load::<PoSRewardDb>(conn)
+            .expect("Failed to query rewards")
+    }
+
+    fn query_reward_claims(conn: &mut PgConnection) -> Vec<PosRewardClaimDb> {
+        pos_reward_claims::table
+            .select(PosRewardClaimDb::as_select())
+            .load::<PosRewardClaimDb>(conn)
+            .expect("Failed to query reward claims")
+    }
+
     fn query_bonds(conn: &mut PgConnection) -> Vec<BondDb> {
         bonds::table
             .select(BondDb::as_select())
@@ -985,6 +1408,15 @@ mod tests {
             .expect("Failed to query bonds")
     }
 
+    fn query_redelegation_history(
+        conn: &mut PgConnection,
+    ) -> Vec<RedelegationHistoryDb> {
+        redelegation_history::table
+            .select(RedelegationHistoryDb::as_select())
+            .load::<RedelegationHistoryDb>(conn)
+            .expect("Failed to query redelegation history")
+    }
+
     fn query_redelegations(conn: &mut PgConnection) -> Vec<RedelegationDb> {
         redelegation::table
             .select(RedelegationDb::as_select())
@@ -6,11 +6,18 @@ use futures::{StreamExt, TryStreamExt};
 use namada_core::chain::{
     BlockHeight as NamadaSdkBlockHeight, Epoch as NamadaSdkEpoch,
 };
+use namada_core::masp::TokenMap;
+use namada_governance::pgf::storage::keys as pgf_keys;
+use namada_governance::pgf::storage::steward::StewardDetail;
+use namada_governance::storage::proposal::{PGFTarget, StoragePgfFunding};
 use namada_sdk::address::{Address as NamadaSdkAddress, InternalAddress};
 use namada_sdk::collections::HashMap;
+use namada_sdk::dec::Dec;
 use namada_sdk::hash::Hash;
 use namada_sdk::ibc::IbcTokenHash;
 use namada_sdk::ibc::storage::{ibc_trace_key_prefix, is_ibc_trace_key};
+use namada_sdk::masp_primitives::merkle_tree::CommitmentTree;
+use namada_sdk::masp_primitives::sapling::Node;
 use namada_sdk::proof_of_stake::storage_key;
 use namada_sdk::queries::RPC;
 use namada_sdk::rpc::{
@@ -19,17 +26,21 @@ use namada_sdk::rpc::{
 use namada_sdk::state::Key;
 use namada_sdk::storage::DbKeySeg;
 use namada_sdk::token::Amount as NamadaSdkAmount;
+use namada_sdk::uint::Uint;
 use namada_sdk::{rpc, token};
-use shared::balance::{Amount, Balance, Balances, TokenSupply};
+use shared::balance::{
+    Amount, Balance, Balances, NativeInflation, TokenSupply,
+};
 use shared::block::{BlockHeight, Epoch};
 use shared::checksums::Checksums;
 use shared::id::Id;
-use shared::masp::MaspRewardData;
+use shared::masp::{MaspCommitmentTree, MaspRewardData, MaspRewardHistory};
+use shared::pgf::{PaymentKind, PaymentRecurrence, PgfScheduledPayment};
 use shared::pos::{
     Bond, BondAddresses, Bonds, Redelegation, Unbond, UnbondAddresses, Unbonds,
 };
 use shared::proposal::{GovernanceProposal, TallyType};
-use shared::token::{IbcRateLimit, IbcToken, Token};
+use shared::token::{IbcRateLimit, IbcToken, Token, TokenDenomination};
 use shared::utils::BalanceChange;
 use shared::validator::{Validator, ValidatorSet, ValidatorState};
 use shared::vote::{GovernanceVote, ProposalVoteKind};
@@ -182,6 +193,28 @@ pub async fn query_balance(
         .await)
 }
 
+pub async fn query_tokens_denomination(
+    client: &HttpClient,
+    tokens: &[Token],
+) -> anyhow::Result<Vec<TokenDenomination>> {
+    futures::stream::iter(tokens)
+        .map(|token| async move {
+            let address = NamadaSdkAddress::from_str(&token.to_string())
+                .context("Failed to parse token address")?;
+
+            let denomination = rpc::query_denom(client, &address).await;
+
+            anyhow::Ok(denomination.map(|denomination| TokenDenomination {
+                address: token.address().clone(),
+                denomination: denomination.0,
+            }))
+        })
+        .buffer_unordered(32)
+        .try_collect::<Vec<_>>()
+        .await
+        .map(|denominations| denominations.into_iter().flatten().collect())
+}
+
 pub async fn query_tokens(client: &HttpClient) -> anyhow::Result<Vec<Token>> {
     let ibc_tokens = query_ibc_tokens(client).await?;
     let native_token = query_native_token(client).await?;
@@ -717,6 +750,7 @@ pub async fn query_all_votes(
                     proposal_id,
                     vote: ProposalVoteKind::from(vote.data),
                     address: Id::from(vote.delegator),
+                    height: None,
                 })
                 .collect::<HashSet<_>>();
 
@@ -916,6 +950,79 @@ pub async fn get_pgf_receipients(
         .collect::<HashSet<_>>()
 }
 
+/// The stewards as of the first block of the epoch, the block in which their
+/// rewards are paid
+pub async fn get_pgf_stewards(
+    client: &HttpClient,
+    first_block_in_epoch: BlockHeight,
+) -> anyhow::Result<Vec<Id>> {
+    let prefix = pgf_keys::stewards_key_prefix();
+    let stewards = query_storage_prefix::<StewardDetail>(
+        client,
+        &prefix,
+        Some(first_block_in_epoch),
+    )
+    .await
+    .context("Failed to query PGF stewards")?;
+
+    anyhow::Ok(
+        stewards
+            .into_iter()
+            .flatten()
+            .map(|(_, steward)| Id::from(steward.address))
+            .collect(),
+    )
+}
+
+/// Continuous fundings scheduled to be paid in the first block of the epoch,
+/// as found in storage at that block. These are not observed transfers: the
+/// protocol emits no event for them and skips a funding when the PGF account
+/// cannot cover it, so they record what was due rather than what was paid.
+pub async fn get_pgf_scheduled_fundings(
+    client: &HttpClient,
+    first_block_in_epoch: BlockHeight,
+    epoch: u32,
+) -> anyhow::Result<Vec<PgfScheduledPayment>> {
+    let prefix = pgf_keys::fundings_key_prefix();
+    let fundings = query_storage_prefix::<StoragePgfFunding>(
+        client,
+        &prefix,
+        Some(first_block_in_epoch),
+    )
+    .await
+    .context("Failed to query PGF fundings")?;
+
+    anyhow::Ok(
+        fundings
+            .into_iter()
+            .flatten()
+            .map(|(_, funding)| {
+                let (kind, receipient, amount) = match funding.detail {
+                    PGFTarget::Internal(target) => (
+                        PaymentKind::Native,
+                        Id::from(target.target),
+                        target.amount,
+                    ),
+                    PGFTarget::Ibc(target) => (
+                        PaymentKind::Ibc,
+                        Id::Account(target.target),
+                        target.amount,
+                    ),
+                };
+
+                PgfScheduledPayment {
+                    proposal_id: funding.id,
+                    recurrence: PaymentRecurrence::Continuous,
+                    kind,
+                    receipient,
+                    amount: amount.into(),
+                    epoch,
+                }
+            })
+            .collect(),
+    )
+}
+
 pub async fn get_native_token_supply(
     client: &HttpClient,
     native_token: &Id,
@@ -937,6 +1044,41 @@ pub async fn get_native_token_supply(
     })
 }
 
+pub async fn get_native_inflation(
+    client: &HttpClient,
+    native_token: &Id,
+    first_block_in_epoch: BlockHeight,
+    epoch: u32,
+) -> anyhow::Result<NativeInflation> {
+    let native_token: NamadaSdkAddress = native_token.clone().into();
+
+    let pos_inflation_key = storage_key::last_pos_inflation_amount_key();
+    let masp_inflation_key =
+        namada_token::storage_key::masp_last_inflation_key(&native_token);
+
+    // Both are written when the inflation is applied, in the first block of
+    // the epoch
+    let (pos_inflation, masp_inflation) = futures::try_join!(
+        query_storage_value::<NamadaSdkAmount>(
+            client,
+            &pos_inflation_key,
+            Some(first_block_in_epoch),
+        ),
+        query_storage_value::<NamadaSdkAmount>(
+            client,
+            &masp_inflation_key,
+            Some(first_block_in_epoch),
+        ),
+    )
+    .context("Failed to query native inflation")?;
+
+    anyhow::Ok(NativeInflation {
+        epoch: epoch as _,
+        pos: Amount::from(pos_inflation.unwrap_or_default()).into(),
+        masp: Amount::from(masp_inflation.unwrap_or_default()).into(),
+    })
+}
+
 pub async fn get_token_supply(
     client: &HttpClient,
     token: String,
@@ -1131,6 +1273,162 @@ pub async fn get_masp_rates(
     default_retry(operation).await
 }
 
+/// The masp reward parameters and the PD-controller values of every masp
+/// token, all read at the first block of the epoch
+pub async fn get_masp_reward_history(
+    client: &HttpClient,
+    first_block_in_epoch: BlockHeight,
+    epoch: u32,
+) -> anyhow::Result<Vec<MaspRewardHistory>> {
+    let epochs_per_year_key =
+        namada_sdk::parameters::storage::get_epochs_per_year_key();
+    let masp_epoch_multiplier_key =
+        namada_sdk::parameters::storage::get_masp_epoch_multiplier_key();
+    let token_map_key = namada_token::storage_key::masp_token_map_key();
+
+    let (epochs_per_year, masp_epoch_multiplier, token_map) =
+        futures::try_join!(
+            query_storage_value::<u64>(
+                client,
+                &epochs_per_year_key,
+                Some(first_block_in_epoch),
+            ),
+            query_storage_value::<u64>(
+                client,
+                &masp_epoch_multiplier_key,
+                Some(first_block_in_epoch),
+            ),
+            query_storage_value::<TokenMap>(
+                client,
+                &token_map_key,
+                Some(first_block_in_epoch),
+            ),
+        )
+        .context("Failed to query masp epochs per year and tokens")?;
+
+    let masp_epochs_per_year = epochs_per_year
+        .unwrap_or_default()
+        .checked_div(masp_epoch_multiplier.unwrap_or(1))
+        .unwrap_or_default();
+
+    let history: Vec<Option<MaspRewardHistory>> =
+        futures::stream::iter(token_map.unwrap_or_default().into_values())
+            .then(|token| async move {
+                let height = Some(first_block_in_epoch);
+                let max_reward_rate_key =
+                    namada_token::storage_key::masp_max_reward_rate_key(&token);
+                let kp_gain_key =
+                    namada_token::storage_key::masp_kp_gain_key(&token);
+                let kd_gain_key =
+                    namada_token::storage_key::masp_kd_gain_key(&token);
+                let locked_amount_target_key =
+                    namada_token::storage_key::masp_locked_amount_target_key(
+                        &token,
+                    );
+                let last_locked_amount_key =
+                    namada_token::storage_key::masp_last_locked_amount_key(
+                        &token,
+                    );
+                let last_inflation_key =
+                    namada_token::storage_key::masp_last_inflation_key(&token);
+
+                // The last locked amount and inflation are written by the
+                // PD-controller when the masp rewards are updated, in the
+                // first block of the masp epoch
+                let (
+                    max_reward_rate,
+                    kp_gain,
+                    kd_gain,
+                    locked_amount_target,
+                    last_locked_amount,
+                    last_inflation,
+                ) = futures::try_join!(
+                    query_storage_value::<Dec>(
+                        client,
+                        &max_reward_rate_key,
+                        height
+                    ),
+                    query_storage_value::<Dec>(client, &kp_gain_key, height),
+                    query_storage_value::<Dec>(client, &kd_gain_key, height),
+                    query_storage_value::<Uint>(
+                        client,
+                        &locked_amount_target_key,
+                        height,
+                    ),
+                    query_storage_value::<NamadaSdkAmount>(
+                        client,
+                        &last_locked_amount_key,
+                        height,
+                    ),
+                    query_storage_value::<NamadaSdkAmount>(
+                        client,
+                        &last_inflation_key,
+                        height,
+                    ),
+                )
+                .with_context(|| {
+                    format!("Failed to query masp rewards of token {token}")
+                })?;
+
+                // Tokens without reward parameters are not rewarded
+                let (
+                    Some(max_reward_rate),
+                    Some(kp_gain),
+                    Some(kd_gain),
+                    Some(locked_amount_target),
+                ) = (max_reward_rate, kp_gain, kd_gain, locked_amount_target)
+                else {
+                    return anyhow::Ok(None);
+                };
+
+                anyhow::Ok(Some(MaspRewardHistory {
+                    reward_data: MaspRewardData {
+                        address: Id::from(token),
+                        max_reward_rate: max_reward_rate.to_string(),
+                        kp_gain: kp_gain.to_string(),
+                        kd_gain: kd_gain.to_string(),
+                        locked_amount_target: Amount::from(
+                            NamadaSdkAmount::from(locked_amount_target),
+                        ),
+                    },
+                    epoch,
+                    last_locked_amount: Amount::from(
+                        last_locked_amount.unwrap_or_default(),
+                    ),
+                    last_inflation: Amount::from(
+                        last_inflation.unwrap_or_default(),
+                    ),
+                    masp_epochs_per_year,
+                }))
+            })
+            .try_collect()
+            .await?;
+
+    anyhow::Ok(history.into_iter().flatten().collect())
+}
+
+pub async fn get_masp_commitment_tree(
+    client: &HttpClient,
+    first_block_in_epoch: BlockHeight,
+    epoch: u32,
+) -> anyhow::Result<MaspCommitmentTree> {
+    let commitment_tree_key =
+        namada_token::storage_key::masp_commitment_tree_key();
+
+    let commitment_tree = query_storage_value::<CommitmentTree<Node>>(
+        client,
+        &commitment_tree_key,
+        Some(first_block_in_epoch),
+    )
+    .await
+    .context("Failed to query masp commitment tree")?;
+
+    anyhow::Ok(MaspCommitmentTree {
+        epoch,
+        size: commitment_tree.map_or(0, |tree| tree.size() as u64),
+    })
+}
+
 fn to_epoch(epoch: u32) -> NamadaSdkEpoch {
     NamadaSdkEpoch::from(epoch as u64)
 }
@@ -268,7 +268,11 @@ async fn crawling_fn(
                     )?;
                 }
 
-                repository::pgf::update_pgf(transaction_conn, pgf_payments)?;
+                repository::pgf::update_pgf(
+                    transaction_conn,
+                    pgf_payments,
+                    epoch as u32,
+                )?;
 
                 for (proposal_id, proposal_result) in executed_proposals {
                     repository::governance::update_proposal_result(
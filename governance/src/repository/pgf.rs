@@ -3,14 +3,38 @@ use diesel::query_dsl::methods::FilterDsl;
 use diesel::{
     BoolExpressionMethods, ExpressionMethods, PgConnection, RunQueryDsl,
 };
-use orm::pgf::{PaymentRecurrenceDb, PublicGoodFundingPaymentInsertDb};
-use orm::schema::public_good_funding;
-use shared::pgf::{PaymentRecurrence, PgfPayment};
+use orm::pgf::{
+    PaymentRecurrenceDb, PgfScheduledPaymentInsertDb,
+    PublicGoodFundingPaymentInsertDb,
+};
+use orm::schema::{pgf_scheduled_payments, public_good_funding};
+use shared::pgf::{PaymentRecurrence, PgfPayment, PgfScheduledPayment};
 
 pub fn update_pgf(
     transaction_conn: &mut PgConnection,
     pgf_payments: Vec<PgfPayment>,
+    epoch: u32,
 ) -> anyhow::Result<()> {
+    // Retro payments are paid once, when their proposal is executed
+    diesel::insert_into(pgf_scheduled_payments::table)
+        .values::<Vec<PgfScheduledPaymentInsertDb>>(
+            pgf_payments
+                .iter()
+                .filter(|payment| {
+                    matches!(payment.recurrence, PaymentRecurrence::Retro)
+                })
+                .cloned()
+                .map(|payment| {
+                    PgfScheduledPaymentInsertDb::from_pgf_scheduled_payment(
+                        PgfScheduledPayment::from_payment(payment, epoch),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        )
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert pgf scheduled payments in db")?;
+
     diesel::insert_into(public_good_funding::table)
         .values::<Vec<PublicGoodFundingPaymentInsertDb>>(
             pgf_payments
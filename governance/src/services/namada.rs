@@ -77,6 +77,9 @@ pub async fn get_governance_proposals_updates(
                     abstain_votes: proposal_result
                         .total_abstain_power
                         .to_string_native(),
+                    total_voting_power: proposal_result
+                        .total_voting_power
+                        .to_string_native(),
                 })
             } else {
                 None
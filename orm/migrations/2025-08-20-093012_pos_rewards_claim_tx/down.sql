@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS index_pos_rewards_claim_tx_id;
+
+ALTER TABLE pos_rewards DROP COLUMN IF EXISTS claimed_at_height;
+ALTER TABLE pos_rewards DROP COLUMN IF EXISTS claim_tx_id;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE pos_rewards ADD COLUMN claim_tx_id VARCHAR(64);
+ALTER TABLE pos_rewards ADD COLUMN claimed_at_height INTEGER;
+
+CREATE INDEX index_pos_rewards_claim_tx_id ON pos_rewards (claim_tx_id);
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS index_governance_votes_proposal_id_block_height;
+
+ALTER TABLE governance_votes DROP COLUMN IF EXISTS block_height;
+
+ALTER TABLE governance_proposals DROP COLUMN IF EXISTS total_voting_power;
+ALTER TABLE governance_proposals DROP COLUMN IF EXISTS init_height;
+ALTER TABLE governance_proposals DROP COLUMN IF EXISTS init_tx_id;
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE governance_proposals ADD COLUMN init_tx_id VARCHAR(64);
+ALTER TABLE governance_proposals ADD COLUMN init_height INTEGER;
+ALTER TABLE governance_proposals ADD COLUMN total_voting_power VARCHAR;
+
+ALTER TABLE governance_votes ADD COLUMN block_height INTEGER;
+
+CREATE INDEX index_governance_votes_proposal_id_block_height ON governance_votes (proposal_id, block_height);
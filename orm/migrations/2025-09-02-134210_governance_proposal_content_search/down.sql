@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS index_governance_proposals_search;
+
+ALTER TABLE governance_proposals DROP COLUMN IF EXISTS abstract;
+ALTER TABLE governance_proposals DROP COLUMN IF EXISTS discussions_to;
+ALTER TABLE governance_proposals DROP COLUMN IF EXISTS authors;
+ALTER TABLE governance_proposals DROP COLUMN IF EXISTS title;
@@ -0,0 +1,39 @@
+-- Your SQL goes here
+ALTER TABLE governance_proposals ADD COLUMN title VARCHAR;
+ALTER TABLE governance_proposals ADD COLUMN authors VARCHAR;
+ALTER TABLE governance_proposals ADD COLUMN discussions_to VARCHAR;
+ALTER TABLE governance_proposals ADD COLUMN abstract VARCHAR;
+
+-- Content is free text, so a proposal that only looks like json must not
+-- abort the migration
+CREATE OR REPLACE FUNCTION try_parse_proposal_content(content TEXT)
+RETURNS JSONB AS $$
+BEGIN
+  RETURN content::jsonb;
+EXCEPTION WHEN others THEN
+  RETURN NULL;
+END;
+$$ LANGUAGE plpgsql IMMUTABLE;
+
+UPDATE governance_proposals
+SET
+  title = NULLIF(TRIM(parsed.content ->> 'title'), ''),
+  authors = NULLIF(TRIM(parsed.content ->> 'authors'), ''),
+  discussions_to = NULLIF(TRIM(parsed.content ->> 'discussions-to'), ''),
+  abstract = NULLIF(TRIM(parsed.content ->> 'abstract'), '')
+FROM (
+  SELECT id, try_parse_proposal_content(content) AS content
+  FROM governance_proposals
+  WHERE content LIKE '{%'
+) AS parsed
+WHERE governance_proposals.id = parsed.id
+  AND jsonb_typeof(parsed.content) = 'object';
+
+DROP FUNCTION try_parse_proposal_content(TEXT);
+
+CREATE INDEX index_governance_proposals_search ON governance_proposals USING GIN ((
+  setweight(to_tsvector('english', COALESCE(title, '')), 'A') ||
+  setweight(to_tsvector('english', COALESCE(abstract, '')), 'B') ||
+  setweight(to_tsvector('english', COALESCE(authors, '')), 'C') ||
+  setweight(to_tsvector('english', content), 'D')
+));
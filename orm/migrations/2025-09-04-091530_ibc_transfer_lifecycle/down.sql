@@ -0,0 +1,22 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS index_ibc_ack_tx_hash;
+DROP INDEX IF EXISTS index_ibc_ack_sender;
+DROP INDEX IF EXISTS index_ibc_ack_receiver;
+DROP INDEX IF EXISTS index_ibc_ack_source_channel;
+DROP INDEX IF EXISTS index_ibc_ack_status;
+
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS source_port;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS source_channel;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS dest_port;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS dest_channel;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS sequence;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS timeout_height;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS send_height;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS denom;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS amount;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS sender;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS receiver;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS memo;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS ack_tx_hash;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS ack_height;
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS refund_tx_hash;
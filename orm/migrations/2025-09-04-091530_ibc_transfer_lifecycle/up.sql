@@ -0,0 +1,38 @@
+-- Your SQL goes here
+ALTER TABLE ibc_ack ADD COLUMN source_port VARCHAR;
+ALTER TABLE ibc_ack ADD COLUMN source_channel VARCHAR;
+ALTER TABLE ibc_ack ADD COLUMN dest_port VARCHAR;
+ALTER TABLE ibc_ack ADD COLUMN dest_channel VARCHAR;
+ALTER TABLE ibc_ack ADD COLUMN sequence BIGINT;
+
+-- id is dest_port/dest_channel/source_port/source_channel/sequence
+UPDATE ibc_ack
+SET
+    dest_port = split_part(id, '/', 1),
+    dest_channel = split_part(id, '/', 2),
+    source_port = split_part(id, '/', 3),
+    source_channel = split_part(id, '/', 4),
+    sequence = split_part(id, '/', 5)::BIGINT;
+
+ALTER TABLE ibc_ack ALTER COLUMN source_port SET NOT NULL;
+ALTER TABLE ibc_ack ALTER COLUMN source_channel SET NOT NULL;
+ALTER TABLE ibc_ack ALTER COLUMN dest_port SET NOT NULL;
+ALTER TABLE ibc_ack ALTER COLUMN dest_channel SET NOT NULL;
+ALTER TABLE ibc_ack ALTER COLUMN sequence SET NOT NULL;
+
+ALTER TABLE ibc_ack ADD COLUMN timeout_height VARCHAR;
+ALTER TABLE ibc_ack ADD COLUMN send_height INTEGER;
+ALTER TABLE ibc_ack ADD COLUMN denom VARCHAR;
+ALTER TABLE ibc_ack ADD COLUMN amount NUMERIC(78, 0);
+ALTER TABLE ibc_ack ADD COLUMN sender VARCHAR;
+ALTER TABLE ibc_ack ADD COLUMN receiver VARCHAR;
+ALTER TABLE ibc_ack ADD COLUMN memo VARCHAR;
+ALTER TABLE ibc_ack ADD COLUMN ack_tx_hash VARCHAR;
+ALTER TABLE ibc_ack ADD COLUMN ack_height INTEGER;
+ALTER TABLE ibc_ack ADD COLUMN refund_tx_hash VARCHAR;
+
+CREATE INDEX index_ibc_ack_tx_hash ON ibc_ack (tx_hash);
+CREATE INDEX index_ibc_ack_sender ON ibc_ack (sender);
+CREATE INDEX index_ibc_ack_receiver ON ibc_ack (receiver);
+CREATE INDEX index_ibc_ack_source_channel ON ibc_ack (source_channel);
+CREATE INDEX index_ibc_ack_status ON ibc_ack (status);
@@ -0,0 +1,48 @@
+-- Your SQL goes here
+CREATE TYPE IBC_CONNECTION_STATE AS ENUM (
+    'uninitialized',
+    'init',
+    'try_open',
+    'open'
+);
+
+CREATE TYPE IBC_CHANNEL_STATE AS ENUM (
+    'uninitialized',
+    'init',
+    'try_open',
+    'open',
+    'closed'
+);
+
+CREATE TYPE IBC_CHANNEL_ORDERING AS ENUM ('none', 'unordered', 'ordered');
+
+CREATE TABLE ibc_clients (
+    client_id VARCHAR PRIMARY KEY,
+    chain_id VARCHAR NOT NULL,
+    latest_height VARCHAR NOT NULL,
+    frozen BOOLEAN NOT NULL
+);
+
+CREATE TABLE ibc_connections (
+    connection_id VARCHAR PRIMARY KEY,
+    client_id VARCHAR NOT NULL,
+    state IBC_CONNECTION_STATE NOT NULL,
+    counterparty_client_id VARCHAR NOT NULL,
+    counterparty_connection_id VARCHAR
+);
+
+CREATE INDEX index_ibc_connections_client_id ON ibc_connections (client_id);
+
+CREATE TABLE ibc_channels (
+    port_id VARCHAR NOT NULL,
+    channel_id VARCHAR NOT NULL,
+    connection_id VARCHAR,
+    state IBC_CHANNEL_STATE NOT NULL,
+    ordering IBC_CHANNEL_ORDERING NOT NULL,
+    version VARCHAR NOT NULL,
+    counterparty_port_id VARCHAR NOT NULL,
+    counterparty_channel_id VARCHAR,
+    PRIMARY KEY (port_id, channel_id)
+);
+
+CREATE INDEX index_ibc_channels_channel_id ON ibc_channels (channel_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS index_ibc_ack_pending;
+
+ALTER TABLE ibc_ack DROP COLUMN IF EXISTS stuck;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE ibc_ack ADD COLUMN stuck BOOLEAN NOT NULL DEFAULT false;
+
+CREATE INDEX index_ibc_ack_pending ON ibc_ack (send_height)
+WHERE status = 'unknown' AND ack_tx_hash IS NULL;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS index_unbonds_address_withdrawn_at_height;
+
+-- Withdrawn unbonds were not kept before
+DELETE FROM unbonds WHERE withdrawn_at_height IS NOT NULL;
+
+ALTER TABLE unbonds DROP COLUMN IF EXISTS withdrawn_at_epoch;
+ALTER TABLE unbonds DROP COLUMN IF EXISTS withdrawn_at_height;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE unbonds ADD COLUMN withdrawn_at_height INT;
+ALTER TABLE unbonds ADD COLUMN withdrawn_at_epoch INT;
+
+CREATE INDEX index_unbonds_address_withdrawn_at_height ON unbonds (address, withdrawn_at_height);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+DROP INDEX IF EXISTS index_redelegation_history_delegator_epoch;
+
+DROP TABLE IF EXISTS redelegation_history;
@@ -0,0 +1,16 @@
+-- Your SQL goes here
+
+CREATE TABLE redelegation_history (
+    id SERIAL PRIMARY KEY,
+    inner_tx_id VARCHAR(64) NOT NULL UNIQUE,
+    delegator VARCHAR NOT NULL,
+    src_validator_id INT NOT NULL,
+    dest_validator_id INT NOT NULL,
+    raw_amount NUMERIC(78, 0) NOT NULL,
+    epoch INT NOT NULL,
+    height INT NOT NULL,
+    CONSTRAINT fk_src_validator_id FOREIGN KEY(src_validator_id) REFERENCES validators(id) ON DELETE CASCADE,
+    CONSTRAINT fk_dest_validator_id FOREIGN KEY(dest_validator_id) REFERENCES validators(id) ON DELETE CASCADE
+);
+
+CREATE INDEX index_redelegation_history_delegator_epoch ON redelegation_history (delegator, epoch);
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+
+CREATE TABLE pos_stats (
+    id SERIAL PRIMARY KEY,
+    epoch INT NOT NULL UNIQUE,
+    total_bonded NUMERIC(78, 0) NOT NULL,
+    total_supply NUMERIC(78, 0) NOT NULL,
+    nakamoto_coefficient INT NOT NULL,
+    active_validators INT NOT NULL,
+    jailed_validators INT NOT NULL,
+    inactive_validators INT NOT NULL,
+    median_commission NUMERIC,
+    unbonding_queue NUMERIC(78, 0) NOT NULL
+);
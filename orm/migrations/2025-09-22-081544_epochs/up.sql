@@ -0,0 +1,7 @@
+-- Your SQL goes here
+
+CREATE TABLE epochs (
+    epoch INT PRIMARY KEY,
+    first_block_height INT NOT NULL,
+    timestamp TIMESTAMP
+);
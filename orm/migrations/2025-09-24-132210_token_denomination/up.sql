@@ -0,0 +1,3 @@
+-- Your SQL goes here
+
+ALTER TABLE token ADD COLUMN denomination INT;
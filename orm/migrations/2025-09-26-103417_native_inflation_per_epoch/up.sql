@@ -0,0 +1,9 @@
+-- Your SQL goes here
+
+CREATE TABLE native_inflation_per_epoch (
+    epoch INT PRIMARY KEY,
+    -- native tokens minted as PoS rewards at the start of the epoch
+    pos_minted NUMERIC(78, 0) NOT NULL,
+    -- native tokens minted as MASP rewards at the start of the epoch
+    masp_minted NUMERIC(78, 0) NOT NULL
+);
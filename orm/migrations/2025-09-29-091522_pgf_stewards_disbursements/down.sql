@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+
+DROP INDEX IF EXISTS index_pgf_disbursements_epoch;
+DROP INDEX IF EXISTS index_pgf_disbursements_receipient;
+DROP INDEX IF EXISTS index_pgf_disbursements_proposal_id_receipient_epoch;
+DROP TABLE IF EXISTS pgf_disbursements;
+
+DROP INDEX IF EXISTS index_pgf_stewards_address_epoch;
+DROP TABLE IF EXISTS pgf_stewards;
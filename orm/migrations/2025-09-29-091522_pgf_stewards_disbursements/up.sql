@@ -0,0 +1,25 @@
+-- Your SQL goes here
+
+CREATE TABLE pgf_stewards (
+    id SERIAL PRIMARY KEY,
+    address VARCHAR(45) NOT NULL,
+    is_steward BOOLEAN NOT NULL,
+    -- epoch at which the address joined or left the steward set
+    epoch INT NOT NULL
+);
+
+CREATE UNIQUE INDEX index_pgf_stewards_address_epoch ON pgf_stewards (address, epoch);
+
+CREATE TABLE pgf_disbursements (
+    id SERIAL PRIMARY KEY,
+    proposal_id INT NOT NULL,
+    payment_recurrence PAYMENT_RECURRENCE NOT NULL,
+    payment_kind PAYMENT_KIND NOT NULL,
+    receipient VARCHAR NOT NULL,
+    amount NUMERIC(78, 0) NOT NULL,
+    epoch INT NOT NULL
+);
+
+CREATE UNIQUE INDEX index_pgf_disbursements_proposal_id_receipient_epoch ON pgf_disbursements (proposal_id, receipient, epoch);
+CREATE INDEX index_pgf_disbursements_receipient ON pgf_disbursements (receipient);
+CREATE INDEX index_pgf_disbursements_epoch ON pgf_disbursements (epoch);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS index_masp_pool_flows_token_address_timestamp;
+DROP INDEX IF EXISTS index_masp_pool_flows_token_address_epoch_timestamp;
+
+DROP TABLE IF EXISTS masp_pool_flows;
@@ -0,0 +1,27 @@
+-- Your SQL goes here
+CREATE TABLE masp_pool_flows (
+    id SERIAL PRIMARY KEY,
+    token_address VARCHAR(45) NOT NULL,
+    epoch INT NOT NULL,
+    timestamp TIMESTAMP NOT NULL,
+    inflow NUMERIC(78, 0) NOT NULL DEFAULT 0,
+    outflow NUMERIC(78, 0) NOT NULL DEFAULT 0
+);
+
+CREATE UNIQUE INDEX index_masp_pool_flows_token_address_epoch_timestamp ON masp_pool_flows (token_address, epoch, timestamp);
+CREATE INDEX index_masp_pool_flows_token_address_timestamp ON masp_pool_flows (token_address, timestamp);
+
+-- Backfill the flows of the entries that were already crawled
+INSERT INTO masp_pool_flows (token_address, epoch, timestamp, inflow, outflow)
+SELECT
+    masp_pool.token_address,
+    blocks.epoch,
+    date_trunc('hour', masp_pool.timestamp),
+    COALESCE(SUM(masp_pool.raw_amount) FILTER (WHERE masp_pool.direction = 'in'), 0),
+    COALESCE(SUM(masp_pool.raw_amount) FILTER (WHERE masp_pool.direction = 'out'), 0)
+FROM masp_pool
+JOIN inner_transactions ON inner_transactions.id = masp_pool.inner_tx_id
+JOIN wrapper_transactions ON wrapper_transactions.id = inner_transactions.wrapper_id
+JOIN blocks ON blocks.height = wrapper_transactions.block_height
+WHERE blocks.epoch IS NOT NULL
+GROUP BY masp_pool.token_address, blocks.epoch, date_trunc('hour', masp_pool.timestamp);
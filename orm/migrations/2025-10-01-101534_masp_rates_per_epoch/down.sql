@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS index_masp_rates_per_epoch_epoch;
+
+DROP TABLE IF EXISTS masp_rates_per_epoch;
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE masp_rates_per_epoch (
+    token VARCHAR NOT NULL,
+    epoch INT NOT NULL,
+    max_reward_rate VARCHAR NOT NULL,
+    kp_gain VARCHAR NOT NULL,
+    kd_gain VARCHAR NOT NULL,
+    locked_amount_target NUMERIC(78, 0) NOT NULL,
+    last_locked_amount NUMERIC(78, 0) NOT NULL,
+    last_inflation NUMERIC(78, 0) NOT NULL,
+    masp_epochs_per_year INT NOT NULL,
+    PRIMARY KEY (token, epoch)
+);
+
+CREATE INDEX index_masp_rates_per_epoch_epoch ON masp_rates_per_epoch (epoch);
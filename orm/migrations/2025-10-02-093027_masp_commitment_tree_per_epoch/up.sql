@@ -0,0 +1,5 @@
+-- Your SQL goes here
+CREATE TABLE masp_commitment_tree_per_epoch (
+    epoch INT PRIMARY KEY,
+    size BIGINT NOT NULL
+);
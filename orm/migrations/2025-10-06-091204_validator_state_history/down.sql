@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+DROP INDEX IF EXISTS index_validator_state_history_epoch;
+
+DROP TABLE IF EXISTS validator_state_history;
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE validator_state_history (
+    validator_id INT NOT NULL,
+    epoch INT NOT NULL,
+    state VALIDATOR_STATE NOT NULL,
+    PRIMARY KEY (validator_id, epoch),
+    CONSTRAINT fk_validator_id FOREIGN KEY(validator_id) REFERENCES validators(id) ON DELETE CASCADE
+);
+
+CREATE INDEX index_validator_state_history_epoch ON validator_state_history (epoch);
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+
+-- ibc_msg_transfer used to be filled with the number of transparent transfers
+UPDATE gas_estimations
+SET ibc_msg_transfer = (
+    SELECT COUNT(*)
+    FROM inner_transactions
+    WHERE inner_transactions.wrapper_id = gas_estimations.wrapper_id
+      AND inner_transactions.kind = 'ibc_transparent_transfer'
+);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS index_pos_reward_claims_owner;
+
+DROP TABLE IF EXISTS pos_reward_claims;
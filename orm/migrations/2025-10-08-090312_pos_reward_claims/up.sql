@@ -0,0 +1,22 @@
+-- Your SQL goes here
+CREATE TABLE pos_reward_claims (
+    id SERIAL PRIMARY KEY,
+    claim_tx_id VARCHAR(64) NOT NULL,
+    owner VARCHAR NOT NULL,
+    validator_id INT NOT NULL,
+    raw_amount NUMERIC(78, 0) NOT NULL,
+    epoch INT,
+    block_height INT NOT NULL,
+    CONSTRAINT fk_validator_id FOREIGN KEY(validator_id) REFERENCES validators(id) ON DELETE CASCADE,
+    CONSTRAINT pos_reward_claims_claim_tx_id_validator_id_key UNIQUE (claim_tx_id, validator_id)
+);
+
+CREATE INDEX index_pos_reward_claims_owner ON pos_reward_claims (owner);
+
+-- The latest reward row cleared by each claim holds the claimed amount
+INSERT INTO pos_reward_claims (claim_tx_id, owner, validator_id, raw_amount, epoch, block_height)
+SELECT DISTINCT ON (claim_tx_id, validator_id)
+    claim_tx_id, owner, validator_id, raw_amount, epoch, claimed_at_height
+FROM pos_rewards
+WHERE claim_tx_id IS NOT NULL AND claimed_at_height IS NOT NULL
+ORDER BY claim_tx_id, validator_id, epoch DESC;
@@ -0,0 +1,35 @@
+-- Your SQL goes here
+CREATE VIEW validator_delegators AS
+SELECT
+    COALESCE(b.address, u.address) AS address,
+    COALESCE(b.validator_id, u.validator_id) AS validator_id,
+    COALESCE(b.bonded, 0) AS bonded,
+    COALESCE(u.unbonding, 0) AS unbonding,
+    b.start_epoch
+FROM
+    (
+        SELECT
+            address,
+            validator_id,
+            SUM(raw_amount) AS bonded,
+            MIN(start) AS start_epoch
+        FROM
+            bonds
+        GROUP BY
+            address,
+            validator_id
+    ) b
+    FULL OUTER JOIN (
+        SELECT
+            address,
+            validator_id,
+            SUM(raw_amount) AS unbonding
+        FROM
+            unbonds
+        WHERE
+            withdrawn_at_height IS NULL
+        GROUP BY
+            address,
+            validator_id
+    ) u ON b.address = u.address
+    AND b.validator_id = u.validator_id;
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+
+-- Backfilled epochs are kept, they are recorded the same way by the crawler
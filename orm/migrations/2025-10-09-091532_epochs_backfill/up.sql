@@ -0,0 +1,12 @@
+-- Your SQL goes here
+
+-- Epochs started before the crawler was deployed, a block opens an epoch when
+-- the previous block belongs to an earlier one
+INSERT INTO epochs (epoch, first_block_height, timestamp)
+SELECT DISTINCT ON (blocks.epoch) blocks.epoch, blocks.height, blocks.timestamp
+FROM blocks
+LEFT JOIN blocks previous ON previous.height = blocks.height - 1
+WHERE blocks.epoch IS NOT NULL
+  AND (blocks.height = 1 OR previous.epoch < blocks.epoch)
+ORDER BY blocks.epoch, blocks.height
+ON CONFLICT (epoch) DO NOTHING;
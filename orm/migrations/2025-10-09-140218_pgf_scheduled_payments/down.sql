@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+
+ALTER INDEX index_pgf_scheduled_payments_epoch RENAME TO index_pgf_disbursements_epoch;
+ALTER INDEX index_pgf_scheduled_payments_receipient RENAME TO index_pgf_disbursements_receipient;
+ALTER INDEX index_pgf_scheduled_payments_proposal_id_receipient_epoch RENAME TO index_pgf_disbursements_proposal_id_receipient_epoch;
+ALTER INDEX pgf_scheduled_payments_pkey RENAME TO pgf_disbursements_pkey;
+ALTER SEQUENCE pgf_scheduled_payments_id_seq RENAME TO pgf_disbursements_id_seq;
+ALTER TABLE pgf_scheduled_payments RENAME TO pgf_disbursements;
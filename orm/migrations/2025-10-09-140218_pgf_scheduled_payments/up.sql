@@ -0,0 +1,9 @@
+-- Your SQL goes here
+
+-- Continuous rows are the fundings due at the epoch, not observed transfers
+ALTER TABLE pgf_disbursements RENAME TO pgf_scheduled_payments;
+ALTER SEQUENCE pgf_disbursements_id_seq RENAME TO pgf_scheduled_payments_id_seq;
+ALTER INDEX pgf_disbursements_pkey RENAME TO pgf_scheduled_payments_pkey;
+ALTER INDEX index_pgf_disbursements_proposal_id_receipient_epoch RENAME TO index_pgf_scheduled_payments_proposal_id_receipient_epoch;
+ALTER INDEX index_pgf_disbursements_receipient RENAME TO index_pgf_scheduled_payments_receipient;
+ALTER INDEX index_pgf_disbursements_epoch RENAME TO index_pgf_scheduled_payments_epoch;
@@ -0,0 +1,14 @@
+use diesel::{Insertable, Queryable, Selectable};
+
+use crate::schema::epochs;
+
+#[derive(Insertable, Clone, Queryable, Selectable, Debug, PartialEq)]
+#[diesel(table_name = epochs)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct EpochDb {
+    pub epoch: i32,
+    pub first_block_height: i32,
+    pub timestamp: Option<chrono::NaiveDateTime>,
+}
+
+pub type EpochInsertDb = EpochDb;
@@ -2,8 +2,8 @@ use diesel::query_builder::AsChangeset;
 use diesel::{Insertable, Queryable, Selectable};
 use serde::{Deserialize, Serialize};
 use shared::proposal::{
-    GovernanceProposal, GovernanceProposalKind, GovernanceProposalResult,
-    GovernanceProposalStatus, TallyType,
+    GovernanceProposal, GovernanceProposalContent, GovernanceProposalKind,
+    GovernanceProposalResult, GovernanceProposalStatus, TallyType,
 };
 
 use crate::schema::governance_proposals;
@@ -109,6 +109,13 @@ pub struct GovernanceProposalDb {
     pub nay_votes: String,
     pub abstain_votes: String,
     pub result: GovernanceProposalResultDb,
+    pub init_tx_id: Option<String>,
+    pub init_height: Option<i32>,
+    pub total_voting_power: Option<String>,
+    pub title: Option<String>,
+    pub authors: Option<String>,
+    pub discussions_to: Option<String>,
+    pub abstract_: Option<String>,
 }
 
 #[derive(Serialize, Insertable, Clone)]
@@ -124,6 +131,12 @@ pub struct GovernanceProposalInsertDb {
     pub start_epoch: i32,
     pub end_epoch: i32,
     pub activation_epoch: i32,
+    pub init_tx_id: Option<String>,
+    pub init_height: Option<i32>,
+    pub title: Option<String>,
+    pub authors: Option<String>,
+    pub discussions_to: Option<String>,
+    pub abstract_: Option<String>,
 }
 
 impl GovernanceProposalInsertDb {
@@ -131,6 +144,8 @@ impl GovernanceProposalInsertDb {
         proposal: GovernanceProposal,
         tally_type: TallyType,
     ) -> Self {
+        let content = GovernanceProposalContent::from_json(&proposal.content);
+
         Self {
             id: proposal.id as i32,
             content: proposal.content,
@@ -141,6 +156,12 @@ impl GovernanceProposalInsertDb {
             start_epoch: proposal.voting_start_epoch as i32,
             end_epoch: proposal.voting_end_epoch as i32,
             activation_epoch: proposal.activation_epoch as i32,
+            init_tx_id: proposal.init_tx_id.map(|id| id.to_string()),
+            init_height: proposal.init_height.map(|height| height as i32),
+            title: content.title,
+            authors: content.authors,
+            discussions_to: content.discussions_to,
+            abstract_: content.r#abstract,
         }
     }
 }
@@ -152,6 +173,7 @@ pub struct GovernanceProposalUpdateStatusDb {
     pub yay_votes: String,
     pub nay_votes: String,
     pub abstain_votes: String,
+    pub total_voting_power: Option<String>,
     pub result: GovernanceProposalResultDb,
 }
 
@@ -161,6 +183,7 @@ impl From<GovernanceProposalStatus> for GovernanceProposalUpdateStatusDb {
             yay_votes: value.yay_votes,
             nay_votes: value.nay_votes,
             abstain_votes: value.abstain_votes,
+            total_voting_power: Some(value.total_voting_power),
             result: value.result.into(),
         }
     }
@@ -182,6 +205,11 @@ pub struct GovernanceProposalNoDataDb {
     pub nay_votes: String,
     pub abstain_votes: String,
     pub result: GovernanceProposalResultDb,
+    pub total_voting_power: Option<String>,
+    pub title: Option<String>,
+    pub authors: Option<String>,
+    pub discussions_to: Option<String>,
+    pub abstract_: Option<String>,
 }
 
 #[derive(Serialize, Queryable, Selectable, Insertable, Clone)]
@@ -32,6 +32,7 @@ pub struct GovernanceProposalVoteDb {
     pub voter_address: String,
     pub kind: GovernanceVoteKindDb,
     pub proposal_id: i32,
+    pub block_height: Option<i32>,
 }
 
 #[derive(Serialize, Insertable, Clone)]
@@ -41,6 +42,7 @@ pub struct GovernanceProposalVoteInsertDb {
     pub voter_address: String,
     pub kind: GovernanceVoteKindDb,
     pub proposal_id: i32,
+    pub block_height: Option<i32>,
 }
 
 impl GovernanceProposalVoteInsertDb {
@@ -49,6 +51,7 @@ impl GovernanceProposalVoteInsertDb {
             voter_address: vote.address.to_string(),
             kind: vote.vote.into(),
             proposal_id: vote.proposal_id as i32,
+            block_height: vote.height.map(|height| height as i32),
         }
     }
 }
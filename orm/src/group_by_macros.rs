@@ -1,7 +1,10 @@
 use diesel::allow_columns_to_appear_in_same_group_by_clause;
 use diesel::expression::{SqlLiteral, ValidGrouping};
 
-use crate::schema::{bonds, redelegation, unbonds, validators};
+use crate::schema::{
+    blocks, bonds, governance_votes, ibc_ack, ibc_token, redelegation, unbonds,
+    validators,
+};
 
 // For find_merged_bonds_by_address
 allow_columns_to_appear_in_same_group_by_clause!(
@@ -21,6 +24,20 @@ allow_columns_to_appear_in_same_group_by_clause!(
     validators::state,
 );
 
+// For find_governance_proposal_vote_counts_by_epoch
+allow_columns_to_appear_in_same_group_by_clause!(
+    blocks::epoch,
+    governance_votes::kind,
+);
+
+// For get_ibc_channel_outflows
+allow_columns_to_appear_in_same_group_by_clause!(
+    ibc_ack::source_port,
+    ibc_ack::source_channel,
+    ibc_ack::denom,
+    ibc_token::address,
+);
+
 macro_rules! impl_valid_grouping {
     ($valid_grouping_type:ty, $column:path) => {
         impl ValidGrouping<$valid_grouping_type> for $column {
@@ -1,11 +1,21 @@
+use std::num::ParseIntError;
+
 use bigdecimal::BigDecimal;
 use diesel::prelude::Queryable;
 use diesel::{AsChangeset, Insertable, Selectable};
 use serde::{Deserialize, Serialize};
+use shared::block::BlockHeight;
+use shared::ibc::{
+    IbcChannel, IbcChannelOrdering, IbcChannelState, IbcClient, IbcConnection,
+    IbcConnectionState,
+};
 use shared::token::IbcRateLimit;
-use shared::transaction::{IbcAckStatus, IbcSequence};
+use shared::transaction::{IbcAck, IbcAckStatus, IbcSequence};
 
-use crate::schema::{ibc_ack, ibc_rate_limits, ibc_token_flows};
+use crate::schema::{
+    ibc_ack, ibc_channels, ibc_clients, ibc_connections, ibc_rate_limits,
+    ibc_token_flows,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
 #[ExistingTypePath = "crate::schema::sql_types::IbcStatus"]
@@ -27,7 +37,7 @@ impl From<IbcAckStatus> for IbcAckStatusDb {
     }
 }
 
-#[derive(Serialize, Queryable, Insertable, Selectable, Clone, Debug)]
+#[derive(Queryable, Insertable, Selectable, Clone, Debug)]
 #[diesel(table_name = ibc_ack)]
 #[diesel(check_for_backend(diesel::pg::Pg))]
 pub struct IbcAckDb {
@@ -35,18 +45,54 @@ pub struct IbcAckDb {
     pub tx_hash: String,
     pub timeout: i64,
     pub status: IbcAckStatusDb,
+    pub source_port: String,
+    pub source_channel: String,
+    pub dest_port: String,
+    pub dest_channel: String,
+    pub sequence: i64,
+    pub timeout_height: Option<String>,
+    pub send_height: Option<i32>,
+    pub denom: Option<String>,
+    pub amount: Option<BigDecimal>,
+    pub sender: Option<String>,
+    pub receiver: Option<String>,
+    pub memo: Option<String>,
+    pub ack_tx_hash: Option<String>,
+    pub ack_height: Option<i32>,
+    pub refund_tx_hash: Option<String>,
+    pub stuck: bool,
 }
 
 pub type IbcAckInsertDb = IbcAckDb;
 
-impl From<IbcSequence> for IbcAckInsertDb {
-    fn from(value: IbcSequence) -> Self {
-        Self {
+impl TryFrom<IbcSequence> for IbcAckInsertDb {
+    type Error = ParseIntError;
+
+    fn try_from(value: IbcSequence) -> Result<Self, Self::Error> {
+        let sequence = value.sequence_number.parse()?;
+
+        Ok(Self {
             id: value.id(),
             tx_hash: value.tx_id.to_string(),
             timeout: value.timeout as i64,
             status: IbcAckStatusDb::Unknown,
-        }
+            source_port: value.source_port,
+            source_channel: value.source_channel,
+            dest_port: value.dest_port,
+            dest_channel: value.dest_channel,
+            sequence,
+            timeout_height: Some(value.timeout_height),
+            send_height: Some(value.height as i32),
+            denom: value.data.as_ref().map(|data| data.denom.clone()),
+            amount: value.data.as_ref().map(|data| data.amount.clone()),
+            sender: value.data.as_ref().map(|data| data.sender.clone()),
+            receiver: value.data.as_ref().map(|data| data.receiver.clone()),
+            memo: value.data.map(|data| data.memo),
+            ack_tx_hash: None,
+            ack_height: None,
+            refund_tx_hash: None,
+            stuck: false,
+        })
     }
 }
 
@@ -55,6 +101,22 @@ impl From<IbcSequence> for IbcAckInsertDb {
 #[diesel(check_for_backend(diesel::pg::Pg))]
 pub struct IbcSequencekStatusUpdateDb {
     pub status: IbcAckStatusDb,
+    pub ack_tx_hash: Option<String>,
+    pub ack_height: Option<i32>,
+    pub refund_tx_hash: Option<String>,
+    pub stuck: bool,
+}
+
+impl From<(IbcAck, BlockHeight)> for IbcSequencekStatusUpdateDb {
+    fn from((ack, height): (IbcAck, BlockHeight)) -> Self {
+        Self {
+            status: IbcAckStatusDb::from(ack.status.clone()),
+            ack_tx_hash: Some(ack.tx_id.to_string()),
+            ack_height: Some(height as i32),
+            refund_tx_hash: ack.is_refund().then(|| ack.tx_id.to_string()),
+            stuck: false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Queryable, Selectable)]
@@ -106,3 +168,142 @@ pub struct IbcTokenFlowsInsertDb {
     pub deposit: BigDecimal,
     pub withdraw: BigDecimal,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::IbcConnectionState"]
+pub enum IbcConnectionStateDb {
+    Uninitialized,
+    Init,
+    TryOpen,
+    Open,
+}
+
+impl From<IbcConnectionState> for IbcConnectionStateDb {
+    fn from(value: IbcConnectionState) -> Self {
+        match value {
+            IbcConnectionState::Uninitialized => Self::Uninitialized,
+            IbcConnectionState::Init => Self::Init,
+            IbcConnectionState::TryOpen => Self::TryOpen,
+            IbcConnectionState::Open => Self::Open,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::IbcChannelState"]
+pub enum IbcChannelStateDb {
+    Uninitialized,
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+impl From<IbcChannelState> for IbcChannelStateDb {
+    fn from(value: IbcChannelState) -> Self {
+        match value {
+            IbcChannelState::Uninitialized => Self::Uninitialized,
+            IbcChannelState::Init => Self::Init,
+            IbcChannelState::TryOpen => Self::TryOpen,
+            IbcChannelState::Open => Self::Open,
+            IbcChannelState::Closed => Self::Closed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::IbcChannelOrdering"]
+pub enum IbcChannelOrderingDb {
+    None,
+    Unordered,
+    Ordered,
+}
+
+impl From<IbcChannelOrdering> for IbcChannelOrderingDb {
+    fn from(value: IbcChannelOrdering) -> Self {
+        match value {
+            IbcChannelOrdering::None => Self::None,
+            IbcChannelOrdering::Unordered => Self::Unordered,
+            IbcChannelOrdering::Ordered => Self::Ordered,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = ibc_clients)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IbcClientDb {
+    pub client_id: String,
+    pub chain_id: String,
+    pub latest_height: String,
+    pub frozen: bool,
+}
+
+pub type IbcClientInsertDb = IbcClientDb;
+
+impl From<IbcClient> for IbcClientInsertDb {
+    fn from(value: IbcClient) -> Self {
+        Self {
+            client_id: value.client_id,
+            chain_id: value.chain_id,
+            latest_height: value.latest_height,
+            frozen: value.frozen,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = ibc_connections)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IbcConnectionDb {
+    pub connection_id: String,
+    pub client_id: String,
+    pub state: IbcConnectionStateDb,
+    pub counterparty_client_id: String,
+    pub counterparty_connection_id: Option<String>,
+}
+
+pub type IbcConnectionInsertDb = IbcConnectionDb;
+
+impl From<IbcConnection> for IbcConnectionInsertDb {
+    fn from(value: IbcConnection) -> Self {
+        Self {
+            connection_id: value.connection_id,
+            client_id: value.client_id,
+            state: IbcConnectionStateDb::from(value.state),
+            counterparty_client_id: value.counterparty_client_id,
+            counterparty_connection_id: value.counterparty_connection_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = ibc_channels)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IbcChannelDb {
+    pub port_id: String,
+    pub channel_id: String,
+    pub connection_id: Option<String>,
+    pub state: IbcChannelStateDb,
+    pub ordering: IbcChannelOrderingDb,
+    pub version: String,
+    pub counterparty_port_id: String,
+    pub counterparty_channel_id: Option<String>,
+}
+
+pub type IbcChannelInsertDb = IbcChannelDb;
+
+impl From<IbcChannel> for IbcChannelInsertDb {
+    fn from(value: IbcChannel) -> Self {
+        Self {
+            port_id: value.port_id,
+            channel_id: value.channel_id,
+            connection_id: value.connection_id,
+            state: IbcChannelStateDb::from(value.state),
+            ordering: IbcChannelOrderingDb::from(value.ordering),
+            version: value.version,
+            counterparty_port_id: value.counterparty_port_id,
+            counterparty_channel_id: value.counterparty_channel_id,
+        }
+    }
+}
@@ -3,6 +3,7 @@ pub mod blocks;
 pub mod bond;
 pub mod cometbft;
 pub mod crawler_state;
+pub mod epochs;
 pub mod gas;
 pub mod governance_proposal;
 pub mod governance_votes;
@@ -11,9 +12,11 @@ pub mod helpers;
 pub mod ibc;
 pub mod masp;
 pub mod migrations;
+pub mod native_inflation_per_epoch;
 pub mod parameters;
 pub mod pgf;
 pub mod pos_rewards;
+pub mod pos_stats;
 pub mod redelegation;
 pub mod revealed_pk;
 pub mod schema;
@@ -2,9 +2,15 @@ use std::str::FromStr;
 
 use bigdecimal::BigDecimal;
 use diesel::{Insertable, Queryable, Selectable};
-use shared::masp::{MaspEntry, MaspEntryDirection, MaspRewardData};
+use shared::masp::{
+    MaspCommitmentTree, MaspEntry, MaspEntryDirection, MaspRewardData,
+    MaspRewardHistory,
+};
 
-use crate::schema::{masp_pool, masp_pool_aggregate, masp_rates};
+use crate::schema::{
+    masp_commitment_tree_per_epoch, masp_pool, masp_pool_aggregate,
+    masp_pool_flows, masp_rates, masp_rates_per_epoch,
+};
 
 #[derive(Debug, Clone, diesel_derive_enum::DbEnum)]
 #[ExistingTypePath = "crate::schema::sql_types::MaspPoolDirection"]
@@ -13,7 +19,7 @@ pub enum MaspPoolDirectionDb {
     Out,
 }
 
-#[derive(Insertable, Clone, Debug)]
+#[derive(Insertable, Queryable, Selectable, Clone, Debug)]
 #[diesel(table_name = masp_pool)]
 #[diesel(check_for_backend(diesel::pg::Pg))]
 pub struct MaspDb {
@@ -26,6 +32,19 @@ pub struct MaspDb {
 
 pub type MaspInsertDb = MaspDb;
 
+#[derive(Insertable, Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = masp_pool_flows)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct MaspPoolFlowDb {
+    pub token_address: String,
+    pub epoch: i32,
+    pub timestamp: chrono::NaiveDateTime,
+    pub inflow: BigDecimal,
+    pub outflow: BigDecimal,
+}
+
+pub type MaspPoolFlowInsertDb = MaspPoolFlowDb;
+
 #[derive(Debug, Clone, diesel_derive_enum::DbEnum)]
 #[ExistingTypePath = "crate::schema::sql_types::MaspPoolAggregateWindow"]
 pub enum MaspPoolAggregateWindowDb {
@@ -102,3 +121,63 @@ impl From<MaspRewardData> for MaspRewardDataInsertDb {
         }
     }
 }
+
+#[derive(Insertable, Clone, Queryable, Selectable, Debug)]
+#[diesel(table_name = masp_rates_per_epoch)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct MaspRewardHistoryDb {
+    pub token: String,
+    pub epoch: i32,
+    pub max_reward_rate: String,
+    pub kp_gain: String,
+    pub kd_gain: String,
+    pub locked_amount_target: BigDecimal,
+    pub last_locked_amount: BigDecimal,
+    pub last_inflation: BigDecimal,
+    pub masp_epochs_per_year: i32,
+}
+
+pub type MaspRewardHistoryInsertDb = MaspRewardHistoryDb;
+
+impl From<MaspRewardHistory> for MaspRewardHistoryInsertDb {
+    fn from(value: MaspRewardHistory) -> Self {
+        let reward_data = MaspRewardDataInsertDb::from(value.reward_data);
+
+        MaspRewardHistoryInsertDb {
+            token: reward_data.token,
+            epoch: value.epoch as i32,
+            max_reward_rate: reward_data.max_reward_rate,
+            kp_gain: reward_data.kp_gain,
+            kd_gain: reward_data.kd_gain,
+            locked_amount_target: reward_data.locked_amount_target,
+            last_locked_amount: BigDecimal::from_str(
+                &value.last_locked_amount.to_string(),
+            )
+            .expect("Invalid last locked amount"),
+            last_inflation: BigDecimal::from_str(
+                &value.last_inflation.to_string(),
+            )
+            .expect("Invalid last inflation"),
+            masp_epochs_per_year: value.masp_epochs_per_year as i32,
+        }
+    }
+}
+
+#[derive(Insertable, Clone, Queryable, Selectable, Debug)]
+#[diesel(table_name = masp_commitment_tree_per_epoch)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct MaspCommitmentTreeDb {
+    pub epoch: i32,
+    pub size: i64,
+}
+
+pub type MaspCommitmentTreeInsertDb = MaspCommitmentTreeDb;
+
+impl From<MaspCommitmentTree> for MaspCommitmentTreeInsertDb {
+    fn from(value: MaspCommitmentTree) -> Self {
+        MaspCommitmentTreeInsertDb {
+            epoch: value.epoch as i32,
+            size: value.size as i64,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use bigdecimal::BigDecimal;
+use diesel::{Insertable, Queryable, Selectable};
+use shared::balance::NativeInflation as SharedNativeInflation;
+
+use crate::schema::native_inflation_per_epoch;
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = native_inflation_per_epoch)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct NativeInflationDb {
+    pub epoch: i32,
+    pub pos_minted: BigDecimal,
+    pub masp_minted: BigDecimal,
+}
+
+pub type NativeInflationInsertDb = NativeInflationDb;
+
+impl From<SharedNativeInflation> for NativeInflationInsertDb {
+    fn from(inflation: SharedNativeInflation) -> Self {
+        Self {
+            epoch: inflation.epoch,
+            pos_minted: inflation.pos,
+            masp_minted: inflation.masp,
+        }
+    }
+}
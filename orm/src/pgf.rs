@@ -3,9 +3,13 @@ use std::str::FromStr;
 use bigdecimal::BigDecimal;
 use diesel::{Insertable, Queryable};
 use serde::{Deserialize, Serialize};
-use shared::pgf::{PaymentKind, PaymentRecurrence, PgfPayment};
+use shared::pgf::{
+    PaymentKind, PaymentRecurrence, PgfPayment, PgfScheduledPayment,
+};
 
-use crate::schema::public_good_funding;
+use crate::schema::{
+    pgf_scheduled_payments, pgf_stewards, public_good_funding,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
 #[ExistingTypePath = "crate::schema::sql_types::PaymentRecurrence"]
@@ -66,3 +70,46 @@ impl PublicGoodFundingPaymentInsertDb {
         }
     }
 }
+
+#[derive(Insertable, Clone, Queryable, diesel::Selectable, Debug)]
+#[diesel(table_name = pgf_scheduled_payments)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PgfScheduledPaymentDb {
+    pub proposal_id: i32,
+    pub payment_recurrence: PaymentRecurrenceDb,
+    pub payment_kind: PaymentKindDb,
+    pub receipient: String,
+    pub amount: BigDecimal,
+    pub epoch: i32,
+}
+
+pub type PgfScheduledPaymentInsertDb = PgfScheduledPaymentDb;
+
+impl PgfScheduledPaymentInsertDb {
+    pub fn from_pgf_scheduled_payment(
+        scheduled_payment: PgfScheduledPayment,
+    ) -> Self {
+        Self {
+            proposal_id: scheduled_payment.proposal_id as i32,
+            payment_recurrence: PaymentRecurrenceDb::from(
+                scheduled_payment.recurrence,
+            ),
+            payment_kind: PaymentKindDb::from(scheduled_payment.kind),
+            receipient: scheduled_payment.receipient.to_string(),
+            amount: BigDecimal::from_str(&scheduled_payment.amount.to_string())
+                .expect("Invalid amount"),
+            epoch: scheduled_payment.epoch as i32,
+        }
+    }
+}
+
+#[derive(Insertable, Clone, Queryable, diesel::Selectable, Debug)]
+#[diesel(table_name = pgf_stewards)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PgfStewardDb {
+    pub address: String,
+    pub is_steward: bool,
+    pub epoch: i32,
+}
+
+pub type PgfStewardInsertDb = PgfStewardDb;
@@ -4,7 +4,7 @@ use bigdecimal::BigDecimal;
 use diesel::{Insertable, Queryable, Selectable};
 use shared::rewards::Reward;
 
-use crate::schema::pos_rewards;
+use crate::schema::{pos_reward_claims, pos_rewards};
 
 #[derive(Insertable, Queryable, Selectable, Clone)]
 #[diesel(table_name = pos_rewards)]
@@ -17,7 +17,42 @@ pub struct PosRewardInsertDb {
     pub claimed: bool,
 }
 
-pub type PoSRewardDb = PosRewardInsertDb;
+#[derive(Queryable, Selectable, Clone)]
+#[diesel(table_name = pos_rewards)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PoSRewardDb {
+    pub owner: String,
+    pub validator_id: i32,
+    pub raw_amount: BigDecimal,
+    pub epoch: i32,
+    pub claimed: bool,
+    pub claim_tx_id: Option<String>,
+    pub claimed_at_height: Option<i32>,
+}
+
+#[derive(Insertable, Clone)]
+#[diesel(table_name = pos_reward_claims)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PosRewardClaimInsertDb {
+    pub claim_tx_id: String,
+    pub owner: String,
+    pub validator_id: i32,
+    pub raw_amount: BigDecimal,
+    pub epoch: Option<i32>,
+    pub block_height: i32,
+}
+
+#[derive(Queryable, Selectable, Clone)]
+#[diesel(table_name = pos_reward_claims)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PosRewardClaimDb {
+    pub claim_tx_id: String,
+    pub owner: String,
+    pub validator_id: i32,
+    pub raw_amount: BigDecimal,
+    pub epoch: Option<i32>,
+    pub block_height: i32,
+}
 
 impl PosRewardInsertDb {
     pub fn from_reward(reward: Reward, validator_id: i32, epoch: i32) -> Self {
@@ -0,0 +1,53 @@
+use bigdecimal::BigDecimal;
+use diesel::{AsChangeset, Insertable, Queryable, Selectable};
+use shared::pos::PosStats;
+
+use crate::schema::pos_stats;
+
+#[derive(Insertable, AsChangeset, Clone, Queryable, Selectable)]
+#[diesel(table_name = pos_stats)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(treat_none_as_null = true)]
+pub struct PosStatsInsertDb {
+    pub epoch: i32,
+    pub total_bonded: BigDecimal,
+    pub total_supply: BigDecimal,
+    pub nakamoto_coefficient: i32,
+    pub active_validators: i32,
+    pub jailed_validators: i32,
+    pub inactive_validators: i32,
+    pub median_commission: Option<BigDecimal>,
+    pub unbonding_queue: BigDecimal,
+}
+
+#[derive(Clone, Queryable, Selectable)]
+#[diesel(table_name = pos_stats)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PosStatsDb {
+    pub id: i32,
+    pub epoch: i32,
+    pub total_bonded: BigDecimal,
+    pub total_supply: BigDecimal,
+    pub nakamoto_coefficient: i32,
+    pub active_validators: i32,
+    pub jailed_validators: i32,
+    pub inactive_validators: i32,
+    pub median_commission: Option<BigDecimal>,
+    pub unbonding_queue: BigDecimal,
+}
+
+impl PosStatsInsertDb {
+    pub fn from_pos_stats(stats: PosStats) -> Self {
+        Self {
+            epoch: stats.epoch as i32,
+            total_bonded: stats.total_bonded.into(),
+            total_supply: stats.total_supply.into(),
+            nakamoto_coefficient: stats.nakamoto_coefficient as i32,
+            active_validators: stats.active_validators as i32,
+            jailed_validators: stats.jailed_validators as i32,
+            inactive_validators: stats.inactive_validators as i32,
+            median_commission: stats.median_commission,
+            unbonding_queue: stats.unbonding_queue.into(),
+        }
+    }
+}
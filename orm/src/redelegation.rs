@@ -1,8 +1,11 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
 use diesel::associations::Associations;
 use diesel::{Identifiable, Insertable, Queryable, Selectable};
-use shared::pos::Redelegation;
+use shared::pos::{Redelegation, RedelegationTx};
 
-use crate::schema::redelegation;
+use crate::schema::{redelegation, redelegation_history};
 use crate::validators::ValidatorDb;
 
 #[derive(Insertable, Clone, Queryable, Selectable)]
@@ -37,3 +40,49 @@ impl RedelegationInsertDb {
         }
     }
 }
+
+#[derive(Insertable, Clone, Queryable, Selectable)]
+#[diesel(table_name = redelegation_history)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct RedelegationHistoryInsertDb {
+    pub inner_tx_id: String,
+    pub delegator: String,
+    pub src_validator_id: i32,
+    pub dest_validator_id: i32,
+    pub raw_amount: BigDecimal,
+    pub epoch: i32,
+    pub height: i32,
+}
+
+#[derive(Identifiable, Clone, Queryable, Selectable)]
+#[diesel(table_name = redelegation_history)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct RedelegationHistoryDb {
+    pub id: i32,
+    pub inner_tx_id: String,
+    pub delegator: String,
+    pub src_validator_id: i32,
+    pub dest_validator_id: i32,
+    pub raw_amount: BigDecimal,
+    pub epoch: i32,
+    pub height: i32,
+}
+
+impl RedelegationHistoryInsertDb {
+    pub fn from_redelegation_tx(
+        redelegation: RedelegationTx,
+        src_validator_id: i32,
+        dest_validator_id: i32,
+    ) -> Self {
+        Self {
+            inner_tx_id: redelegation.inner_tx_id.to_string(),
+            delegator: redelegation.delegator.to_string(),
+            src_validator_id,
+            dest_validator_id,
+            raw_amount: BigDecimal::from_str(&redelegation.amount.to_string())
+                .expect("Invalid amount"),
+            epoch: redelegation.epoch as i32,
+            height: redelegation.height as i32,
+        }
+    }
+}
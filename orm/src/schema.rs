@@ -41,6 +41,30 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "history_kind"))]
     pub struct HistoryKind;
 
+    #[derive(
+        diesel::query_builder::QueryId,
+        std::fmt::Debug,
+        diesel::sql_types::SqlType,
+    )]
+    #[diesel(postgres_type(name = "ibc_channel_ordering"))]
+    pub struct IbcChannelOrdering;
+
+    #[derive(
+        diesel::query_builder::QueryId,
+        std::fmt::Debug,
+        diesel::sql_types::SqlType,
+    )]
+    #[diesel(postgres_type(name = "ibc_channel_state"))]
+    pub struct IbcChannelState;
+
+    #[derive(
+        diesel::query_builder::QueryId,
+        std::fmt::Debug,
+        diesel::sql_types::SqlType,
+    )]
+    #[diesel(postgres_type(name = "ibc_connection_state"))]
+    pub struct IbcConnectionState;
+
     #[derive(
         diesel::query_builder::QueryId,
         std::fmt::Debug,
@@ -207,6 +231,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    epochs (epoch) {
+        epoch -> Int4,
+        first_block_height -> Int4,
+        timestamp -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     gas_estimations (id) {
         id -> Int4,
@@ -258,6 +290,15 @@ diesel::table! {
         yay_votes -> Varchar,
         nay_votes -> Varchar,
         abstain_votes -> Varchar,
+        #[max_length = 64]
+        init_tx_id -> Nullable<Varchar>,
+        init_height -> Nullable<Int4>,
+        total_voting_power -> Nullable<Varchar>,
+        title -> Nullable<Varchar>,
+        authors -> Nullable<Varchar>,
+        discussions_to -> Nullable<Varchar>,
+        #[sql_name = "abstract"]
+        abstract_ -> Nullable<Varchar>,
     }
 }
 
@@ -270,6 +311,7 @@ diesel::table! {
         kind -> VoteKind,
         voter_address -> Varchar,
         proposal_id -> Int4,
+        block_height -> Nullable<Int4>,
     }
 }
 
@@ -282,6 +324,61 @@ diesel::table! {
         tx_hash -> Varchar,
         timeout -> Int8,
         status -> IbcStatus,
+        source_port -> Varchar,
+        source_channel -> Varchar,
+        dest_port -> Varchar,
+        dest_channel -> Varchar,
+        sequence -> Int8,
+        timeout_height -> Nullable<Varchar>,
+        send_height -> Nullable<Int4>,
+        denom -> Nullable<Varchar>,
+        amount -> Nullable<Numeric>,
+        sender -> Nullable<Varchar>,
+        receiver -> Nullable<Varchar>,
+        memo -> Nullable<Varchar>,
+        ack_tx_hash -> Nullable<Varchar>,
+        ack_height -> Nullable<Int4>,
+        refund_tx_hash -> Nullable<Varchar>,
+        stuck -> Bool,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::IbcChannelState;
+    use super::sql_types::IbcChannelOrdering;
+
+    ibc_channels (port_id, channel_id) {
+        port_id -> Varchar,
+        channel_id -> Varchar,
+        connection_id -> Nullable<Varchar>,
+        state -> IbcChannelState,
+        ordering -> IbcChannelOrdering,
+        version -> Varchar,
+        counterparty_port_id -> Varchar,
+        counterparty_channel_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    ibc_clients (client_id) {
+        client_id -> Varchar,
+        chain_id -> Varchar,
+        latest_height -> Varchar,
+        frozen -> Bool,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::IbcConnectionState;
+
+    ibc_connections (connection_id) {
+        connection_id -> Varchar,
+        client_id -> Varchar,
+        state -> IbcConnectionState,
+        counterparty_client_id -> Varchar,
+        counterparty_connection_id -> Nullable<Varchar>,
     }
 }
 
@@ -331,6 +428,13 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    masp_commitment_tree_per_epoch (epoch) {
+        epoch -> Int4,
+        size -> Int8,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::MaspPoolDirection;
@@ -362,6 +466,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    masp_pool_flows (id) {
+        id -> Int4,
+        #[max_length = 45]
+        token_address -> Varchar,
+        epoch -> Int4,
+        timestamp -> Timestamp,
+        inflow -> Numeric,
+        outflow -> Numeric,
+    }
+}
+
 diesel::table! {
     masp_rates (token) {
         token -> Varchar,
@@ -372,6 +488,67 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    masp_rates_per_epoch (token, epoch) {
+        token -> Varchar,
+        epoch -> Int4,
+        max_reward_rate -> Varchar,
+        kp_gain -> Varchar,
+        kd_gain -> Varchar,
+        locked_amount_target -> Numeric,
+        last_locked_amount -> Numeric,
+        last_inflation -> Numeric,
+        masp_epochs_per_year -> Int4,
+    }
+}
+
+diesel::table! {
+    native_inflation_per_epoch (epoch) {
+        epoch -> Int4,
+        pos_minted -> Numeric,
+        masp_minted -> Numeric,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::PaymentRecurrence;
+    use super::sql_types::PaymentKind;
+
+    pgf_scheduled_payments (id) {
+        id -> Int4,
+        proposal_id -> Int4,
+        payment_recurrence -> PaymentRecurrence,
+        payment_kind -> PaymentKind,
+        receipient -> Varchar,
+        amount -> Numeric,
+        epoch -> Int4,
+    }
+}
+
+diesel::table! {
+    pgf_stewards (id) {
+        id -> Int4,
+        #[max_length = 45]
+        address -> Varchar,
+        is_steward -> Bool,
+        epoch -> Int4,
+    }
+}
+
+diesel::table! {
+    pos_reward_claims (id) {
+        id -> Int4,
+        #[max_length = 64]
+        claim_tx_id -> Varchar,
+        owner -> Varchar,
+        validator_id -> Int4,
+        raw_amount -> Numeric,
+        epoch -> Nullable<Int4>,
+        block_height -> Int4,
+    }
+}
+
 diesel::table! {
     pos_rewards (id) {
         id -> Int4,
@@ -380,6 +557,24 @@ diesel::table! {
         raw_amount -> Numeric,
         claimed -> Bool,
         epoch -> Int4,
+        #[max_length = 64]
+        claim_tx_id -> Nullable<Varchar>,
+        claimed_at_height -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    pos_stats (id) {
+        id -> Int4,
+        epoch -> Int4,
+        total_bonded -> Numeric,
+        total_supply -> Numeric,
+        nakamoto_coefficient -> Int4,
+        active_validators -> Int4,
+        jailed_validators -> Int4,
+        inactive_validators -> Int4,
+        median_commission -> Nullable<Numeric>,
+        unbonding_queue -> Numeric,
     }
 }
 
@@ -407,6 +602,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    redelegation_history (id) {
+        id -> Int4,
+        #[max_length = 64]
+        inner_tx_id -> Varchar,
+        delegator -> Varchar,
+        src_validator_id -> Int4,
+        dest_validator_id -> Int4,
+        raw_amount -> Numeric,
+        epoch -> Int4,
+        height -> Int4,
+    }
+}
+
 diesel::table! {
     revealed_pk (id) {
         id -> Int4,
@@ -423,6 +632,7 @@ diesel::table! {
         #[max_length = 45]
         address -> Varchar,
         token_type -> TokenType,
+        denomination -> Nullable<Int4>,
     }
 }
 
@@ -457,6 +667,19 @@ diesel::table! {
         validator_id -> Int4,
         raw_amount -> Numeric,
         withdraw_epoch -> Int4,
+        withdrawn_at_height -> Nullable<Int4>,
+        withdrawn_at_epoch -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ValidatorState;
+
+    validator_state_history (validator_id, epoch) {
+        validator_id -> Int4,
+        epoch -> Int4,
+        state -> ValidatorState,
     }
 }
 
@@ -509,12 +732,14 @@ diesel::joinable!(ibc_token -> token (address));
 diesel::joinable!(ibc_token_flows -> token (address));
 diesel::joinable!(inner_transactions -> wrapper_transactions (wrapper_id));
 diesel::joinable!(masp_pool -> inner_transactions (inner_tx_id));
+diesel::joinable!(pos_reward_claims -> validators (validator_id));
 diesel::joinable!(pos_rewards -> validators (validator_id));
 diesel::joinable!(public_good_funding -> governance_proposals (proposal_id));
 diesel::joinable!(redelegation -> validators (validator_id));
 diesel::joinable!(token_supplies_per_epoch -> token (address));
 diesel::joinable!(transaction_history -> inner_transactions (inner_tx_id));
 diesel::joinable!(unbonds -> validators (validator_id));
+diesel::joinable!(validator_state_history -> validators (validator_id));
 diesel::joinable!(wrapper_transactions -> blocks (block_height));
 
 diesel::allow_tables_to_appear_in_same_query!(
@@ -524,26 +749,40 @@ diesel::allow_tables_to_appear_in_same_query!(
     chain_parameters,
     cometbft_block,
     crawler_state,
+    epochs,
     gas_estimations,
     gas_price,
     governance_proposals,
     governance_votes,
     ibc_ack,
+    ibc_channels,
+    ibc_clients,
+    ibc_connections,
     ibc_rate_limits,
     ibc_token,
     ibc_token_flows,
     inner_transactions,
+    masp_commitment_tree_per_epoch,
     masp_pool,
     masp_pool_aggregate,
+    masp_pool_flows,
     masp_rates,
+    masp_rates_per_epoch,
+    native_inflation_per_epoch,
+    pgf_scheduled_payments,
+    pgf_stewards,
+    pos_reward_claims,
     pos_rewards,
+    pos_stats,
     public_good_funding,
     redelegation,
+    redelegation_history,
     revealed_pk,
     token,
     token_supplies_per_epoch,
     transaction_history,
     unbonds,
+    validator_state_history,
     validators,
     wrapper_transactions,
 );
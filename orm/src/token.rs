@@ -17,6 +17,7 @@ pub enum TokenTypeDb {
 pub struct TokenDb {
     pub address: String,
     pub token_type: TokenTypeDb,
+    pub denomination: Option<i32>,
 }
 
 pub type TokenInsertDb = TokenDb;
@@ -27,10 +28,12 @@ impl From<&Token> for TokenDb {
             Token::Native(token) => TokenDb {
                 address: token.to_string(),
                 token_type: TokenTypeDb::Native,
+                denomination: None,
             },
             Token::Ibc(token) => TokenDb {
                 address: token.address.to_string(),
                 token_type: TokenTypeDb::Ibc,
+                denomination: None,
             },
         }
     }
@@ -28,6 +28,8 @@ pub struct UnbondDb {
     pub validator_id: i32,
     pub raw_amount: BigDecimal,
     pub withdraw_epoch: i32,
+    pub withdrawn_at_height: Option<i32>,
+    pub withdrawn_at_epoch: Option<i32>,
 }
 
 impl UnbondInsertDb {
@@ -7,3 +7,13 @@ diesel::table! {
         raw_amount -> Numeric,
     }
 }
+
+diesel::table! {
+    validator_delegators (address, validator_id) {
+        address -> Varchar,
+        validator_id -> Int4,
+        bonded -> Numeric,
+        unbonding -> Numeric,
+        start_epoch -> Nullable<Int4>,
+    }
+}
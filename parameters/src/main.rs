@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::convert::identity;
 use std::sync::Arc;
 
@@ -7,6 +8,7 @@ use deadpool_diesel::postgres::Object;
 use namada_sdk::state::EPOCH_SWITCH_BLOCKS_DELAY;
 use namada_sdk::time::{DateTimeUtc, Utc};
 use orm::gas::GasPriceDb;
+use orm::ibc::{IbcChannelInsertDb, IbcClientInsertDb, IbcConnectionInsertDb};
 use orm::migrations::CustomMigrationSource;
 use orm::parameters::ParametersInsertDb;
 use parameters::app_state::AppState;
@@ -89,6 +91,35 @@ async fn crawling_fn(
 
     let gas_price = namada_service::get_gas_price(&client).await;
 
+    // The ibc state is only upserted, so when a query fails the indexed
+    // state is kept until the next crawl rather than failing the parameters
+    let ibc_connections = namada_service::get_ibc_connections(&client)
+        .await
+        .unwrap_or_else(|err| {
+            tracing::error!(?err, "Failed to query ibc connections");
+            vec![]
+        });
+
+    let ibc_channels = namada_service::get_ibc_channels(&client)
+        .await
+        .unwrap_or_else(|err| {
+            tracing::error!(?err, "Failed to query ibc channels");
+            vec![]
+        });
+
+    let ibc_clients = namada_service::get_ibc_clients(
+        &client,
+        ibc_connections
+            .iter()
+            .map(|connection| connection.client_id.clone())
+            .collect::<BTreeSet<_>>(),
+    )
+    .await
+    .unwrap_or_else(|err| {
+        tracing::error!(?err, "Failed to query ibc clients");
+        vec![]
+    });
+
     let timestamp = DateTimeUtc::now().0.timestamp();
     let crawler_state = IntervalCrawlerState { timestamp };
 
@@ -117,6 +148,30 @@ async fn crawling_fn(
                         .collect::<Vec<GasPriceDb>>(),
                 )?;
 
+                repository::ibc::upsert_ibc_clients(
+                    transaction_conn,
+                    ibc_clients
+                        .into_iter()
+                        .map(IbcClientInsertDb::from)
+                        .collect(),
+                )?;
+
+                repository::ibc::upsert_ibc_connections(
+                    transaction_conn,
+                    ibc_connections
+                        .into_iter()
+                        .map(IbcConnectionInsertDb::from)
+                        .collect(),
+                )?;
+
+                repository::ibc::upsert_ibc_channels(
+                    transaction_conn,
+                    ibc_channels
+                        .into_iter()
+                        .map(IbcChannelInsertDb::from)
+                        .collect(),
+                )?;
+
                 repository::crawler_state::upsert_crawler_state(
                     transaction_conn,
                     (CrawlerName::Parameters, crawler_state).into(),
@@ -0,0 +1,71 @@
+use anyhow::Context;
+use diesel::upsert::excluded;
+use diesel::{ExpressionMethods, PgConnection, RunQueryDsl};
+use orm::ibc::{IbcChannelInsertDb, IbcClientInsertDb, IbcConnectionInsertDb};
+use orm::schema::{ibc_channels, ibc_clients, ibc_connections};
+
+pub fn upsert_ibc_clients(
+    transaction_conn: &mut PgConnection,
+    clients: Vec<IbcClientInsertDb>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(ibc_clients::table)
+        .values(clients)
+        .on_conflict(ibc_clients::client_id)
+        .do_update()
+        .set((
+            ibc_clients::chain_id.eq(excluded(ibc_clients::chain_id)),
+            ibc_clients::latest_height.eq(excluded(ibc_clients::latest_height)),
+            ibc_clients::frozen.eq(excluded(ibc_clients::frozen)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to update ibc clients in db")?;
+
+    Ok(())
+}
+
+pub fn upsert_ibc_connections(
+    transaction_conn: &mut PgConnection,
+    connections: Vec<IbcConnectionInsertDb>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(ibc_connections::table)
+        .values(connections)
+        .on_conflict(ibc_connections::connection_id)
+        .do_update()
+        .set((
+            ibc_connections::client_id.eq(excluded(ibc_connections::client_id)),
+            ibc_connections::state.eq(excluded(ibc_connections::state)),
+            ibc_connections::counterparty_client_id
+                .eq(excluded(ibc_connections::counterparty_client_id)),
+            ibc_connections::counterparty_connection_id
+                .eq(excluded(ibc_connections::counterparty_connection_id)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to update ibc connections in db")?;
+
+    Ok(())
+}
+
+pub fn upsert_ibc_channels(
+    transaction_conn: &mut PgConnection,
+    channels: Vec<IbcChannelInsertDb>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(ibc_channels::table)
+        .values(channels)
+        .on_conflict((ibc_channels::port_id, ibc_channels::channel_id))
+        .do_update()
+        .set((
+            ibc_channels::connection_id
+                .eq(excluded(ibc_channels::connection_id)),
+            ibc_channels::state.eq(excluded(ibc_channels::state)),
+            ibc_channels::ordering.eq(excluded(ibc_channels::ordering)),
+            ibc_channels::version.eq(excluded(ibc_channels::version)),
+            ibc_channels::counterparty_port_id
+                .eq(excluded(ibc_channels::counterparty_port_id)),
+            ibc_channels::counterparty_channel_id
+                .eq(excluded(ibc_channels::counterparty_channel_id)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to update ibc channels in db")?;
+
+    Ok(())
+}
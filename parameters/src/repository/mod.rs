@@ -1,2 +1,3 @@
 pub mod crawler_state;
+pub mod ibc;
 pub mod parameters;
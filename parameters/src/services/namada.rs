@@ -7,6 +7,12 @@ use namada_sdk::address::Address as NamadaAddress;
 use namada_sdk::arith::checked;
 use namada_sdk::dec::Dec;
 use namada_sdk::hash::Hash;
+use namada_sdk::ibc::clients::tendermint::client_state::ClientState as TmClientState;
+use namada_sdk::ibc::core::channel::types::channel::ChannelEnd;
+use namada_sdk::ibc::core::connection::types::ConnectionEnd;
+use namada_sdk::ibc::core::host::types::identifiers::ClientId;
+use namada_sdk::ibc::primitives::proto::{Any, Protobuf};
+use namada_sdk::ibc::storage as ibc_storage;
 use namada_sdk::proof_of_stake::storage_key as pos_storage_key;
 use namada_sdk::queries::RPC;
 use namada_sdk::rpc::{
@@ -18,6 +24,7 @@ use shared::balance::Amount;
 use shared::block::Epoch;
 use shared::checksums::Checksums;
 use shared::gas::GasPrice;
+use shared::ibc::{IbcChannel, IbcClient, IbcConnection};
 use shared::parameters::Parameters;
 use tendermint_rpc::HttpClient;
 
@@ -143,6 +150,116 @@ pub async fn get_gas_price(client: &HttpClient) -> Vec<GasPrice> {
     gas_table
 }
 
+pub async fn get_ibc_connections(
+    client: &HttpClient,
+) -> anyhow::Result<Vec<IbcConnection>> {
+    let prefix = ibc_storage::ibc_key("connections")
+        .context("Failed to build ibc connections key")?;
+
+    let connections = RPC
+        .shell()
+        .storage_prefix(client, None, None, false, &prefix)
+        .await
+        .context("Failed to query ibc connections")?
+        .data
+        .into_iter()
+        .filter_map(|entry| {
+            let connection_id = ibc_storage::connection_id(&entry.key).ok()?;
+            let connection_end = ConnectionEnd::decode_vec(&entry.value)
+                .inspect_err(|err| {
+                    tracing::warn!(
+                        %connection_id,
+                        ?err,
+                        "Failed to decode ibc connection"
+                    );
+                })
+                .ok()?;
+
+            Some(IbcConnection::from_connection_end(
+                &connection_id,
+                &connection_end,
+            ))
+        })
+        .collect();
+
+    Ok(connections)
+}
+
+pub async fn get_ibc_channels(
+    client: &HttpClient,
+) -> anyhow::Result<Vec<IbcChannel>> {
+    let prefix = ibc_storage::ibc_key("channelEnds")
+        .context("Failed to build ibc channels key")?;
+
+    let channels = RPC
+        .shell()
+        .storage_prefix(client, None, None, false, &prefix)
+        .await
+        .context("Failed to query ibc channels")?
+        .data
+        .into_iter()
+        .filter_map(|entry| {
+            let (port_id, channel_id) =
+                ibc_storage::port_channel_id(&entry.key).ok()?;
+            let channel_end = ChannelEnd::decode_vec(&entry.value)
+                .inspect_err(|err| {
+                    tracing::warn!(
+                        %port_id,
+                        %channel_id,
+                        ?err,
+                        "Failed to decode ibc channel"
+                    );
+                })
+                .ok()?;
+
+            Some(IbcChannel::from_channel_end(
+                &port_id,
+                &channel_id,
+                &channel_end,
+            ))
+        })
+        .collect();
+
+    Ok(channels)
+}
+
+/// Query the state of the given light clients. Only tendermint clients are
+/// indexed, as they are the only ones that can be created on namada.
+pub async fn get_ibc_clients(
+    client: &HttpClient,
+    client_ids: impl IntoIterator<Item = String>,
+) -> anyhow::Result<Vec<IbcClient>> {
+    let mut clients = vec![];
+
+    for client_id in client_ids {
+        let client_id: ClientId = client_id
+            .parse()
+            .with_context(|| format!("Invalid ibc client id {client_id}"))?;
+        let key = ibc_storage::client_state_key(&client_id);
+
+        let (bytes, _) =
+            rpc::query_storage_value_bytes(client, &key, None, false)
+                .await
+                .with_context(|| {
+                    format!("Failed to query ibc client {client_id}")
+                })?;
+
+        let Some(bytes) = bytes else {
+            continue;
+        };
+
+        match <TmClientState as Protobuf<Any>>::decode_vec(&bytes) {
+            Ok(client_state) => clients
+                .push(IbcClient::from_tendermint(&client_id, &client_state)),
+            Err(err) => {
+                tracing::warn!(%client_id, ?err, "Failed to decode ibc client")
+            }
+        }
+    }
+
+    Ok(clients)
+}
+
 pub async fn get_current_epoch(client: &HttpClient) -> anyhow::Result<Epoch> {
     let epoch = rpc::query_epoch(client)
         .await
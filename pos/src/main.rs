@@ -7,6 +7,7 @@ use deadpool_diesel::postgres::Object;
 use namada_sdk::time::DateTimeUtc;
 use orm::crawler_state::EpochStateInsertDb;
 use orm::migrations::CustomMigrationSource;
+use orm::pos_stats::PosStatsInsertDb;
 use orm::validators::ValidatorInsertDb;
 use pos::app_state::AppState;
 use pos::config::AppConfig;
@@ -14,10 +15,12 @@ use pos::repository::{self};
 use pos::services::{
     namada as namada_service, tendermint as tendermint_service,
 };
+use shared::block::BlockHeight;
 use shared::client::Client;
 use shared::crawler;
 use shared::crawler_state::{CrawlerName, EpochCrawlerState};
 use shared::error::{AsDbError, AsRpcError, ContextDbInteractError, MainError};
+use shared::pos::PosStats;
 use tendermint_rpc::HttpClient;
 
 #[tokio::main]
@@ -89,6 +92,24 @@ async fn crawling_fn(
         "Queried validators successfully...",
     );
 
+    let Some(first_block_height) =
+        get_first_block_height(epoch_to_process, &conn, &client).await?
+    else {
+        tracing::warn!(
+            epoch = epoch_to_process,
+            "First block of the epoch is not indexed yet, waiting...",
+        );
+
+        return Err(MainError::NoAction);
+    };
+
+    let total_supply = namada_service::get_native_token_total_supply(
+        &client,
+        first_block_height,
+    )
+    .await
+    .into_rpc_error()?;
+
     let timestamp = DateTimeUtc::now().0.timestamp();
     let crawler_state = EpochCrawlerState {
         last_processed_epoch: epoch_to_process,
@@ -103,7 +124,8 @@ async fn crawling_fn(
             .run(|transaction_conn| {
                 let validators_dbo = &validators_set
                     .validators
-                    .into_iter()
+                    .iter()
+                    .cloned()
                     .map(ValidatorInsertDb::from_validator)
                     .collect::<Vec<_>>();
 
@@ -112,6 +134,27 @@ async fn crawling_fn(
                     validators_dbo,
                 )?;
 
+                repository::pos::upsert_validator_state_history(
+                    transaction_conn,
+                    validators_dbo,
+                    epoch_to_process,
+                )?;
+
+                let unbonding_queue = repository::pos::get_unbonding_queue(
+                    transaction_conn,
+                    epoch_to_process,
+                )?;
+                let pos_stats = PosStats::new(
+                    &validators_set,
+                    total_supply,
+                    unbonding_queue,
+                );
+
+                repository::pos::upsert_pos_stats(
+                    transaction_conn,
+                    PosStatsInsertDb::from_pos_stats(pos_stats),
+                )?;
+
                 repository::crawler_state::upsert_crawler_state(
                     transaction_conn,
                     crawler_state,
@@ -147,6 +190,31 @@ async fn can_process(
     Ok(current_epoch >= epoch)
 }
 
+/// The node only knows the first block of its current epoch, older epochs
+/// are looked up in the epochs indexed by the chain crawler
+async fn get_first_block_height(
+    epoch: u32,
+    conn: &Object,
+    client: &HttpClient,
+) -> Result<Option<BlockHeight>, MainError> {
+    let indexed_height = conn
+        .interact(move |conn| {
+            repository::pos::get_epoch_first_block_height(conn, epoch)
+        })
+        .await
+        .context_db_interact_error()
+        .and_then(identity)
+        .into_db_error()?;
+
+    if indexed_height.is_some() {
+        return Ok(indexed_height);
+    }
+
+    namada_service::get_first_block_height_of_epoch(client, epoch)
+        .await
+        .into_rpc_error()
+}
+
 async fn update_crawler_timestamp(
     conn: &Object,
     timestamp: NaiveDateTime,
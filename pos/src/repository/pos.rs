@@ -1,8 +1,19 @@
 use anyhow::Context;
+use bigdecimal::BigDecimal;
+use diesel::dsl::sum;
+use diesel::sql_types::Integer;
 use diesel::upsert::excluded;
-use diesel::{ExpressionMethods, PgConnection, RunQueryDsl};
-use orm::schema::validators;
+use diesel::{
+    ExpressionMethods, IntoSql, OptionalExtension, PgConnection, QueryDsl,
+    RunQueryDsl,
+};
+use orm::pos_stats::PosStatsInsertDb;
+use orm::schema::{
+    epochs, pos_stats, unbonds, validator_state_history, validators,
+};
 use orm::validators::ValidatorInsertDb;
+use shared::balance::Amount;
+use shared::block::{BlockHeight, Epoch};
 
 pub fn upsert_validators(
     transaction_conn: &mut PgConnection,
@@ -26,3 +37,90 @@ pub fn upsert_validators(
 
     Ok(())
 }
+
+/// Records the current state of the given validators as their state at the
+/// given epoch
+pub fn upsert_validator_state_history(
+    transaction_conn: &mut PgConnection,
+    validators_db: &[ValidatorInsertDb],
+    epoch: Epoch,
+) -> anyhow::Result<()> {
+    let addresses = validators_db
+        .iter()
+        .map(|validator| validator.namada_address.clone())
+        .collect::<Vec<_>>();
+
+    diesel::insert_into(validator_state_history::table)
+        .values(
+            validators::table
+                .filter(validators::columns::namada_address.eq_any(addresses))
+                .select((
+                    validators::columns::id,
+                    (epoch as i32).into_sql::<Integer>(),
+                    validators::columns::state,
+                )),
+        )
+        .into_columns((
+            validator_state_history::columns::validator_id,
+            validator_state_history::columns::epoch,
+            validator_state_history::columns::state,
+        ))
+        .on_conflict((
+            validator_state_history::columns::validator_id,
+            validator_state_history::columns::epoch,
+        ))
+        .do_update()
+        .set(
+            validator_state_history::columns::state
+                .eq(excluded(validator_state_history::columns::state)),
+        )
+        .execute(transaction_conn)
+        .context("Failed to update validator state history in db")?;
+
+    Ok(())
+}
+
+/// First block of the given epoch, as indexed by the chain crawler
+pub fn get_epoch_first_block_height(
+    transaction_conn: &mut PgConnection,
+    epoch: Epoch,
+) -> anyhow::Result<Option<BlockHeight>> {
+    let height = epochs::table
+        .filter(epochs::columns::epoch.eq(epoch as i32))
+        .select(epochs::columns::first_block_height)
+        .first::<i32>(transaction_conn)
+        .optional()
+        .context("Failed to query first block height of epoch")?;
+
+    Ok(height.map(|height| height as BlockHeight))
+}
+
+/// Sum of the unbonds that are not yet withdrawable at the given epoch
+pub fn get_unbonding_queue(
+    transaction_conn: &mut PgConnection,
+    epoch: Epoch,
+) -> anyhow::Result<Amount> {
+    let unbonding = unbonds::table
+        .filter(unbonds::columns::withdraw_epoch.gt(epoch as i32))
+        .filter(unbonds::columns::withdrawn_at_height.is_null())
+        .select(sum(unbonds::columns::raw_amount))
+        .first::<Option<BigDecimal>>(transaction_conn)
+        .context("Failed to query unbonding amount")?;
+
+    Ok(unbonding.map(Amount::from).unwrap_or_else(Amount::zero))
+}
+
+pub fn upsert_pos_stats(
+    transaction_conn: &mut PgConnection,
+    pos_stats_db: PosStatsInsertDb,
+) -> anyhow::Result<()> {
+    diesel::insert_into(pos_stats::table)
+        .values(&pos_stats_db)
+        .on_conflict(pos_stats::columns::epoch)
+        .do_update()
+        .set(&pos_stats_db)
+        .execute(transaction_conn)
+        .context("Failed to update pos stats in db")?;
+
+    Ok(())
+}
@@ -2,10 +2,16 @@ use std::collections::HashSet;
 
 use anyhow::Context;
 use futures::{StreamExt, TryStreamExt};
-use namada_core::chain::Epoch as NamadaSdkEpoch;
+use namada_core::chain::{
+    BlockHeight as NamadaSdkBlockHeight, Epoch as NamadaSdkEpoch,
+};
 use namada_sdk::address::Address;
-use namada_sdk::rpc;
-use shared::block::Epoch;
+use namada_sdk::borsh::BorshDeserialize;
+use namada_sdk::queries::RPC;
+use namada_sdk::token::Amount as NamadaSdkAmount;
+use namada_sdk::{rpc, token};
+use shared::balance::Amount;
+use shared::block::{BlockHeight, Epoch};
 use shared::id::Id;
 use shared::validator::{Validator, ValidatorSet, ValidatorState};
 use tendermint_rpc::HttpClient;
@@ -150,6 +156,65 @@ pub async fn get_validators_state(
     Ok(ValidatorSet { validators, epoch })
 }
 
+pub async fn get_native_token_total_supply(
+    client: &HttpClient,
+    height: BlockHeight,
+) -> anyhow::Result<Amount> {
+    let native_token = RPC
+        .shell()
+        .native_token(client)
+        .await
+        .context("Failed to query native token")?;
+
+    let minted_key = token::storage_key::minted_balance_key(&native_token);
+
+    let value = RPC
+        .shell()
+        .storage_value(
+            client,
+            None,
+            Some(to_block_height(height)),
+            false,
+            &minted_key,
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to query total supply of native token at height \
+                 {height}"
+            )
+        })?;
+
+    if value.data.is_empty() {
+        return Ok(Amount::zero());
+    }
+
+    NamadaSdkAmount::try_from_slice(&value.data)
+        .map(Amount::from)
+        .context("Failed to deserialize total supply of native token")
+}
+
+/// First block of the given epoch, only known by the node while it is the
+/// current epoch
+pub async fn get_first_block_height_of_epoch(
+    client: &HttpClient,
+    epoch: Epoch,
+) -> anyhow::Result<Option<BlockHeight>> {
+    let height = RPC
+        .shell()
+        .first_block_height_of_current_epoch(client)
+        .await
+        .context("Failed to query first block height of current epoch")?;
+
+    let height_epoch = rpc::query_epoch_at_height(client, height)
+        .await
+        .with_context(|| format!("Failed to query epoch at height {height}"))?;
+
+    Ok(height_epoch
+        .filter(|height_epoch| height_epoch.0 == epoch as u64)
+        .map(|_| height.0 as BlockHeight))
+}
+
 pub async fn get_current_epoch(client: &HttpClient) -> anyhow::Result<Epoch> {
     let epoch = rpc::query_epoch(client)
         .await
@@ -158,6 +223,10 @@ pub async fn get_current_epoch(client: &HttpClient) -> anyhow::Result<Epoch> {
     Ok(epoch.0 as Epoch)
 }
 
+fn to_block_height(height: BlockHeight) -> NamadaSdkBlockHeight {
+    NamadaSdkBlockHeight::from(height as u64)
+}
+
 fn to_epoch(epoch: u32) -> NamadaSdkEpoch {
     NamadaSdkEpoch::from(epoch as u64)
 }
@@ -155,6 +155,14 @@ pub struct TokenSupply {
     pub effective: Option<BigDecimal>,
 }
 
+/// Native tokens minted as rewards at the start of an epoch
+#[derive(Debug, Clone)]
+pub struct NativeInflation {
+    pub epoch: i32,
+    pub pos: BigDecimal,
+    pub masp: BigDecimal,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
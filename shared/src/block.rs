@@ -10,20 +10,24 @@ use namada_sdk::token::Transfer;
 use subtle_encoding::hex;
 use tendermint_rpc::endpoint::block::Response as TendermintBlockResponse;
 
+use crate::balance::Amount;
 use crate::block_result::BlockResult;
 use crate::checksums::Checksums;
 use crate::header::BlockHeader;
 use crate::id::Id;
 use crate::masp::{MaspEntry, MaspEntryDirection};
-use crate::pos::{BondAddresses, UnbondAddresses};
+use crate::pos::{BondAddresses, RedelegationTx, UnbondAddresses};
 use crate::proposal::{GovernanceProposal, GovernanceProposalKind};
 use crate::public_key::PublicKey;
+use crate::rewards::RewardClaim;
 use crate::token::{IbcToken, Token};
 use crate::transaction::{
     InnerTransaction, Transaction, TransactionKind, TransactionTarget,
     WrapperTransaction,
 };
-use crate::utils::{BalanceChange, MASP_ADDRESS, ibc_ack_to_balance_info};
+use crate::utils::{
+    BalanceChange, DelegationPair, MASP_ADDRESS, ibc_ack_to_balance_info,
+};
 use crate::validator::{
     Validator, ValidatorMetadataChange, ValidatorState, ValidatorStateChange,
 };
@@ -600,6 +604,8 @@ impl Block {
                             init_proposal_data.activation_epoch.0 as u32,
                         ),
                         content: proposal_content_serialized,
+                        init_tx_id: Some(tx.tx_id.clone()),
+                        init_height: Some(self.header.height),
                     })
                 }
                 _ => None,
@@ -607,7 +613,7 @@ impl Block {
             .collect()
     }
 
-    pub fn pos_rewards(&self) -> HashSet<(Id, Id)> {
+    pub fn pos_rewards(&self) -> HashSet<RewardClaim> {
         self.transactions
             .iter()
             .fold(vec![], |mut acc, (wrapper_tx, inner_txs)| {
@@ -629,7 +635,44 @@ impl Block {
                         .to_owned()
                         .unwrap_or_else(|| validator.clone());
 
-                    Some((Id::from(source), Id::from(validator)))
+                    Some(RewardClaim {
+                        inner_tx_id: tx.tx_id.clone(),
+                        delegation_pair: DelegationPair {
+                            validator_address: Id::from(validator),
+                            delegator_address: Id::from(source),
+                        },
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn redelegations(&self) -> Vec<RedelegationTx> {
+        self.transactions
+            .iter()
+            .fold(vec![], |mut acc, (wrapper_tx, inner_txs)| {
+                // Extract successful inner txs
+                for inner_tx in inner_txs {
+                    if inner_tx.was_successful(wrapper_tx) {
+                        acc.push(inner_tx)
+                    }
+                }
+
+                acc
+            })
+            .iter()
+            .filter_map(|tx| match &tx.kind {
+                TransactionKind::Redelegation(Some(data)) => {
+                    Some(RedelegationTx {
+                        inner_tx_id: tx.tx_id.clone(),
+                        delegator: Id::from(data.owner.clone()),
+                        src_validator: Id::from(data.src_validator.clone()),
+                        dest_validator: Id::from(data.dest_validator.clone()),
+                        amount: Amount::from(data.amount),
+                        epoch: self.epoch,
+                        height: self.header.height,
+                    })
                 }
                 _ => None,
             })
@@ -656,6 +699,7 @@ impl Block {
                         proposal_id: vote_proposal_data.id,
                         vote: vote_proposal_data.vote.to_owned().into(),
                         address: Id::from(vote_proposal_data.voter.to_owned()),
+                        height: Some(self.header.height),
                     })
                 }
                 _ => None,
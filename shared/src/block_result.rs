@@ -197,6 +197,25 @@ pub struct IbcPacket {
     pub data: String,
 }
 
+impl IbcPacket {
+    /// Decode the ICS-20 data carried by the packet, if it is a fungible
+    /// token transfer
+    pub fn fungible_token_packet(&self) -> Option<FungibleTokenPacket> {
+        let packet_data: Ics20PacketData =
+            serde_json::from_str(&self.data).ok()?;
+        let ibc_amount: NamadaAmount =
+            packet_data.token.amount.try_into().ok()?;
+
+        Some(FungibleTokenPacket {
+            memo: packet_data.memo.to_string(),
+            sender: packet_data.sender.to_string(),
+            receiver: packet_data.receiver.to_string(),
+            denom: packet_data.token.denom.to_string(),
+            amount: Amount::from(ibc_amount).into(),
+        })
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct FungibleTokenPacket {
     pub sender: String,
@@ -393,18 +412,7 @@ impl TxAttributesType {
             _ => return None,
         };
 
-        let packet_data: Ics20PacketData =
-            serde_json::from_str(&packet.data).ok()?;
-        let ibc_amount: NamadaAmount =
-            packet_data.token.amount.try_into().ok()?;
-
-        let ics20_packet = FungibleTokenPacket {
-            memo: packet_data.memo.to_string(),
-            sender: packet_data.sender.to_string(),
-            receiver: packet_data.receiver.to_string(),
-            denom: packet_data.token.denom.to_string(),
-            amount: Amount::from(ibc_amount).into(),
-        };
+        let ics20_packet = packet.fungible_token_packet()?;
 
         Some((action, Some(packet), Cow::Owned(ics20_packet)))
     }
@@ -627,6 +635,43 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn ibc_packet_fungible_token_data() {
+        let packet = IbcPacket {
+            source_port: "transfer".to_owned(),
+            dest_port: "transfer".to_owned(),
+            source_channel: "channel-1".to_owned(),
+            dest_channel: "channel-7".to_owned(),
+            timeout_timestamp: 0,
+            timeout_height: "1-1000".to_owned(),
+            sequence: "12".to_owned(),
+            data: r#"{"amount":"1500000","denom":"tnam1q9gr66cvu4hrzm0sd5kmlnjje82gs3xlfg3v6nu7","memo":"bridge","receiver":"osmo1m8wg4vxkefhs374qxmmqpyusgz289wmulex5qdwpfx7jnrxzer5s9cv83q","sender":"tnam1qz4sdx5jlh909j44uz46pf29ty0ztftfzc98s8dx"}"#
+                .to_owned(),
+        };
+
+        let data = packet
+            .fungible_token_packet()
+            .expect("Packet should carry ICS-20 data");
+
+        assert_eq!(
+            data.sender,
+            "tnam1qz4sdx5jlh909j44uz46pf29ty0ztftfzc98s8dx"
+        );
+        assert_eq!(
+            data.receiver,
+            "osmo1m8wg4vxkefhs374qxmmqpyusgz289wmulex5qdwpfx7jnrxzer5s9cv83q"
+        );
+        assert_eq!(data.denom, "tnam1q9gr66cvu4hrzm0sd5kmlnjje82gs3xlfg3v6nu7");
+        assert_eq!(data.memo, "bridge");
+        assert_eq!(data.amount, "1500000".parse().unwrap());
+
+        let packet = IbcPacket {
+            data: "{}".to_owned(),
+            ..packet
+        };
+        assert!(packet.fungible_token_packet().is_none());
+    }
+
     fn example_events() -> Vec<TestEvent> {
         vec![
             TestEvent {
@@ -0,0 +1,159 @@
+use namada_ibc::clients::tendermint::client_state::ClientState as TmClientState;
+use namada_ibc::core::channel::types::channel::{
+    ChannelEnd, Order, State as ChannelState,
+};
+use namada_ibc::core::connection::types::{
+    ConnectionEnd, State as ConnectionState,
+};
+use namada_ibc::core::host::types::identifiers::{
+    ChannelId, ClientId, ConnectionId, PortId,
+};
+
+#[derive(Debug, Clone)]
+pub enum IbcConnectionState {
+    Uninitialized,
+    Init,
+    TryOpen,
+    Open,
+}
+
+impl From<&ConnectionState> for IbcConnectionState {
+    fn from(value: &ConnectionState) -> Self {
+        match value {
+            ConnectionState::Uninitialized => Self::Uninitialized,
+            ConnectionState::Init => Self::Init,
+            ConnectionState::TryOpen => Self::TryOpen,
+            ConnectionState::Open => Self::Open,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum IbcChannelState {
+    Uninitialized,
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+impl From<&ChannelState> for IbcChannelState {
+    fn from(value: &ChannelState) -> Self {
+        match value {
+            ChannelState::Uninitialized => Self::Uninitialized,
+            ChannelState::Init => Self::Init,
+            ChannelState::TryOpen => Self::TryOpen,
+            ChannelState::Open => Self::Open,
+            ChannelState::Closed => Self::Closed,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum IbcChannelOrdering {
+    None,
+    Unordered,
+    Ordered,
+}
+
+impl From<&Order> for IbcChannelOrdering {
+    fn from(value: &Order) -> Self {
+        match value {
+            Order::None => Self::None,
+            Order::Unordered => Self::Unordered,
+            Order::Ordered => Self::Ordered,
+        }
+    }
+}
+
+/// A light client tracking a counterparty chain
+#[derive(Debug, Clone)]
+pub struct IbcClient {
+    pub client_id: String,
+    pub chain_id: String,
+    pub latest_height: String,
+    pub frozen: bool,
+}
+
+impl IbcClient {
+    pub fn from_tendermint(
+        client_id: &ClientId,
+        client_state: &TmClientState,
+    ) -> Self {
+        let client_state = client_state.inner();
+
+        Self {
+            client_id: client_id.to_string(),
+            chain_id: client_state.chain_id().to_string(),
+            latest_height: client_state.latest_height.to_string(),
+            frozen: client_state.is_frozen(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IbcConnection {
+    pub connection_id: String,
+    pub client_id: String,
+    pub state: IbcConnectionState,
+    pub counterparty_client_id: String,
+    pub counterparty_connection_id: Option<String>,
+}
+
+impl IbcConnection {
+    pub fn from_connection_end(
+        connection_id: &ConnectionId,
+        connection_end: &ConnectionEnd,
+    ) -> Self {
+        let counterparty = connection_end.counterparty();
+
+        Self {
+            connection_id: connection_id.to_string(),
+            client_id: connection_end.client_id().to_string(),
+            state: IbcConnectionState::from(connection_end.state()),
+            counterparty_client_id: counterparty.client_id().to_string(),
+            counterparty_connection_id: counterparty
+                .connection_id()
+                .map(ConnectionId::to_string),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IbcChannel {
+    pub port_id: String,
+    pub channel_id: String,
+    pub connection_id: Option<String>,
+    pub state: IbcChannelState,
+    pub ordering: IbcChannelOrdering,
+    pub version: String,
+    pub counterparty_port_id: String,
+    pub counterparty_channel_id: Option<String>,
+}
+
+impl IbcChannel {
+    pub fn from_channel_end(
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        channel_end: &ChannelEnd,
+    ) -> Self {
+        let counterparty = channel_end.counterparty();
+
+        Self {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            // Namada does not support multi-hop channels
+            connection_id: channel_end
+                .connection_hops()
+                .first()
+                .map(ConnectionId::to_string),
+            state: IbcChannelState::from(channel_end.state()),
+            ordering: IbcChannelOrdering::from(channel_end.ordering()),
+            version: channel_end.version().to_string(),
+            counterparty_port_id: counterparty.port_id().to_string(),
+            counterparty_channel_id: counterparty
+                .channel_id()
+                .map(ChannelId::to_string),
+        }
+    }
+}
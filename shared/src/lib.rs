@@ -11,6 +11,7 @@ pub mod futures;
 pub mod gas;
 pub mod genesis;
 pub mod header;
+pub mod ibc;
 pub mod id;
 pub mod log_config;
 pub mod masp;
@@ -24,3 +24,21 @@ pub struct MaspRewardData {
     pub kd_gain: String,
     pub locked_amount_target: Amount,
 }
+
+/// The masp reward parameters of a token at an epoch, along with the values
+/// the PD-controller used to compute the last rewards
+#[derive(Debug, Clone)]
+pub struct MaspRewardHistory {
+    pub reward_data: MaspRewardData,
+    pub epoch: u32,
+    pub last_locked_amount: Amount,
+    pub last_inflation: Amount,
+    pub masp_epochs_per_year: u64,
+}
+
+/// Size of the masp note commitment tree at the start of an epoch
+#[derive(Debug, Clone)]
+pub struct MaspCommitmentTree {
+    pub epoch: u32,
+    pub size: u64,
+}
@@ -33,3 +33,29 @@ pub struct PgfPayment {
     pub amount: Amount,
     pub action: Option<PgfAction>,
 }
+
+/// A PGF payment due to a recipient at an epoch. Retro payments are recorded
+/// when their proposal is executed, continuous ones from the fundings scheduled
+/// in the first block of the epoch
+#[derive(Debug, Clone)]
+pub struct PgfScheduledPayment {
+    pub proposal_id: u64,
+    pub recurrence: PaymentRecurrence,
+    pub kind: PaymentKind,
+    pub receipient: Id,
+    pub amount: Amount,
+    pub epoch: u32,
+}
+
+impl PgfScheduledPayment {
+    pub fn from_payment(payment: PgfPayment, epoch: u32) -> Self {
+        Self {
+            proposal_id: payment.proposal_id,
+            recurrence: payment.recurrence,
+            kind: payment.kind,
+            receipient: payment.receipient,
+            amount: payment.amount,
+            epoch,
+        }
+    }
+}
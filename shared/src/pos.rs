@@ -1,8 +1,13 @@
+use std::str::FromStr;
+
+use bigdecimal::{BigDecimal, Zero};
 use fake::Fake;
+use namada_core::token::NATIVE_MAX_DECIMAL_PLACES;
 
 use crate::balance::Amount;
-use crate::block::Epoch;
+use crate::block::{BlockHeight, Epoch};
 use crate::id::Id;
+use crate::validator::{ValidatorSet, ValidatorState};
 
 #[derive(Hash, Debug, Clone, PartialEq, Eq)]
 pub struct BondAddresses {
@@ -85,3 +90,215 @@ impl Redelegation {
 }
 
 pub type Redelegations = Vec<Redelegation>;
+
+/// A redelegation tx, moving a bond from one validator to another
+#[derive(Debug, Clone)]
+pub struct RedelegationTx {
+    pub inner_tx_id: Id,
+    pub delegator: Id,
+    pub src_validator: Id,
+    pub dest_validator: Id,
+    pub amount: Amount,
+    pub epoch: Epoch,
+    pub height: BlockHeight,
+}
+
+/// Network-wide staking statistics at the given epoch
+#[derive(Debug, Clone)]
+pub struct PosStats {
+    pub epoch: Epoch,
+    pub total_bonded: Amount,
+    pub total_supply: Amount,
+    pub nakamoto_coefficient: u64,
+    pub active_validators: u64,
+    pub jailed_validators: u64,
+    pub inactive_validators: u64,
+    pub median_commission: Option<BigDecimal>,
+    pub unbonding_queue: Amount,
+}
+
+impl PosStats {
+    pub fn new(
+        validator_set: &ValidatorSet,
+        total_supply: Amount,
+        unbonding_queue: Amount,
+    ) -> Self {
+        let validators = &validator_set.validators;
+
+        let count = |states: &[ValidatorState]| {
+            validators
+                .iter()
+                .filter(|validator| states.contains(&validator.state))
+                .count() as u64
+        };
+        let active_states = [
+            ValidatorState::Consensus,
+            ValidatorState::BelowCapacity,
+            ValidatorState::BelowThreshold,
+            ValidatorState::Deactivating,
+        ];
+
+        let mut commissions = validators
+            .iter()
+            .filter(|validator| active_states.contains(&validator.state))
+            .filter_map(|validator| {
+                BigDecimal::from_str(&validator.commission).ok()
+            })
+            .collect::<Vec<_>>();
+        commissions.sort();
+
+        let median_commission = match commissions.len() {
+            0 => None,
+            len if len % 2 == 0 => {
+                Some((&commissions[len / 2 - 1] + &commissions[len / 2]) / 2)
+            }
+            len => Some(commissions[len / 2].clone()),
+        };
+
+        // Every validator of the set, jailed or inactive ones included, holds
+        // bonds active at this epoch
+        let total_bonded = validators
+            .iter()
+            .filter_map(|validator| {
+                BigDecimal::from_str(&validator.voting_power).ok()
+            })
+            .sum::<BigDecimal>();
+        let total_bonded = Amount::from(
+            (total_bonded
+                * BigDecimal::from(
+                    10_u64.pow(NATIVE_MAX_DECIMAL_PLACES as u32),
+                ))
+            .with_scale(0),
+        );
+
+        let mut voting_powers = validators
+            .iter()
+            .filter(|validator| validator.state == ValidatorState::Consensus)
+            .filter_map(|validator| {
+                BigDecimal::from_str(&validator.voting_power).ok()
+            })
+            .collect::<Vec<_>>();
+        voting_powers.sort_by(|a, b| b.cmp(a));
+
+        Self {
+            epoch: validator_set.epoch,
+            total_bonded,
+            total_supply,
+            nakamoto_coefficient: nakamoto_coefficient(&voting_powers),
+            active_validators: count(&active_states),
+            jailed_validators: count(&[
+                ValidatorState::Jailed,
+                ValidatorState::Unjailing,
+            ]),
+            inactive_validators: count(&[
+                ValidatorState::Inactive,
+                ValidatorState::Reactivating,
+            ]),
+            median_commission,
+            unbonding_queue,
+        }
+    }
+}
+
+/// Smallest number of validators controlling more than a third of the voting
+/// power, expects the voting powers sorted in descending order
+fn nakamoto_coefficient(voting_powers: &[BigDecimal]) -> u64 {
+    let total = voting_powers.iter().sum::<BigDecimal>();
+
+    let mut cumulated = BigDecimal::zero();
+    for (index, voting_power) in voting_powers.iter().enumerate() {
+        cumulated += voting_power;
+        if &cumulated * 3 > total {
+            return index as u64 + 1;
+        }
+    }
+
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::validator::Validator;
+
+    fn validator(
+        voting_power: &str,
+        commission: &str,
+        state: ValidatorState,
+    ) -> Validator {
+        Validator {
+            voting_power: voting_power.to_string(),
+            commission: commission.to_string(),
+            state,
+            ..Validator::fake()
+        }
+    }
+
+    fn stats(validators: Vec<Validator>) -> PosStats {
+        let validator_set = ValidatorSet {
+            validators: validators.into_iter().collect::<HashSet<_>>(),
+            epoch: 1,
+        };
+
+        PosStats::new(&validator_set, Amount::zero(), Amount::zero())
+    }
+
+    #[test]
+    fn median_commission_of_even_length_averages_middle_values() {
+        let pos_stats = stats(vec![
+            validator("10", "0.01", ValidatorState::Consensus),
+            validator("10", "0.04", ValidatorState::BelowCapacity),
+            validator("10", "0.02", ValidatorState::Consensus),
+            validator("10", "0.1", ValidatorState::BelowThreshold),
+        ]);
+
+        assert_eq!(
+            pos_stats.median_commission,
+            Some(BigDecimal::from_str("0.03").unwrap())
+        );
+    }
+
+    #[test]
+    fn empty_validator_set() {
+        let pos_stats = stats(vec![]);
+
+        assert_eq!(pos_stats.total_bonded, Amount::zero());
+        assert_eq!(pos_stats.nakamoto_coefficient, 0);
+        assert_eq!(pos_stats.median_commission, None);
+        assert_eq!(pos_stats.active_validators, 0);
+        assert_eq!(pos_stats.jailed_validators, 0);
+        assert_eq!(pos_stats.inactive_validators, 0);
+    }
+
+    #[test]
+    fn nakamoto_coefficient_excludes_non_consensus_validators() {
+        let pos_stats = stats(vec![
+            validator("34", "0.05", ValidatorState::Consensus),
+            validator("33", "0.05", ValidatorState::Consensus),
+            validator("33", "0.05", ValidatorState::Consensus),
+            validator("10", "0.05", ValidatorState::BelowCapacity),
+            validator("10", "0.05", ValidatorState::Jailed),
+        ]);
+
+        assert_eq!(pos_stats.nakamoto_coefficient, 1);
+        assert_eq!(pos_stats.active_validators, 4);
+        assert_eq!(pos_stats.jailed_validators, 1);
+        assert_eq!(pos_stats.inactive_validators, 0);
+    }
+
+    #[test]
+    fn total_bonded_sums_the_stake_of_every_validator() {
+        let pos_stats = stats(vec![
+            validator("10.5", "0.05", ValidatorState::Consensus),
+            validator("2.000001", "0.05", ValidatorState::BelowCapacity),
+            validator("1", "0.05", ValidatorState::Jailed),
+        ]);
+
+        assert_eq!(
+            pos_stats.total_bonded,
+            Amount::from(BigDecimal::from(13_500_001))
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use fake::Fake;
 use namada_governance::ProposalType;
@@ -12,7 +12,7 @@ use namada_sdk::token::Amount;
 use rand::distributions::{Distribution, Standard};
 use subtle_encoding::hex;
 
-use crate::block::Epoch;
+use crate::block::{BlockHeight, Epoch};
 use crate::id::Id;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -55,6 +55,8 @@ pub struct GovernanceProposal {
     pub voting_start_epoch: Epoch,
     pub voting_end_epoch: Epoch,
     pub activation_epoch: Epoch,
+    pub init_tx_id: Option<Id>,
+    pub init_height: Option<BlockHeight>,
 }
 
 impl From<StorageProposal> for GovernanceProposal {
@@ -71,6 +73,41 @@ impl From<StorageProposal> for GovernanceProposal {
             voting_start_epoch: proposal.voting_start_epoch.0 as Epoch,
             voting_end_epoch: proposal.voting_end_epoch.0 as Epoch,
             activation_epoch: proposal.activation_epoch.0 as Epoch,
+            init_tx_id: None,
+            init_height: None,
+        }
+    }
+}
+
+/// The well known fields of a proposal content
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GovernanceProposalContent {
+    pub title: Option<String>,
+    pub authors: Option<String>,
+    pub discussions_to: Option<String>,
+    pub r#abstract: Option<String>,
+}
+
+impl GovernanceProposalContent {
+    /// Extracts the fields from the json serialized content, leaving them
+    /// empty if the content is not a json object of strings
+    pub fn from_json(content: &str) -> Self {
+        let mut content =
+            serde_json::from_str::<BTreeMap<String, String>>(content)
+                .unwrap_or_default();
+
+        let mut field = |key: &str| {
+            content
+                .remove(key)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        Self {
+            title: field("title"),
+            authors: field("authors"),
+            discussions_to: field("discussions-to"),
+            r#abstract: field("abstract"),
         }
     }
 }
@@ -82,6 +119,7 @@ pub struct GovernanceProposalStatus {
     pub yay_votes: String,
     pub nay_votes: String,
     pub abstain_votes: String,
+    pub total_voting_power: String,
 }
 
 impl GovernanceProposal {
@@ -147,6 +185,8 @@ impl GovernanceProposal {
             voting_start_epoch,
             voting_end_epoch,
             activation_epoch,
+            init_tx_id: None,
+            init_height: None,
             content: "Lorem ipsum dolor sit amet, consectetur adipiscing \
                       elit. Nullam purus tellus, mollis in nisi sed, laoreet \
                       scelerisque ante. Mauris at odio in magna ullamcorper \
@@ -191,6 +231,7 @@ impl GovernanceProposalStatus {
             yay_votes: (0..10000).fake::<u64>().to_string(),
             nay_votes: (0..10000).fake::<u64>().to_string(),
             abstain_votes: (0..10000).fake::<u64>().to_string(),
+            total_voting_power: (30000..40000).fake::<u64>().to_string(),
         }
     }
 }
@@ -9,6 +9,12 @@ pub struct Reward {
     pub epoch: i32,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RewardClaim {
+    pub inner_tx_id: Id,
+    pub delegation_pair: DelegationPair,
+}
+
 impl Reward {
     pub fn fake(validator_id: u64) -> Self {
         let delegator_address =
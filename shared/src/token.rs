@@ -50,6 +50,13 @@ impl Token {
     }
 }
 
+/// Number of decimal places of a token, as stored in the chain state
+#[derive(Debug, Clone)]
+pub struct TokenDenomination {
+    pub address: Id,
+    pub denomination: u8,
+}
+
 #[derive(Debug)]
 pub struct IbcRateLimit {
     /// Address of the token in Namada
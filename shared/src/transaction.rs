@@ -21,7 +21,9 @@ use namada_tx::{IndexedTx, Section, Tx};
 use serde::Serialize;
 
 use crate::block::BlockHeight;
-use crate::block_result::{BlockResult, TxEventStatusCode};
+use crate::block_result::{
+    BlockResult, FungibleTokenPacket, TxEventStatusCode,
+};
 use crate::checksums::Checksums;
 use crate::id::Id;
 use crate::ser::{IbcMessage, TransferData};
@@ -657,7 +659,10 @@ pub struct IbcSequence {
     pub source_channel: String,
     pub dest_channel: String,
     pub timeout: u64,
+    pub timeout_height: String,
     pub tx_id: Id,
+    pub height: BlockHeight,
+    pub data: Option<FungibleTokenPacket>,
 }
 
 impl IbcSequence {
@@ -689,9 +694,16 @@ pub struct IbcAck {
     pub source_channel: String,
     pub dest_channel: String,
     pub status: IbcAckStatus,
+    pub tx_id: Id,
 }
 
 impl IbcAck {
+    /// ICS-20 refunds the sender in the same message that handles a failed
+    /// acknowledgement or a timeout
+    pub fn is_refund(&self) -> bool {
+        matches!(self.status, IbcAckStatus::Fail | IbcAckStatus::Timeout)
+    }
+
     pub fn id_source(&self) -> String {
         format!(
             "{}/{}/{}",
@@ -2,6 +2,7 @@ use namada_governance::ProposalVote;
 use rand::distributions::{Distribution, Standard};
 use serde::{Deserialize, Serialize};
 
+use crate::block::BlockHeight;
 use crate::id::Id;
 
 #[derive(Debug, Clone, Deserialize, Serialize, Hash, PartialEq, Eq)]
@@ -27,6 +28,7 @@ pub struct GovernanceVote {
     pub proposal_id: u64,
     pub vote: ProposalVoteKind,
     pub address: Id,
+    pub height: Option<BlockHeight>,
 }
 
 impl GovernanceVote {
@@ -39,6 +41,7 @@ impl GovernanceVote {
             proposal_id,
             vote,
             address: Id::Account(address.to_string()),
+            height: None,
         }
     }
 }
@@ -275,8 +275,22 @@ async fn crawling_fn(
                 transaction_repo::update_ibc_sequence(
                     transaction_conn,
                     ibc_ack_packet,
+                    block_height,
                 )?;
 
+                let stuck_sequences =
+                    transaction_repo::mark_stuck_ibc_sequences(
+                        transaction_conn,
+                        timestamp,
+                    )?;
+                if stuck_sequences > 0 {
+                    tracing::warn!(
+                        block = block_height,
+                        stuck_sequences,
+                        "Found ibc packets past their timeout"
+                    );
+                }
+
                 transaction_repo::upsert_ibc_token_flows(
                     transaction_conn,
                     ibc_token_flows,
@@ -292,7 +306,15 @@ async fn crawling_fn(
                     gas_estimates,
                 )?;
 
-                masp_repo::insert_masp_entries(transaction_conn, masp_entries)?;
+                let masp_entries = masp_repo::insert_masp_entries(
+                    transaction_conn,
+                    masp_entries,
+                )?;
+                masp_repo::upsert_masp_pool_flows(
+                    transaction_conn,
+                    masp_entries,
+                    cometbft_block.epoch,
+                )?;
 
                 anyhow::Ok(())
             })
@@ -1,13 +1,22 @@
+use std::collections::HashMap;
+
 use anyhow::Context;
-use diesel::{PgConnection, RunQueryDsl};
-use orm::masp::MaspInsertDb;
-use orm::schema::masp_pool;
+use bigdecimal::{BigDecimal, Zero};
+use chrono::{DurationRound, TimeDelta};
+use diesel::upsert::excluded;
+use diesel::{ExpressionMethods, PgConnection, RunQueryDsl, SelectableHelper};
+use orm::masp::{
+    MaspDb, MaspInsertDb, MaspPoolDirectionDb, MaspPoolFlowInsertDb,
+};
+use orm::schema::{masp_pool, masp_pool_flows};
 use shared::masp::MaspEntry;
 
+/// Inserts the masp entries and returns the ones that were not already
+/// stored
 pub fn insert_masp_entries(
     transaction_conn: &mut PgConnection,
     masp_entries: Vec<MaspEntry>,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<Vec<MaspDb>> {
     diesel::insert_into(masp_pool::table)
         .values::<&Vec<MaspInsertDb>>(
             &masp_entries
@@ -16,8 +25,63 @@ pub fn insert_masp_entries(
                 .collect::<Vec<_>>(),
         )
         .on_conflict_do_nothing()
+        .returning(MaspDb::as_returning())
+        .get_results(transaction_conn)
+        .context("Failed to insert masp pool entries in db")
+}
+
+/// Adds the masp entries to the hourly inflows and outflows of their token
+pub fn upsert_masp_pool_flows(
+    transaction_conn: &mut PgConnection,
+    masp_entries: Vec<MaspDb>,
+    epoch: u32,
+) -> anyhow::Result<()> {
+    let mut flows_map = HashMap::new();
+
+    for entry in masp_entries {
+        let timestamp = entry
+            .timestamp
+            .duration_trunc(TimeDelta::hours(1))
+            .context("Failed to truncate masp entry timestamp")?;
+
+        let flow = flows_map
+            .entry((entry.token_address, timestamp))
+            .or_insert((BigDecimal::zero(), BigDecimal::zero()));
+        match entry.direction {
+            MaspPoolDirectionDb::In => flow.0 += entry.raw_amount,
+            MaspPoolDirectionDb::Out => flow.1 += entry.raw_amount,
+        }
+    }
+
+    let flows = flows_map
+        .into_iter()
+        .map(|((token_address, timestamp), (inflow, outflow))| {
+            MaspPoolFlowInsertDb {
+                token_address,
+                epoch: epoch as i32,
+                timestamp,
+                inflow,
+                outflow,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    diesel::insert_into(masp_pool_flows::table)
+        .values(&flows)
+        .on_conflict((
+            masp_pool_flows::dsl::token_address,
+            masp_pool_flows::dsl::epoch,
+            masp_pool_flows::dsl::timestamp,
+        ))
+        .do_update()
+        .set((
+            masp_pool_flows::dsl::inflow.eq(masp_pool_flows::dsl::inflow
+                + excluded(masp_pool_flows::dsl::inflow)),
+            masp_pool_flows::dsl::outflow.eq(masp_pool_flows::dsl::outflow
+                + excluded(masp_pool_flows::dsl::outflow)),
+        ))
         .execute(transaction_conn)
-        .context("Failed to insert masp pool entries in db")?;
+        .context("Failed to upsert masp pool flows in db")?;
 
     anyhow::Ok(())
 }
@@ -2,10 +2,12 @@ use std::collections::HashSet;
 
 use anyhow::Context;
 use chrono::NaiveDateTime;
+use diesel::dsl::sql;
+use diesel::sql_types::Bool;
 use diesel::upsert::excluded;
 use diesel::{
-    ExpressionMethods, OptionalEmptyChangesetExtension, PgConnection,
-    RunQueryDsl,
+    BoolExpressionMethods, ExpressionMethods, OptionalEmptyChangesetExtension,
+    PgConnection, RunQueryDsl,
 };
 use orm::crawler_state::{BlockStateInsertDb, CrawlerNameDb};
 use orm::gas::GasEstimationInsertDb;
@@ -21,6 +23,7 @@ use orm::transactions::{
     InnerTransactionInsertDb, TransactionHistoryInsertDb,
     WrapperTransactionInsertDb,
 };
+use shared::block::BlockHeight;
 use shared::crawler_state::{BlockCrawlerState, CrawlerName};
 use shared::gas::GasEstimation;
 use shared::transaction::{
@@ -112,13 +115,24 @@ pub fn insert_ibc_sequence(
     transaction_conn: &mut PgConnection,
     ibc_sequences: Vec<IbcSequence>,
 ) -> anyhow::Result<()> {
+    let ibc_sequences = ibc_sequences
+        .into_iter()
+        .filter_map(|sequence| {
+            let sequence_number = sequence.sequence_number.clone();
+            IbcAckInsertDb::try_from(sequence)
+                .inspect_err(|err| {
+                    tracing::warn!(
+                        sequence_number,
+                        ?err,
+                        "Skipping ibc packet with an invalid sequence"
+                    );
+                })
+                .ok()
+        })
+        .collect::<Vec<_>>();
+
     diesel::insert_into(ibc_ack::table)
-        .values::<Vec<IbcAckInsertDb>>(
-            ibc_sequences
-                .into_iter()
-                .map(IbcAckInsertDb::from)
-                .collect(),
-        )
+        .values::<Vec<IbcAckInsertDb>>(ibc_sequences)
         .execute(transaction_conn)
         .context("Failed to update crawler state in db")?;
 
@@ -128,14 +142,14 @@ pub fn insert_ibc_sequence(
 pub fn update_ibc_sequence(
     transaction_conn: &mut PgConnection,
     ibc_acks: Vec<IbcAck>,
+    block_height: BlockHeight,
 ) -> anyhow::Result<()> {
     for ack in ibc_acks {
-        let ack_update = IbcSequencekStatusUpdateDb {
-            status: IbcAckStatusDb::from(ack.status.clone()),
-        };
+        let id = ack.id();
+        let ack_update = IbcSequencekStatusUpdateDb::from((ack, block_height));
         diesel::update(ibc_ack::table)
             .set(ack_update)
-            .filter(ibc_ack::dsl::id.eq(ack.id()))
+            .filter(ibc_ack::dsl::id.eq(id))
             .execute(transaction_conn)
             .optional_empty_changeset()
             .context("Failed to update validator metadata in db")?;
@@ -143,6 +157,46 @@ pub fn update_ibc_sequence(
     anyhow::Ok(())
 }
 
+/// Whether the counterparty height, as last seen by the light client behind
+/// the source channel, reached the timeout height of the packet. Heights are
+/// formatted as `revision-height` and compared revision first, a zero timeout
+/// height means the packet has no height based timeout.
+const IBC_TIMEOUT_HEIGHT_REACHED: &str =
+    "EXISTS (SELECT 1 FROM ibc_channels JOIN ibc_connections ON \
+     ibc_connections.connection_id = ibc_channels.connection_id JOIN \
+     ibc_clients ON ibc_clients.client_id = ibc_connections.client_id WHERE \
+     ibc_channels.port_id = ibc_ack.source_port AND ibc_channels.channel_id = \
+     ibc_ack.source_channel AND CASE WHEN ibc_clients.latest_height ~ \
+     '^[0-9]+-[0-9]+$' THEN string_to_array(ibc_clients.latest_height, \
+     '-')::BIGINT[] END >= CASE WHEN ibc_ack.timeout_height ~ \
+     '^[0-9]+-[0-9]+$' AND ibc_ack.timeout_height <> '0-0' THEN \
+     string_to_array(ibc_ack.timeout_height, '-')::BIGINT[] END)";
+
+/// Flag the packets whose timeout is past, i.e. whose timeout timestamp is
+/// past the given block time or whose timeout height was reached on the
+/// counterparty chain, but for which no acknowledgement nor timeout was
+/// relayed
+pub fn mark_stuck_ibc_sequences(
+    transaction_conn: &mut PgConnection,
+    block_timestamp: i64,
+) -> anyhow::Result<usize> {
+    let block_timestamp_nanos = block_timestamp.saturating_mul(1_000_000_000);
+
+    diesel::update(ibc_ack::table)
+        .filter(ibc_ack::dsl::status.eq(IbcAckStatusDb::Unknown))
+        .filter(ibc_ack::dsl::ack_tx_hash.is_null())
+        .filter(ibc_ack::dsl::stuck.eq(false))
+        .filter(
+            ibc_ack::dsl::timeout
+                .gt(0)
+                .and(ibc_ack::dsl::timeout.lt(block_timestamp_nanos))
+                .or(sql::<Bool>(IBC_TIMEOUT_HEIGHT_REACHED)),
+        )
+        .set(ibc_ack::dsl::stuck.eq(true))
+        .execute(transaction_conn)
+        .context("Failed to mark stuck ibc sequences in db")
+}
+
 pub fn insert_transactions_history(
     transaction_conn: &mut PgConnection,
     txs: HashSet<TransactionTarget>,
@@ -2,6 +2,7 @@ use bigdecimal::BigDecimal;
 use namada_sdk::ibc::core::channel::types::acknowledgement::AcknowledgementStatus;
 use namada_sdk::ibc::core::channel::types::msgs::PacketMsg;
 use namada_sdk::ibc::core::handler::types::msgs::MsgEnvelope;
+use shared::block::BlockHeight;
 use shared::block_result::{BlockResult, TxAttributesType};
 use shared::gas::GasEstimation;
 use shared::transaction::{
@@ -71,9 +72,12 @@ pub fn get_ibc_packets(
                         source_channel: packet.source_channel.clone(),
                         dest_channel: packet.dest_channel.clone(),
                         timeout: packet.timeout_timestamp,
+                        timeout_height: packet.timeout_height.clone(),
                         tx_id: ibc_txs
                             .pop()
                             .expect("Ibc ack should have a corresponding tx."),
+                        height: block_results.height as BlockHeight,
+                        data: packet.fungible_token_packet(),
                     }),
                     _ => None,
                 }
@@ -107,6 +111,7 @@ pub fn get_ibc_ack_packet(inner_txs: &[InnerTransaction]) -> Vec<IbcAck> {
                                         AcknowledgementStatus::Success(_) => IbcAckStatus::Success,
                                         AcknowledgementStatus::Error(_) => IbcAckStatus::Fail,
                                     },
+                                    tx_id: tx.tx_id.clone(),
                                 },
                                 Err(_) => IbcAck {
                                     sequence_number: msg.packet.seq_on_a.to_string(),
@@ -115,6 +120,7 @@ pub fn get_ibc_ack_packet(inner_txs: &[InnerTransaction]) -> Vec<IbcAck> {
                                     source_channel: msg.packet.chan_id_on_a.to_string(),
                                     dest_channel: msg.packet.chan_id_on_b.to_string(),
                                     status: IbcAckStatus::Unknown,
+                                    tx_id: tx.tx_id.clone(),
                                 },
                             };
                             Some(ack)
@@ -126,6 +132,7 @@ pub fn get_ibc_ack_packet(inner_txs: &[InnerTransaction]) -> Vec<IbcAck> {
                             source_channel: msg.packet.chan_id_on_a.to_string(),
                             dest_channel: msg.packet.chan_id_on_b.to_string(),
                             status: IbcAckStatus::Timeout,
+                            tx_id: tx.tx_id.clone(),
                         }),
                         PacketMsg::TimeoutOnClose(msg) => Some(IbcAck {
                             sequence_number: msg.packet.seq_on_a.to_string(),
@@ -134,6 +141,7 @@ pub fn get_ibc_ack_packet(inner_txs: &[InnerTransaction]) -> Vec<IbcAck> {
                             source_channel: msg.packet.chan_id_on_a.to_string(),
                             dest_channel: msg.packet.chan_id_on_b.to_string(),
                             status: IbcAckStatus::Timeout,
+                            tx_id: tx.tx_id.clone(),
                         }),
                     },
                     _ => None,
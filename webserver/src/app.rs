@@ -4,7 +4,7 @@ use std::time::Duration;
 use axum::error_handling::HandleErrorLayer;
 use axum::http::{HeaderValue, StatusCode};
 use axum::response::IntoResponse;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::{BoxError, Json, Router};
 use axum_prometheus::PrometheusMetricLayer;
 use lazy_static::lazy_static;
@@ -25,11 +25,14 @@ use crate::handler::{
     masp as masp_handlers, pgf as pgf_service, pk as pk_handlers,
     pos as pos_handlers, transaction as transaction_handlers,
 };
+use crate::service::ibc::IbcService;
 use crate::state::common::CommonState;
+use crate::token_registry::TokenRegistry;
 
 lazy_static! {
     static ref HTTP_TIMEOUT: u64 = 60;
     static ref REQ_PER_SEC: u64 = u64::MAX;
+    static ref STUCK_PACKETS_REFRESH_SECS: u64 = 30;
 }
 
 pub struct ApplicationServer;
@@ -43,12 +46,38 @@ impl ApplicationServer {
 
         let app_state = AppState::new(db_url, cache_url);
         let client = Client::new(&config.tendermint_url);
+        let token_registry =
+            TokenRegistry::load(config.token_registry_path.as_deref())?;
+
+        // The stuck packets gauge is refreshed in the background, so that
+        // scrapes don't query the database
+        let ibc_service = IbcService::new(app_state.clone());
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(
+                *STUCK_PACKETS_REFRESH_SECS,
+            ));
+            loop {
+                interval.tick().await;
+                match ibc_service.count_ibc_stuck_transfers().await {
+                    Ok(stuck) => {
+                        metrics::gauge!("ibc_stuck_packets").set(stuck as f64)
+                    }
+                    Err(err) => {
+                        tracing::warn!(
+                            ?err,
+                            "Failed to count stuck ibc packets"
+                        )
+                    }
+                }
+            }
+        });
 
         let routes = {
             let common_state = CommonState::new(
                 client.get(),
                 config.clone(),
                 app_state.clone(),
+                token_registry,
             );
 
             Router::new()
@@ -57,16 +86,45 @@ impl ApplicationServer {
                     "/pos/validator/all",
                     get(pos_handlers::get_all_validators),
                 )
+                .route(
+                    "/pos/validator/{address}/delegators",
+                    get(pos_handlers::get_validator_delegators),
+                )
+                .route(
+                    "/pos/validator/{address}/stake-distribution",
+                    get(pos_handlers::get_validator_stake_distribution),
+                )
+                .route("/pos/stats", get(pos_handlers::get_pos_stats))
+                .route(
+                    "/pos/stats/history",
+                    get(pos_handlers::get_pos_stats_history),
+                )
                 .route("/pos/bond/{address}", get(pos_handlers::get_bonds))
                 .route(
                     "/pos/merged-bonds/{address}",
                     get(pos_handlers::get_merged_bonds),
                 )
                 .route("/pos/unbond/{address}", get(pos_handlers::get_unbonds))
+                .route(
+                    "/pos/unbonding-schedule",
+                    get(pos_handlers::get_unbonding_schedule),
+                )
+                .route(
+                    "/pos/unbonding-schedule/{address}",
+                    get(pos_handlers::get_unbonding_schedule_by_address),
+                )
                 .route(
                     "/pos/merged-unbonds/{address}",
                     get(pos_handlers::get_merged_unbonds),
                 )
+                .route(
+                    "/pos/redelegations/{address}",
+                    get(pos_handlers::get_redelegations),
+                )
+                .route(
+                    "/pos/redelegations/{delegator}/{validator}/eligibility",
+                    get(pos_handlers::get_redelegation_eligibility),
+                )
                 .route(
                     "/pos/withdraw/{address}",
                     get(pos_handlers::get_withdraws),
@@ -76,6 +134,10 @@ impl ApplicationServer {
                     "/pos/reward/{delegator}/{validator}/{epoch}",
                     get(pos_handlers::get_rewards_by_delegator_and_validator_and_epoch),
                 )
+                .route(
+                    "/pos/reward-ledger/{address}",
+                    get(pos_handlers::get_reward_ledger),
+                )
                 .route(
                     "/pos/voting-power",
                     get(pos_handlers::get_total_voting_power),
@@ -84,6 +146,10 @@ impl ApplicationServer {
                     "/gov/proposal",
                     get(gov_handlers::get_governance_proposals),
                 )
+                .route(
+                    "/gov/proposal/search",
+                    get(gov_handlers::search_governance_proposals),
+                )
                 .route(
                     "/gov/proposal/{id}",
                     get(gov_handlers::get_governance_proposal_by_id),
@@ -92,6 +158,14 @@ impl ApplicationServer {
                     "/gov/proposal/{id}/data",
                     get(gov_handlers::get_proposal_data_by_proposal_id),
                 )
+                .route(
+                    "/gov/proposal/{id}/timeline",
+                    get(gov_handlers::get_governance_proposal_timeline),
+                )
+                .route(
+                    "/gov/proposal/{id}/tally/projection",
+                    get(gov_handlers::get_governance_proposal_tally_projection),
+                )
                 .route(
                     "/gov/proposal/{id}/votes",
                     get(gov_handlers::get_governance_proposal_votes),
@@ -104,6 +178,18 @@ impl ApplicationServer {
                     "/gov/voter/{address}/votes",
                     get(gov_handlers::get_governance_proposal_votes_by_voter),
                 )
+                .route(
+                    "/gov/voter/{address}/participation",
+                    get(gov_handlers::get_voter_participation),
+                )
+                .route(
+                    "/gov/participation/validators",
+                    get(gov_handlers::get_validators_participation),
+                )
+                .route(
+                    "/gov/participation/validators/non-voting",
+                    get(gov_handlers::get_non_voting_validators),
+                )
                 .route(
                     "/account/{address}",
                     get(balance_handlers::get_address_balance),
@@ -112,7 +198,11 @@ impl ApplicationServer {
                     "/revealed-public-key/{address}",
                     get(pk_handlers::get_revealed_pk),
                 )
-                .route("/gas/estimate", get(gas_handlers::get_gas_estimate))
+                .route(
+                    "/gas/estimate",
+                    get(gas_handlers::get_gas_estimate)
+                        .post(gas_handlers::get_gas_estimate_for_batch),
+                )
                 .route(
                     "/gas-price/{token}",
                     get(gas_handlers::get_gas_price_by_token),
@@ -134,17 +224,34 @@ impl ApplicationServer {
                     "/chain/history",
                     get(transaction_handlers::get_transaction_history),
                 )
+                .route("/tx/dry-run", post(transaction_handlers::dry_run_tx))
                 .route("/chain/parameters", get(chain_handlers::get_parameters))
                 .route("/chain/rpc-url", get(chain_handlers::get_rpc_url))
                 .route("/chain/token", get(chain_handlers::get_tokens))
+                .route(
+                    "/chain/token/{address}/holders",
+                    get(chain_handlers::get_token_holders),
+                )
                 .route(
                     "/chain/token-supply",
                     get(chain_handlers::get_token_supply),
                 )
+                .route(
+                    "/chain/token-supply/history",
+                    get(chain_handlers::get_token_supply_history),
+                )
+                .route(
+                    "/chain/inflation",
+                    get(chain_handlers::get_native_inflation),
+                )
                 .route(
                     "/chain/circulating-supply",
                     get(chain_handlers::get_circulating_supply),
                 )
+                .route(
+                    "/chain/circulating-supply/breakdown",
+                    get(chain_handlers::get_circulating_supply_breakdown),
+                )
                 .route(
                     "/chain/block/latest",
                     get(chain_handlers::get_last_processed_block),
@@ -153,11 +260,30 @@ impl ApplicationServer {
                     "/chain/epoch/latest",
                     get(chain_handlers::get_last_processed_epoch),
                 )
+                .route("/chain/epoch/{epoch}", get(chain_handlers::get_epoch))
                 .route("/ibc/{tx_id}/status", get(ibc_handler::get_ibc_status))
+                .route("/ibc/transfers", get(ibc_handler::get_ibc_transfers))
+                .route(
+                    "/ibc/pending",
+                    get(ibc_handler::get_ibc_pending_transfers),
+                )
+                .route("/ibc/channels", get(ibc_handler::get_ibc_channels))
+                .route(
+                    "/ibc/channels/{channel_id}",
+                    get(ibc_handler::get_ibc_channel),
+                )
                 .route(
                     "/ibc/rate-limits",
                     get(ibc_handler::get_ibc_rate_limits),
                 )
+                .route(
+                    "/ibc/rate-limits/{token}/history",
+                    get(ibc_handler::get_ibc_rate_limit_history),
+                )
+                .route(
+                    "/ibc/rate-limits/{token}/headroom",
+                    get(ibc_handler::get_ibc_rate_limit_headroom),
+                )
                 .route(
                     "/ibc/token-flows",
                     get(ibc_handler::get_ibc_token_flows),
@@ -170,6 +296,23 @@ impl ApplicationServer {
                     "/pgf/payments/{proposal_id}",
                     get(pgf_service::get_pgf_payment_by_proposal_id),
                 )
+                .route("/pgf/stewards", get(pgf_service::get_pgf_stewards))
+                .route(
+                    "/pgf/stewards/history",
+                    get(pgf_service::get_pgf_steward_history),
+                )
+                .route(
+                    "/pgf/continuous-payments",
+                    get(pgf_service::get_pgf_active_continuous_payments),
+                )
+                .route(
+                    "/pgf/scheduled-payments",
+                    get(pgf_service::get_pgf_scheduled_payments),
+                )
+                .route(
+                    "/pgf/recipients",
+                    get(pgf_service::get_pgf_recipient_totals),
+                )
                 .route(
                     "/crawlers/timestamps",
                     get(crawler_state_handlers::get_crawlers_timestamps),
@@ -196,6 +339,22 @@ impl ApplicationServer {
                     "/masp/rates",
                     get(masp_handlers::get_masp_rates),
                 )
+                .route(
+                    "/masp/pool/{token}/series",
+                    get(masp_handlers::get_masp_pool_series),
+                )
+                .route(
+                    "/masp/rates/{token}/history",
+                    get(masp_handlers::get_masp_rates_history),
+                )
+                .route(
+                    "/masp/rewards/estimate",
+                    get(masp_handlers::get_masp_rewards_estimate),
+                )
+                .route(
+                    "/masp/activity",
+                    get(masp_handlers::get_masp_activity),
+                )
                 .route(
                     "/metrics",
                     get(|| async move { metric_handle.render() }),
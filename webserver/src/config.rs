@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use shared::log_config::LogConfig;
 
 #[derive(clap::Parser, Clone)]
@@ -17,6 +19,24 @@ pub struct AppConfig {
     #[clap(long, env)]
     pub tendermint_url: String,
 
+    /// Path to a chain-registry `assetlist.json` overriding token metadata
+    #[clap(long, env)]
+    pub token_registry_path: Option<PathBuf>,
+
+    /// Accounts whose native balance is locked, e.g. vesting or genesis
+    /// allocations, excluded from the circulating supply
+    #[clap(
+        long,
+        env,
+        value_delimiter = ',',
+        default_value = "tnam1qxdzup2hcvhswcgw5kerd5lfkf04t64y3scgqm5v,\
+                         tnam1qxt7uxhj9r00mfm4u870e7ghz6j20jrdz58gm5kj,\
+                         tnam1qyez9fd9nkaxfj4u2f2k0vavr8mm69azcgds45rr,\
+                         tnam1qqp69rzwsgnqdm0d4qfhw4qa4s6v3tlzm5069f4j,\
+                         tnam1qrucghh3hw2zq8xtqzdj44nh5nrmnkn0usqng8yq"
+    )]
+    pub locked_addresses: Vec<String>,
+
     #[clap(flatten)]
     pub log: LogConfig,
 }
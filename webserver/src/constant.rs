@@ -1 +1,5 @@
 pub const ITEM_PER_PAGE: u64 = 30;
+pub const NATIVE_TOKEN_SYMBOL: &str = "NAM";
+pub const NATIVE_TOKEN_NAME: &str = "Namada";
+/// Number of order-of-magnitude buckets of the token holders distribution
+pub const HOLDER_BUCKETS: i32 = 8;
@@ -9,9 +9,34 @@ pub struct TokenSupply {
     pub address: String,
 }
 
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenSupplyHistory {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    pub address: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeInflation {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+}
+
 #[derive(Clone, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct CirculatingSupply {
     #[validate(range(min = 0))]
     pub epoch: Option<i32>,
 }
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenHoldersQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    /// Leave out the balances of protocol addresses (PoS, PGF, MASP, IBC
+    /// escrow, ...)
+    pub exclude_protocol: Option<bool>,
+}
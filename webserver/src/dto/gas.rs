@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::entity::gas::GasBatch;
+use crate::entity::transaction::TransactionKind;
 use crate::error::gas::GasError;
 
 #[derive(Clone, Serialize, Deserialize, Validate)]
@@ -67,3 +69,96 @@ impl GasEstimateQuery {
         }
     }
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GasEstimateTxKindDto {
+    TransparentTransfer,
+    ShieldedTransfer,
+    ShieldingTransfer,
+    UnshieldingTransfer,
+    IbcTransparentTransfer,
+    IbcShieldingTransfer,
+    IbcUnshieldingTransfer,
+    Bond,
+    Redelegation,
+    Unbond,
+    Withdraw,
+    ClaimRewards,
+    VoteProposal,
+    RevealPk,
+}
+
+impl From<GasEstimateTxKindDto> for TransactionKind {
+    fn from(value: GasEstimateTxKindDto) -> Self {
+        match value {
+            GasEstimateTxKindDto::TransparentTransfer => {
+                Self::TransparentTransfer
+            }
+            GasEstimateTxKindDto::ShieldedTransfer => Self::ShieldedTransfer,
+            GasEstimateTxKindDto::ShieldingTransfer => Self::ShieldingTransfer,
+            GasEstimateTxKindDto::UnshieldingTransfer => {
+                Self::UnshieldingTransfer
+            }
+            GasEstimateTxKindDto::IbcTransparentTransfer => {
+                Self::IbcTransparentTransfer
+            }
+            GasEstimateTxKindDto::IbcShieldingTransfer => {
+                Self::IbcShieldingTransfer
+            }
+            GasEstimateTxKindDto::IbcUnshieldingTransfer => {
+                Self::IbcUnshieldingTransfer
+            }
+            GasEstimateTxKindDto::Bond => Self::Bond,
+            GasEstimateTxKindDto::Redelegation => Self::Redelegation,
+            GasEstimateTxKindDto::Unbond => Self::Unbond,
+            GasEstimateTxKindDto::Withdraw => Self::Withdraw,
+            GasEstimateTxKindDto::ClaimRewards => Self::ClaimRewards,
+            GasEstimateTxKindDto::VoteProposal => Self::VoteProposal,
+            GasEstimateTxKindDto::RevealPk => Self::RevealPk,
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct GasEstimateBatchTx {
+    pub kind: GasEstimateTxKindDto,
+    #[validate(range(min = 1, max = 100))]
+    pub count: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct GasEstimateBatchBody {
+    #[validate]
+    #[validate(length(min = 1, max = 100))]
+    pub txs: Vec<GasEstimateBatchTx>,
+    #[validate(range(min = 1, max = 20))]
+    pub signatures: Option<u64>,
+    #[validate(range(min = 1, max = 100000))]
+    pub tx_size: Option<u64>,
+}
+
+impl GasEstimateBatchBody {
+    pub fn is_valid(&self) -> Result<(), GasError> {
+        self.validate()
+            .map_err(|e| GasError::InvalidBatch(e.to_string()))
+    }
+}
+
+impl From<GasEstimateBatchBody> for GasBatch {
+    fn from(value: GasEstimateBatchBody) -> Self {
+        let mut batch = GasBatch {
+            signatures: value.signatures,
+            tx_size: value.tx_size,
+            ..Default::default()
+        };
+
+        for tx in value.txs {
+            batch.add(&tx.kind.into(), tx.count.unwrap_or(1));
+        }
+
+        batch
+    }
+}
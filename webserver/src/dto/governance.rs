@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::dto::pos::ValidatorStateDto;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ProposalStatus {
@@ -30,8 +32,21 @@ pub struct ProposalQueryParams {
     pub pattern: Option<String>,
 }
 
+#[derive(Clone, Serialize, Deserialize, Validate)]
+pub struct ProposalSearchQueryParams {
+    #[validate(length(min = 3, max = 256))]
+    pub q: String,
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+}
+
 #[derive(Clone, Serialize, Deserialize, Validate)]
 pub struct ProposalVotesQueryparams {
     #[validate(range(min = 1, max = 10000))]
     pub page: Option<u64>,
 }
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+pub struct ValidatorParticipationQueryParams {
+    pub state: Option<Vec<ValidatorStateDto>>,
+}
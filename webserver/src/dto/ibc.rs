@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -12,3 +13,55 @@ pub struct IbcRateLimit {
 pub struct IbcTokenFlow {
     pub token_address: Option<String>,
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IbcTransferStatusDto {
+    Pending,
+    Stuck,
+    Completed,
+    Refunded,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcTransferQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    pub sender: Option<String>,
+    pub receiver: Option<String>,
+    pub channel: Option<String>,
+    pub status: Option<IbcTransferStatusDto>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IbcChannelStateDto {
+    Uninitialized,
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcChannelQueryParams {
+    pub state: Option<IbcChannelStateDto>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+pub struct IbcPendingQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    /// Only return the packets sent more than this many seconds ago
+    #[validate(range(max = 315360000))]
+    pub older_than: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcRateLimitHistoryQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+}
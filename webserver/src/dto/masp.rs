@@ -6,3 +6,46 @@ use validator::Validate;
 pub struct MaspAggregatesQueryParams {
     pub token: Option<String>,
 }
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MaspPoolSeriesIntervalDto {
+    Hour,
+    #[default]
+    Day,
+    Epoch,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspPoolSeriesQueryParams {
+    pub interval: Option<MaspPoolSeriesIntervalDto>,
+    /// Unix timestamp, or epoch when the interval is `epoch`
+    #[validate(range(min = 0))]
+    pub from: Option<i64>,
+    /// Unix timestamp, or epoch when the interval is `epoch`
+    #[validate(range(min = 0))]
+    pub to: Option<i64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+pub struct MaspRewardHistoryQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspRewardEstimateQueryParams {
+    pub token: String,
+    /// Raw amount of the token to shield
+    pub amount: String,
+    #[validate(range(min = 1, max = 100000))]
+    pub epochs: u64,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+pub struct MaspActivityQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+}
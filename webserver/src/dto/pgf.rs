@@ -6,3 +6,19 @@ pub struct PgfQueryParams {
     #[validate(range(min = 1, max = 10000))]
     pub page: Option<u64>,
 }
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+pub struct PgfStewardHistoryQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    pub address: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+pub struct PgfScheduledPaymentsQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    pub receipient: Option<String>,
+    #[validate(range(min = 0))]
+    pub epoch: Option<i32>,
+}
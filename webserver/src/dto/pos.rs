@@ -51,6 +51,30 @@ pub struct ValidatorQueryParams {
     pub sort_order: Option<OrderByDto>,
 }
 
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DelegatorSortFieldDto {
+    BondedAmount,
+    StartEpoch,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegatorsQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    pub sort_field: Option<DelegatorSortFieldDto>,
+    pub sort_order: Option<OrderByDto>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeDistributionQueryParams {
+    /// Number of largest delegators to compute the share of
+    #[validate(range(min = 1, max = 1000))]
+    pub top: Option<u64>,
+}
+
 #[derive(Clone, Serialize, Deserialize, Validate)]
 pub struct AllValidatorsQueryParams {
     pub state: Option<Vec<ValidatorStateDto>>,
@@ -81,16 +105,41 @@ pub struct UnbondsDto {
     pub active_at: Option<i32>,
 }
 
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct PosStatsQueryParams {
+    #[validate(range(min = 0))]
+    pub epoch: Option<i32>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct PosStatsHistoryQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RedelegationsDto {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+}
+
 #[derive(Clone, Serialize, Deserialize, Validate)]
 pub struct WithdrawsDto {
     #[validate(range(min = 1, max = 10000))]
     pub page: Option<u64>,
     #[validate(range(min = 1, max = 10000))]
     pub epoch: Option<u64>,
+    /// Also return the unbonds that were already withdrawn
+    pub include_history: Option<bool>,
 }
 
 #[derive(Clone, Serialize, Deserialize, Validate)]
 pub struct RewardsDto {
     #[validate(range(min = 1, max = 10000))]
     pub epoch: Option<u64>,
+    /// Also return the rewards that were already claimed
+    pub include_history: Option<bool>,
 }
@@ -39,3 +39,22 @@ pub struct TransactionMostRecentQueryParams {
     #[validate(range(min = 10, max = 30))]
     pub size: Option<u64>,
 }
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionDryRunBody {
+    /// Hex encoded serialized wrapper transaction
+    pub tx: String,
+}
+
+impl TransactionDryRunBody {
+    pub fn tx_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        let tx = self.tx.strip_prefix("0x").unwrap_or(&self.tx);
+
+        hex::decode(tx.as_bytes()).map_err(|_| {
+            TransactionError::InvalidTx(
+                "The transaction must be hex encoded".to_string(),
+            )
+        })
+    }
+}
@@ -1,7 +1,17 @@
-use bigdecimal::ToPrimitive;
+use bigdecimal::{BigDecimal, Signed, ToPrimitive, Zero};
+use namada_sdk::state::EPOCH_SWITCH_BLOCKS_DELAY;
+use orm::epochs::EpochDb;
+use orm::native_inflation_per_epoch::NativeInflationDb;
 use orm::parameters::ParametersDb;
+use orm::token::{IbcTokenDb, TokenDb};
+use orm::token_supplies_per_epoch::TokenSuppliesDb;
 use serde_json::Value as SerdeJSONValue;
+use shared::balance::Amount;
 use shared::id::Id;
+use shared::token::{IbcToken, Token};
+
+use crate::constant::{HOLDER_BUCKETS, NATIVE_TOKEN_NAME, NATIVE_TOKEN_SYMBOL};
+use crate::token_registry::{RegistryAsset, TokenRegistry};
 
 #[derive(Clone, Debug)]
 pub struct Parameters {
@@ -56,11 +66,580 @@ impl From<ParametersDb> for Parameters {
 #[derive(Clone, Debug)]
 pub struct TokenSupply {
     pub address: Id,
-    pub total_supply: u64,
-    pub effective_supply: Option<u64>,
+    pub epoch: i32,
+    pub total_supply: Amount,
+    pub effective_supply: Option<Amount>,
+    pub denominated_total_supply: Option<String>,
+    pub denominated_effective_supply: Option<String>,
+}
+
+impl TokenSupply {
+    pub fn from_db(
+        supply: TokenSuppliesDb,
+        metadata: Option<&TokenMetadata>,
+    ) -> Self {
+        let denominate = |amount: &BigDecimal| {
+            metadata.and_then(|metadata| metadata.denominate(amount))
+        };
+
+        Self {
+            address: Id::Account(supply.address),
+            epoch: supply.epoch,
+            denominated_total_supply: denominate(&supply.total),
+            denominated_effective_supply: supply
+                .effective
+                .as_ref()
+                .and_then(denominate),
+            total_supply: supply.total.into(),
+            effective_supply: supply.effective.map(Amount::from),
+        }
+    }
+}
+
+/// Native tokens minted at the start of an epoch. The residual is the part of
+/// the total supply increase since the previous epoch that is not explained by
+/// the recorded PoS and MASP rewards, such as PGF fundings.
+#[derive(Clone, Debug)]
+pub struct NativeInflation {
+    pub epoch: i32,
+    pub total_supply: Option<Amount>,
+    pub minted: Option<Amount>,
+    pub pos: Amount,
+    pub residual: Option<Amount>,
+    pub masp: Amount,
+    pub annual_rate: Option<f64>,
+}
+
+impl NativeInflation {
+    pub fn from_db(
+        inflation: NativeInflationDb,
+        total_supply: Option<&BigDecimal>,
+        previous_total_supply: Option<&BigDecimal>,
+        epochs_per_year: u64,
+    ) -> Self {
+        let minted = total_supply
+            .zip(previous_total_supply)
+            .map(|(supply, previous)| supply - previous)
+            .filter(|minted| !minted.is_negative());
+
+        let residual = minted.as_ref().map(|minted| {
+            (minted - &inflation.pos_minted - &inflation.masp_minted)
+                .max(BigDecimal::zero())
+        });
+
+        let annual_rate = minted
+            .as_ref()
+            .zip(previous_total_supply)
+            .filter(|(_, previous)| !previous.is_zero())
+            .and_then(|(minted, previous)| {
+                (minted / previous)
+                    .to_f64()
+                    .map(|rate| rate * epochs_per_year as f64)
+            });
+
+        Self {
+            epoch: inflation.epoch,
+            total_supply: total_supply.map(Amount::from),
+            minted: minted.map(Amount::from),
+            pos: inflation.pos_minted.into(),
+            residual: residual.map(Amount::from),
+            masp: inflation.masp_minted.into(),
+            annual_rate,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TokenMetadata {
+    pub token: Token,
+    pub denomination: Option<u8>,
+    pub symbol: Option<String>,
+    pub name: Option<String>,
+    pub logo_uri: Option<String>,
+    pub coingecko_id: Option<String>,
+}
+
+impl TokenMetadata {
+    /// Derives the metadata of a token from the chain state: the
+    /// denomination is indexed by the chain crawler and the symbol of IBC
+    /// tokens comes from the base denom of their trace. Entries of the token
+    /// registry take precedence, except for the denomination.
+    pub fn from_db(
+        token: TokenDb,
+        ibc_token: Option<IbcTokenDb>,
+        registry: &TokenRegistry,
+    ) -> Self {
+        let denomination = token
+            .denomination
+            .and_then(|denomination| u8::try_from(denomination).ok());
+
+        let (token, symbol, name) = match ibc_token {
+            Some(ibc_token) => {
+                let base_denom = ibc_token
+                    .ibc_trace
+                    .rsplit('/')
+                    .next()
+                    .unwrap_or_default()
+                    .to_string();
+                let symbol = symbol_from_base_denom(&base_denom, denomination);
+
+                (
+                    Token::Ibc(IbcToken {
+                        address: Id::Account(ibc_token.address),
+                        trace: Some(Id::IbcTrace(ibc_token.ibc_trace)),
+                    }),
+                    Some(symbol),
+                    Some(base_denom),
+                )
+            }
+            None => (
+                Token::Native(Id::Account(token.address)),
+                Some(NATIVE_TOKEN_SYMBOL.to_string()),
+                Some(NATIVE_TOKEN_NAME.to_string()),
+            ),
+        };
+
+        let trace = match &token {
+            Token::Ibc(IbcToken {
+                trace: Some(trace), ..
+            }) => Some(trace.to_string()),
+            _ => None,
+        };
+        let asset = registry.find(&token.to_string(), trace.as_deref());
+
+        Self {
+            denomination: denomination
+                .or_else(|| asset.and_then(RegistryAsset::denomination)),
+            symbol: asset.and_then(|asset| asset.symbol.clone()).or(symbol),
+            name: asset.and_then(|asset| asset.name.clone()).or(name),
+            logo_uri: asset.and_then(RegistryAsset::logo_uri),
+            coingecko_id: asset.and_then(|asset| asset.coingecko_id.clone()),
+            token,
+        }
+    }
+
+    /// Converts an amount of the smallest unit of the token to its display
+    /// unit
+    pub fn denominate(&self, raw_amount: &BigDecimal) -> Option<String> {
+        let denomination = self.denomination?;
+        let (digits, scale) = raw_amount.as_bigint_and_exponent();
+
+        Some(
+            BigDecimal::new(digits, scale + i64::from(denomination))
+                .normalized()
+                .to_string(),
+        )
+    }
+}
+
+/// Strips the metric prefix of the base denom when it matches the
+/// denomination, e.g. `uosmo` with 6 decimals is `OSMO`
+fn symbol_from_base_denom(
+    base_denom: &str,
+    denomination: Option<u8>,
+) -> String {
+    let prefix = match denomination {
+        Some(3) => Some('m'),
+        Some(6) => Some('u'),
+        Some(9) => Some('n'),
+        Some(18) => Some('a'),
+        _ => None,
+    };
+
+    let symbol = prefix
+        .and_then(|prefix| base_denom.strip_prefix(prefix))
+        .filter(|symbol| !symbol.is_empty())
+        .unwrap_or(base_denom);
+
+    symbol.to_uppercase()
 }
 
 #[derive(Clone, Debug)]
 pub struct CirculatingSupply {
     pub circulating_supply: String,
 }
+
+#[derive(Clone, Debug)]
+pub enum LockedSupplyKind {
+    Bonded,
+    Unbonding,
+    Shielded,
+    Pgf,
+    IbcEscrow,
+    Vesting,
+}
+
+impl LockedSupplyKind {
+    /// Whether the category is part of the circulating supply, only the PGF
+    /// funds, which the effective supply excludes, and the vesting accounts
+    /// are not.
+    pub fn is_circulating(&self) -> bool {
+        !matches!(self, Self::Pgf | Self::Vesting)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LockedSupply {
+    pub kind: LockedSupplyKind,
+    pub amount: Amount,
+    pub share: f64,
+    pub circulating: bool,
+    /// Accounts the amount is read from, empty if it is derived from the
+    /// staking statistics or the effective supply
+    pub accounts: Vec<(Id, Amount)>,
+}
+
+/// A locked category with its amount and the balances it is read from
+pub type LockedAmount =
+    (LockedSupplyKind, BigDecimal, Vec<(String, BigDecimal)>);
+
+#[derive(Clone, Debug)]
+pub struct CirculatingSupplyBreakdown {
+    pub epoch: i32,
+    pub total_supply: Amount,
+    pub circulating_supply: Amount,
+    pub locked: Vec<LockedSupply>,
+}
+
+impl CirculatingSupplyBreakdown {
+    /// The circulating supply is the total supply minus the categories that
+    /// are not circulating
+    pub fn new(
+        epoch: i32,
+        total_supply: BigDecimal,
+        locked: Vec<LockedAmount>,
+    ) -> Self {
+        let share = |amount: &BigDecimal| {
+            if total_supply.is_zero() {
+                0.0
+            } else {
+                (amount / &total_supply).to_f64().unwrap_or_default()
+            }
+        };
+
+        let non_circulating = locked
+            .iter()
+            .filter(|(kind, _, _)| !kind.is_circulating())
+            .map(|(_, amount, _)| amount)
+            .sum::<BigDecimal>();
+        let circulating_supply =
+            (&total_supply - non_circulating).max(BigDecimal::zero());
+
+        Self {
+            epoch,
+            locked: locked
+                .into_iter()
+                .map(|(kind, amount, accounts)| LockedSupply {
+                    circulating: kind.is_circulating(),
+                    kind,
+                    share: share(&amount),
+                    amount: amount.into(),
+                    accounts: accounts
+                        .into_iter()
+                        .map(|(address, amount)| {
+                            (Id::Account(address), amount.into())
+                        })
+                        .collect(),
+                })
+                .collect(),
+            total_supply: total_supply.into(),
+            circulating_supply: circulating_supply.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EpochInfo {
+    pub epoch: u64,
+    pub first_block_height: Option<u64>,
+    pub start_time: i64,
+    pub estimated: bool,
+}
+
+impl EpochInfo {
+    /// Uses the recorded start of the epoch when known, otherwise projects it
+    /// from the latest indexed epochs. The observed epoch duration and length
+    /// are bounded by the `min_duration` and `min_num_of_blocks` parameters,
+    /// as an epoch can not end before both are reached. Epochs before the
+    /// first indexed block are extrapolated backwards the same way. Returns
+    /// `None` when the projection is out of range.
+    pub fn from_db(
+        epoch: u32,
+        db_epoch: Option<EpochDb>,
+        latest_epochs: &[EpochDb],
+        min_duration: i32,
+        min_num_of_blocks: i32,
+    ) -> Option<Self> {
+        if let Some(EpochDb {
+            first_block_height,
+            timestamp: Some(timestamp),
+            ..
+        }) = db_epoch
+        {
+            return Some(Self {
+                epoch: u64::from(epoch),
+                first_block_height: Some(first_block_height as u64),
+                start_time: timestamp.and_utc().timestamp(),
+                estimated: false,
+            });
+        }
+
+        let latest = latest_epochs.first()?;
+        let latest_time = latest.timestamp?.and_utc().timestamp();
+
+        let (observed_duration, observed_blocks) = latest_epochs
+            .last()
+            .filter(|oldest| oldest.epoch < latest.epoch)
+            .and_then(|oldest| {
+                let epochs = i64::from(latest.epoch) - i64::from(oldest.epoch);
+                let duration =
+                    latest_time - oldest.timestamp?.and_utc().timestamp();
+                let blocks = i64::from(latest.first_block_height)
+                    - i64::from(oldest.first_block_height);
+
+                Some((duration / epochs, blocks / epochs))
+            })
+            .unwrap_or_default();
+
+        let epoch_duration = observed_duration.max(i64::from(min_duration));
+        let blocks_per_epoch = observed_blocks.max(
+            i64::from(min_num_of_blocks) + EPOCH_SWITCH_BLOCKS_DELAY as i64,
+        );
+
+        let epochs_between = i64::from(epoch) - i64::from(latest.epoch);
+        let first_block_height = match db_epoch {
+            Some(db_epoch) => Some(i64::from(db_epoch.first_block_height)),
+            None => epochs_between.checked_mul(blocks_per_epoch).and_then(
+                |blocks| {
+                    blocks.checked_add(i64::from(latest.first_block_height))
+                },
+            ),
+        };
+        let start_time = epochs_between
+            .checked_mul(epoch_duration)
+            .and_then(|duration| duration.checked_add(latest_time))?;
+
+        Some(Self {
+            epoch: u64::from(epoch),
+            first_block_height: first_block_height
+                .and_then(|height| u64::try_from(height).ok())
+                .filter(|height| *height > 0),
+            start_time,
+            estimated: true,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TokenHolder {
+    pub address: Id,
+    pub amount: Amount,
+    pub denominated_amount: Option<String>,
+    pub share: f64,
+}
+
+/// Holders whose balance in the display unit of the token is within
+/// `[min_amount, max_amount)`
+#[derive(Clone, Debug)]
+pub struct HolderBucket {
+    pub min_amount: String,
+    pub max_amount: Option<String>,
+    pub holders: u64,
+    pub amount: Amount,
+    pub share: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct TokenHolders {
+    pub token: Id,
+    pub holder_count: u64,
+    pub total_supply: Amount,
+    pub distribution: Vec<HolderBucket>,
+    pub holders: Vec<TokenHolder>,
+}
+
+impl TokenHolders {
+    pub fn from_db(
+        metadata: &TokenMetadata,
+        total_supply: Option<BigDecimal>,
+        db_holders: Vec<(String, BigDecimal)>,
+        db_distribution: Vec<(i32, i64, Option<BigDecimal>)>,
+    ) -> Self {
+        let held_supply = db_distribution
+            .iter()
+            .filter_map(|(_, _, amount)| amount.as_ref())
+            .sum::<BigDecimal>();
+        // Without an indexed supply, shares are relative to the balances
+        let total_supply = total_supply.unwrap_or(held_supply);
+
+        let share = |amount: &BigDecimal| {
+            if total_supply.is_zero() {
+                0.0
+            } else {
+                (amount / &total_supply).to_f64().unwrap_or_default()
+            }
+        };
+
+        let holders = db_holders
+            .into_iter()
+            .map(|(address, amount)| TokenHolder {
+                address: Id::Account(address),
+                denominated_amount: metadata.denominate(&amount),
+                share: share(&amount),
+                amount: amount.into(),
+            })
+            .collect();
+
+        let distribution = (0..HOLDER_BUCKETS)
+            .map(|bucket| {
+                let (holders, amount) = db_distribution
+                    .iter()
+                    .find(|(index, _, _)| *index == bucket)
+                    .map(|(_, holders, amount)| {
+                        (*holders as u64, amount.clone().unwrap_or_default())
+                    })
+                    .unwrap_or_default();
+
+                let bound = |exponent: i32| {
+                    BigDecimal::from(10u64.pow(exponent as u32)).to_string()
+                };
+
+                HolderBucket {
+                    min_amount: if bucket == 0 {
+                        "0".to_string()
+                    } else {
+                        bound(bucket - 1)
+                    },
+                    max_amount: (bucket < HOLDER_BUCKETS - 1)
+                        .then(|| bound(bucket)),
+                    holders,
+                    share: share(&amount),
+                    amount: amount.into(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            token: metadata.token.address().clone(),
+            holder_count: distribution
+                .iter()
+                .map(|bucket| bucket.holders)
+                .sum(),
+            total_supply: total_supply.into(),
+            distribution,
+            holders,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn epoch_db(epoch: i32, first_block_height: i32, time: i64) -> EpochDb {
+        EpochDb {
+            epoch,
+            first_block_height,
+            timestamp: chrono::DateTime::from_timestamp(time, 0)
+                .map(|timestamp| timestamp.naive_utc()),
+        }
+    }
+
+    #[test]
+    fn circulating_supply_excludes_pgf_and_vesting() {
+        let breakdown = CirculatingSupplyBreakdown::new(
+            3,
+            BigDecimal::from(1_000),
+            vec![
+                (LockedSupplyKind::Bonded, BigDecimal::from(400), vec![]),
+                (LockedSupplyKind::Pgf, BigDecimal::from(100), vec![]),
+                (
+                    LockedSupplyKind::Vesting,
+                    BigDecimal::from(150),
+                    vec![("tnam1vesting".to_string(), BigDecimal::from(150))],
+                ),
+            ],
+        );
+
+        assert_eq!(breakdown.total_supply, BigDecimal::from(1_000).into());
+        assert_eq!(breakdown.circulating_supply, BigDecimal::from(750).into());
+
+        let circulating = breakdown
+            .locked
+            .iter()
+            .map(|locked| (locked.circulating, locked.share))
+            .collect::<Vec<_>>();
+        assert_eq!(circulating, vec![(true, 0.4), (false, 0.1), (false, 0.15)]);
+        assert_eq!(
+            breakdown.locked[2].accounts,
+            vec![(
+                Id::Account("tnam1vesting".to_string()),
+                BigDecimal::from(150).into()
+            )]
+        );
+    }
+
+    #[test]
+    fn circulating_supply_of_empty_supply() {
+        let breakdown = CirculatingSupplyBreakdown::new(
+            3,
+            BigDecimal::zero(),
+            vec![(LockedSupplyKind::Vesting, BigDecimal::from(10), vec![])],
+        );
+
+        assert_eq!(breakdown.circulating_supply, Amount::zero());
+        assert_eq!(breakdown.locked[0].share, 0.0);
+    }
+
+    #[test]
+    fn epoch_info_uses_recorded_start() {
+        let epoch_info = EpochInfo::from_db(
+            3,
+            Some(epoch_db(3, 300, 1_000)),
+            &[epoch_db(5, 500, 2_000)],
+            10,
+            10,
+        )
+        .expect("Epoch should be known");
+
+        assert_eq!(epoch_info.first_block_height, Some(300));
+        assert_eq!(epoch_info.start_time, 1_000);
+        assert!(!epoch_info.estimated);
+    }
+
+    #[test]
+    fn epoch_info_projects_from_observed_epochs() {
+        let latest_epochs = [epoch_db(5, 500, 2_000), epoch_db(3, 300, 1_000)];
+
+        let epoch_info = EpochInfo::from_db(7, None, &latest_epochs, 10, 10)
+            .expect("Epoch should be projected");
+
+        assert_eq!(epoch_info.first_block_height, Some(700));
+        assert_eq!(epoch_info.start_time, 3_000);
+        assert!(epoch_info.estimated);
+
+        let epoch_info = EpochInfo::from_db(1, None, &latest_epochs, 10, 10)
+            .expect("Epoch should be projected");
+
+        assert_eq!(epoch_info.first_block_height, Some(100));
+        assert_eq!(epoch_info.start_time, 0);
+    }
+
+    #[test]
+    fn epoch_info_projection_is_bounded_by_parameters() {
+        let epoch_info =
+            EpochInfo::from_db(6, None, &[epoch_db(5, 500, 2_000)], 600, 100)
+                .expect("Epoch should be projected");
+
+        assert_eq!(
+            epoch_info.first_block_height,
+            Some(600 + EPOCH_SWITCH_BLOCKS_DELAY as u64)
+        );
+        assert_eq!(epoch_info.start_time, 2_600);
+    }
+
+    #[test]
+    fn epoch_info_without_indexed_epochs() {
+        assert!(EpochInfo::from_db(1, None, &[], 10, 10).is_none());
+    }
+}
@@ -1,8 +1,10 @@
-use orm::gas::GasPriceDb;
+use orm::gas::{GasEstimationDb, GasPriceDb};
 use orm::token::{IbcTokenDb, TokenDb};
 use shared::id::Id;
 use shared::token::{IbcToken, Token};
 
+use crate::entity::transaction::TransactionKind;
+
 #[derive(Clone, Debug)]
 pub struct GasPrice {
     pub token: Token,
@@ -45,3 +47,532 @@ pub struct GasEstimate {
     pub avg: u64,
     pub total_estimates: u64,
 }
+
+/// Inner transaction kinds used as regressors by the gas model, in the order
+/// of [`GasBatch::counts`].
+pub const GAS_MODEL_TX_KINDS: [TransactionKind; 14] = [
+    TransactionKind::TransparentTransfer,
+    TransactionKind::ShieldedTransfer,
+    TransactionKind::ShieldingTransfer,
+    TransactionKind::UnshieldingTransfer,
+    TransactionKind::IbcTransparentTransfer,
+    TransactionKind::IbcShieldingTransfer,
+    TransactionKind::IbcUnshieldingTransfer,
+    TransactionKind::Bond,
+    TransactionKind::Redelegation,
+    TransactionKind::Unbond,
+    TransactionKind::Withdraw,
+    TransactionKind::ClaimRewards,
+    TransactionKind::VoteProposal,
+    TransactionKind::RevealPk,
+];
+
+// Inner tx kind counts, signatures and tx size
+const GAS_MODEL_FEATURES: usize = GAS_MODEL_TX_KINDS.len() + 2;
+// Features plus the intercept
+const GAS_MODEL_PARAMS: usize = GAS_MODEL_FEATURES + 1;
+// Two-sided 95% quantile of the normal distribution
+const GAS_MODEL_CONFIDENCE_Z: f64 = 1.96;
+const GAS_MODEL_CONFIDENCE: f64 = 0.95;
+// Minimum number of residual degrees of freedom required to fit the model
+const GAS_MODEL_MIN_DEGREES_OF_FREEDOM: usize = 10;
+const GAS_MODEL_RIDGE: f64 = 1e-8;
+
+/// Composition of a wrapper transaction batch
+#[derive(Clone, Debug, Default)]
+pub struct GasBatch {
+    pub counts: [u64; GAS_MODEL_TX_KINDS.len()],
+    pub signatures: Option<u64>,
+    pub tx_size: Option<u64>,
+}
+
+impl GasBatch {
+    pub fn add(&mut self, kind: &TransactionKind, count: u64) {
+        if let Some(index) = GAS_MODEL_TX_KINDS.iter().position(|k| k == kind) {
+            self.counts[index] = self.counts[index].saturating_add(count);
+        }
+    }
+
+    pub fn from_db(gas_estimation_db: &GasEstimationDb) -> Self {
+        let count = |value: i32| value.max(0) as u64;
+
+        Self {
+            counts: [
+                count(gas_estimation_db.transparent_transfer),
+                count(gas_estimation_db.shielded_transfer),
+                count(gas_estimation_db.shielding_transfer),
+                count(gas_estimation_db.unshielding_transfer),
+                count(gas_estimation_db.ibc_msg_transfer),
+                count(gas_estimation_db.ibc_shielding_transfer),
+                count(gas_estimation_db.ibc_unshielding_transfer),
+                count(gas_estimation_db.bond),
+                count(gas_estimation_db.redelegation),
+                count(gas_estimation_db.unbond),
+                count(gas_estimation_db.withdraw),
+                count(gas_estimation_db.claim_rewards),
+                count(gas_estimation_db.vote_proposal),
+                count(gas_estimation_db.reveal_pk),
+            ],
+            signatures: Some(count(gas_estimation_db.signatures)),
+            tx_size: Some(count(gas_estimation_db.tx_size)),
+        }
+    }
+
+    /// Design row of the batch, signatures and tx size default to the
+    /// provided means when unknown
+    fn features(
+        &self,
+        means: &[f64; GAS_MODEL_FEATURES],
+    ) -> [f64; GAS_MODEL_PARAMS] {
+        let kinds = GAS_MODEL_TX_KINDS.len();
+        let mut row = [0_f64; GAS_MODEL_PARAMS];
+
+        row[0] = 1.0;
+        for (i, count) in self.counts.iter().enumerate() {
+            row[i + 1] = *count as f64;
+        }
+        row[kinds + 1] =
+            self.signatures.map(|s| s as f64).unwrap_or(means[kinds]);
+        row[kinds + 2] =
+            self.tx_size.map(|s| s as f64).unwrap_or(means[kinds + 1]);
+
+        row
+    }
+}
+
+/// Linear model of the gas used by a batch, with one coefficient per inner
+/// transaction kind plus signatures and tx size, fitted by least squares over
+/// the indexed wrapper transactions
+#[derive(Clone, Debug)]
+pub struct GasModel {
+    pub epoch: i32,
+    pub samples: u64,
+    coefficients: [f64; GAS_MODEL_PARAMS],
+    // (XᵀX)⁻¹, used to compute the standard error of a prediction
+    inverse: [[f64; GAS_MODEL_PARAMS]; GAS_MODEL_PARAMS],
+    residual_variance: f64,
+    // Whether each feature was ever non-zero in the training samples
+    observed: [bool; GAS_MODEL_FEATURES],
+    means: [f64; GAS_MODEL_FEATURES],
+}
+
+impl GasModel {
+    /// Fits the model over `(batch, gas used)` samples. Returns `None` if
+    /// there are not enough samples to fit it.
+    pub fn fit(epoch: i32, samples: Vec<(GasBatch, f64)>) -> Option<Self> {
+        let n = samples.len();
+        if n < GAS_MODEL_PARAMS + GAS_MODEL_MIN_DEGREES_OF_FREEDOM {
+            return None;
+        }
+
+        let mut means = [0_f64; GAS_MODEL_FEATURES];
+        for (batch, _) in &samples {
+            let row = batch.features(&means);
+            for (mean, value) in means.iter_mut().zip(&row[1..]) {
+                *mean += value / n as f64;
+            }
+        }
+
+        let rows = samples
+            .iter()
+            .map(|(batch, gas)| (batch.features(&means), *gas))
+            .collect::<Vec<_>>();
+
+        let mut xtx = [[0_f64; GAS_MODEL_PARAMS]; GAS_MODEL_PARAMS];
+        let mut xty = [0_f64; GAS_MODEL_PARAMS];
+        for (row, gas) in &rows {
+            for i in 0..GAS_MODEL_PARAMS {
+                xty[i] += row[i] * gas;
+                for j in 0..GAS_MODEL_PARAMS {
+                    xtx[i][j] += row[i] * row[j];
+                }
+            }
+        }
+
+        let mut observed = [false; GAS_MODEL_FEATURES];
+        for (i, observed) in observed.iter_mut().enumerate() {
+            *observed = xtx[i + 1][i + 1] > 0.0;
+        }
+
+        let inverse = invert_gram_matrix(xtx)?;
+
+        let mut coefficients = [0_f64; GAS_MODEL_PARAMS];
+        for (i, coefficient) in coefficients.iter_mut().enumerate() {
+            *coefficient =
+                (0..GAS_MODEL_PARAMS).map(|j| inverse[i][j] * xty[j]).sum();
+        }
+
+        let params = 1 + observed.iter().filter(|o| **o).count();
+        let sse = rows
+            .iter()
+            .map(|(row, gas)| {
+                let predicted = row
+                    .iter()
+                    .zip(&coefficients)
+                    .map(|(x, b)| x * b)
+                    .sum::<f64>();
+                (gas - predicted).powi(2)
+            })
+            .sum::<f64>();
+
+        Some(Self {
+            epoch,
+            samples: n as u64,
+            coefficients,
+            inverse,
+            residual_variance: sse / (n - params) as f64,
+            observed,
+            means,
+        })
+    }
+
+    /// Predicts the gas used by the batch. Inner transaction kinds never seen
+    /// in the training samples are priced with `fallback`.
+    pub fn predict(
+        &self,
+        batch: &GasBatch,
+        fallback: impl Fn(&TransactionKind) -> u64,
+    ) -> GasEstimateInterval {
+        let kinds = GAS_MODEL_TX_KINDS.len();
+        let mut row = batch.features(&self.means);
+
+        // Fixed cost of the batch, plus the cost of its signatures and size
+        let base = self.coefficients[0]
+            + row[kinds + 1] * self.coefficients[kinds + 1]
+            + row[kinds + 2] * self.coefficients[kinds + 2];
+
+        // Inner txs are priced at the gas per tx reported in the components,
+        // so that the components and the base add up to the estimate
+        let mut inner_txs_gas = 0_u64;
+        let mut components = Vec::new();
+
+        for (i, kind) in GAS_MODEL_TX_KINDS.iter().enumerate() {
+            let count = batch.counts[i];
+            let (gas_per_tx, modelled) = if self.observed[i] {
+                (self.coefficients[i + 1].max(0.0).round() as u64, true)
+            } else {
+                // Unmodelled kinds do not contribute to the uncertainty
+                row[i + 1] = 0.0;
+                (fallback(kind), false)
+            };
+
+            inner_txs_gas =
+                inner_txs_gas.saturating_add(gas_per_tx.saturating_mul(count));
+
+            if count > 0 {
+                components.push(GasComponentEstimate {
+                    kind: kind.clone(),
+                    count,
+                    gas_per_tx,
+                    modelled,
+                });
+            }
+        }
+
+        let leverage = (0..GAS_MODEL_PARAMS)
+            .flat_map(|i| (0..GAS_MODEL_PARAMS).map(move |j| (i, j)))
+            .map(|(i, j)| row[i] * self.inverse[i][j] * row[j])
+            .sum::<f64>()
+            .max(0.0);
+        let margin = GAS_MODEL_CONFIDENCE_Z
+            * (self.residual_variance * (1.0 + leverage)).sqrt();
+
+        let estimate = (base + inner_txs_gas as f64).max(0.0);
+
+        GasEstimateInterval {
+            estimate: estimate.round() as u64,
+            lower: (estimate - margin).max(0.0).round() as u64,
+            upper: (estimate + margin).round() as u64,
+            confidence: GAS_MODEL_CONFIDENCE,
+            samples: self.samples,
+            epoch: Some(self.epoch),
+            components,
+        }
+    }
+}
+
+/// Inverts the gram matrix XᵀX by Gauss-Jordan elimination. Columns are
+/// scaled to unit diagonal first, so that tx sizes and counts are comparable,
+/// and a small ridge keeps collinear features solvable. Features that are
+/// always zero get a zero coefficient.
+fn invert_gram_matrix(
+    xtx: [[f64; GAS_MODEL_PARAMS]; GAS_MODEL_PARAMS],
+) -> Option<[[f64; GAS_MODEL_PARAMS]; GAS_MODEL_PARAMS]> {
+    let scale: [f64; GAS_MODEL_PARAMS] = std::array::from_fn(|i| {
+        if xtx[i][i] > 0.0 {
+            xtx[i][i].sqrt()
+        } else {
+            1.0
+        }
+    });
+
+    let mut matrix = [[0_f64; GAS_MODEL_PARAMS]; GAS_MODEL_PARAMS];
+    let mut inverse = [[0_f64; GAS_MODEL_PARAMS]; GAS_MODEL_PARAMS];
+    for i in 0..GAS_MODEL_PARAMS {
+        for j in 0..GAS_MODEL_PARAMS {
+            matrix[i][j] = xtx[i][j] / (scale[i] * scale[j]);
+        }
+        if xtx[i][i] > 0.0 {
+            matrix[i][i] += GAS_MODEL_RIDGE;
+        } else {
+            matrix[i][i] = 1.0;
+        }
+        inverse[i][i] = 1.0;
+    }
+
+    for col in 0..GAS_MODEL_PARAMS {
+        let pivot = (col..GAS_MODEL_PARAMS).max_by(|a, b| {
+            matrix[*a][col].abs().total_cmp(&matrix[*b][col].abs())
+        })?;
+        if matrix[pivot][col].abs() < f64::EPSILON {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        inverse.swap(col, pivot);
+
+        let value = matrix[col][col];
+        for j in 0..GAS_MODEL_PARAMS {
+            matrix[col][j] /= value;
+            inverse[col][j] /= value;
+        }
+
+        for row in 0..GAS_MODEL_PARAMS {
+            if row == col {
+                continue;
+            }
+            let factor = matrix[row][col];
+            if factor == 0.0 {
+                continue;
+            }
+            for j in 0..GAS_MODEL_PARAMS {
+                matrix[row][j] -= factor * matrix[col][j];
+                inverse[row][j] -= factor * inverse[col][j];
+            }
+        }
+    }
+
+    for (i, row) in inverse.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value /= scale[i] * scale[j];
+        }
+    }
+
+    Some(inverse)
+}
+
+#[derive(Clone, Debug)]
+pub struct GasComponentEstimate {
+    pub kind: TransactionKind,
+    pub count: u64,
+    pub gas_per_tx: u64,
+    /// Whether the gas comes from the fitted model or the default table
+    pub modelled: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct GasEstimateInterval {
+    pub estimate: u64,
+    pub lower: u64,
+    pub upper: u64,
+    pub confidence: f64,
+    pub samples: u64,
+    pub epoch: Option<i32>,
+    pub components: Vec<GasComponentEstimate>,
+}
+
+impl GasEstimateInterval {
+    /// Estimate from the default gas table, used when there is no fitted
+    /// model
+    pub fn from_defaults(
+        batch: &GasBatch,
+        fallback: impl Fn(&TransactionKind) -> u64,
+    ) -> Self {
+        let components = GAS_MODEL_TX_KINDS
+            .iter()
+            .zip(batch.counts)
+            .filter(|(_, count)| *count > 0)
+            .map(|(kind, count)| GasComponentEstimate {
+                kind: kind.clone(),
+                count,
+                gas_per_tx: fallback(kind),
+                modelled: false,
+            })
+            .collect::<Vec<_>>();
+        let estimate = components.iter().fold(0_u64, |acc, component| {
+            acc.saturating_add(
+                component.gas_per_tx.saturating_mul(component.count),
+            )
+        });
+
+        Self {
+            estimate,
+            lower: estimate,
+            upper: estimate,
+            confidence: GAS_MODEL_CONFIDENCE,
+            samples: 0,
+            epoch: None,
+            components,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOND_INDEX: usize = 7;
+    const SHIELDED_TRANSFER_INDEX: usize = 1;
+    const REVEAL_PK_INDEX: usize = 13;
+
+    // Deterministic noise in [-1000, 1000)
+    fn noise(seed: &mut u64) -> f64 {
+        *seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((*seed >> 33) % 2000) as f64 - 1000.0
+    }
+
+    fn samples(n: u64) -> Vec<(GasBatch, f64)> {
+        let mut seed = 7;
+
+        (0..n)
+            .map(|i| {
+                let mut batch = GasBatch {
+                    signatures: Some(1 + (i / 7) % 3),
+                    tx_size: Some(500 + (i * 37) % 3000),
+                    ..Default::default()
+                };
+                batch.counts[BOND_INDEX] = i % 3;
+                batch.counts[SHIELDED_TRANSFER_INDEX] = (i / 3) % 2;
+                batch.counts[REVEAL_PK_INDEX] = (i / 5) % 2;
+
+                let gas = 10_000.0
+                    + 50_000.0 * batch.counts[BOND_INDEX] as f64
+                    + 30_000.0 * batch.counts[SHIELDED_TRANSFER_INDEX] as f64
+                    + 20_000.0 * batch.counts[REVEAL_PK_INDEX] as f64
+                    + 1_000.0 * batch.signatures.unwrap() as f64
+                    + 5.0 * batch.tx_size.unwrap() as f64
+                    + noise(&mut seed);
+
+                (batch, gas)
+            })
+            .collect()
+    }
+
+    fn gas_per_tx(
+        estimate: &GasEstimateInterval,
+        kind: TransactionKind,
+    ) -> u64 {
+        estimate
+            .components
+            .iter()
+            .find(|component| component.kind == kind)
+            .map(|component| component.gas_per_tx)
+            .expect("Component should exist")
+    }
+
+    #[test]
+    fn gas_model_recovers_coefficients() {
+        let model = GasModel::fit(1, samples(2000)).expect("Model should fit");
+
+        let mut batch = GasBatch {
+            signatures: Some(2),
+            tx_size: Some(1000),
+            ..Default::default()
+        };
+        batch.counts[BOND_INDEX] = 2;
+        batch.counts[SHIELDED_TRANSFER_INDEX] = 1;
+        batch.counts[REVEAL_PK_INDEX] = 1;
+
+        let estimate = model.predict(&batch, |_| 0);
+
+        assert_eq!(estimate.samples, 2000);
+        assert_eq!(estimate.epoch, Some(1));
+        assert!(
+            gas_per_tx(&estimate, TransactionKind::Bond).abs_diff(50_000) < 100
+        );
+        assert!(
+            gas_per_tx(&estimate, TransactionKind::ShieldedTransfer)
+                .abs_diff(30_000)
+                < 100
+        );
+        assert!(
+            gas_per_tx(&estimate, TransactionKind::RevealPk).abs_diff(20_000)
+                < 100
+        );
+
+        let expected =
+            10_000 + 2 * 50_000 + 30_000 + 20_000 + 2 * 1_000 + 5 * 1000;
+        assert!(estimate.estimate.abs_diff(expected) < 200);
+    }
+
+    #[test]
+    fn gas_model_needs_enough_samples() {
+        let samples = samples(
+            (GAS_MODEL_PARAMS + GAS_MODEL_MIN_DEGREES_OF_FREEDOM) as u64,
+        );
+
+        assert!(GasModel::fit(1, samples[1..].to_vec()).is_none());
+        assert!(GasModel::fit(1, samples).is_some());
+        assert!(GasModel::fit(1, vec![]).is_none());
+    }
+
+    #[test]
+    fn gas_model_prices_unobserved_kinds_with_fallback() {
+        let model = GasModel::fit(1, samples(2000)).expect("Model should fit");
+
+        let mut batch = GasBatch::default();
+        batch.add(&TransactionKind::Bond, 1);
+        batch.add(&TransactionKind::Withdraw, 3);
+        let with_withdraws = model.predict(&batch, |_| 40_000);
+
+        let mut batch = GasBatch::default();
+        batch.add(&TransactionKind::Bond, 1);
+        let without_withdraws = model.predict(&batch, |_| 40_000);
+
+        let withdraw = with_withdraws
+            .components
+            .iter()
+            .find(|component| component.kind == TransactionKind::Withdraw)
+            .expect("Withdraw component should exist");
+        assert!(!withdraw.modelled);
+        assert_eq!(withdraw.gas_per_tx, 40_000);
+        assert_eq!(
+            with_withdraws.estimate,
+            without_withdraws.estimate + 3 * 40_000
+        );
+        // Unobserved kinds do not widen the interval
+        assert_eq!(
+            with_withdraws.upper - with_withdraws.estimate,
+            without_withdraws.upper - without_withdraws.estimate
+        );
+    }
+
+    #[test]
+    fn gas_model_interval_contains_estimate() {
+        let model = GasModel::fit(1, samples(2000)).expect("Model should fit");
+
+        let mut batch = GasBatch::default();
+        batch.add(&TransactionKind::ShieldedTransfer, 2);
+        let estimate = model.predict(&batch, |_| 0);
+
+        assert!(estimate.lower < estimate.estimate);
+        assert!(estimate.estimate < estimate.upper);
+        assert_eq!(estimate.confidence, GAS_MODEL_CONFIDENCE);
+    }
+
+    #[test]
+    fn default_estimate_adds_up_components() {
+        let mut batch = GasBatch::default();
+        batch.add(&TransactionKind::Bond, 2);
+        batch.add(&TransactionKind::RevealPk, 1);
+        batch.add(&TransactionKind::Bond, u64::MAX);
+
+        let estimate = GasEstimateInterval::from_defaults(&batch, |_| 10);
+
+        assert_eq!(estimate.components.len(), 2);
+        assert_eq!(estimate.estimate, u64::MAX);
+        assert_eq!(estimate.lower, estimate.upper);
+        assert_eq!(estimate.samples, 0);
+    }
+}
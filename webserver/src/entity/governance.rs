@@ -1,3 +1,9 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use bigdecimal::{BigDecimal, ParseBigDecimalError, RoundingMode};
+use namada_sdk::token::NATIVE_MAX_DECIMAL_PLACES;
+use orm::blocks::BlockDb;
 use orm::crawler_state::ChainCrawlerStateDb;
 use orm::governance_proposal::{
     GovernanceProposalKindDb, GovernanceProposalNoDataDb,
@@ -6,6 +12,8 @@ use orm::governance_proposal::{
 use orm::governance_votes::{GovernanceProposalVoteDb, GovernanceVoteKindDb};
 use shared::id::Id;
 
+use crate::entity::pgf::PgfPayment;
+use crate::entity::pos::Validator;
 use crate::response::utils::{epoch_progress, time_between_epochs};
 
 #[derive(Clone, Debug)]
@@ -48,6 +56,30 @@ impl From<GovernanceProposalTallyTypeDb> for TallyType {
     }
 }
 
+impl TallyType {
+    /// Fraction of the total voting power that has to vote for the tally to
+    /// be valid
+    pub fn quorum(&self) -> (u64, u64) {
+        match self {
+            Self::TwoFifths => (2, 5),
+            Self::OneHalfOverOneThird | Self::LessOneHalfOverOneThirdNay => {
+                (1, 3)
+            }
+        }
+    }
+
+    /// Fraction of the yay and nay voting power that has to vote yay for the
+    /// proposal to pass
+    pub fn yay_threshold(&self) -> (u64, u64) {
+        match self {
+            Self::TwoFifths => (2, 3),
+            Self::OneHalfOverOneThird | Self::LessOneHalfOverOneThirdNay => {
+                (1, 2)
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum ProposalStatus {
     Pending,
@@ -79,6 +111,10 @@ impl From<GovernanceProposalResultDb> for ProposalStatus {
 pub struct Proposal {
     pub id: u64,
     pub content: String,
+    pub title: Option<String>,
+    pub authors: Option<String>,
+    pub discussions_to: Option<String>,
+    pub r#abstract: Option<String>,
     pub r#type: ProposalType,
     pub tally_type: TallyType,
     pub author: Id,
@@ -168,6 +204,10 @@ impl Proposal {
         Self {
             id: value.id as u64,
             content: value.content,
+            title: value.title,
+            authors: value.authors,
+            discussions_to: value.discussions_to,
+            r#abstract: value.abstract_,
             r#type: ProposalType::from(value.kind),
             tally_type: TallyType::from(value.tally_type),
             author: Id::Account(value.author),
@@ -211,3 +251,441 @@ pub struct ProposalData {
     pub hash: Option<String>,
     pub r#type: ProposalType,
 }
+
+#[derive(Clone, Debug)]
+pub struct ProposalSubmission {
+    pub tx_id: Option<Id>,
+    pub block_height: Option<u64>,
+    pub time: Option<String>,
+}
+
+impl ProposalSubmission {
+    pub fn from_db(
+        init_tx_id: Option<String>,
+        init_height: Option<i32>,
+        block: Option<BlockDb>,
+    ) -> Self {
+        Self {
+            tx_id: init_tx_id.map(Id::Hash),
+            block_height: init_height.map(|height| height as u64),
+            time: block
+                .and_then(|block| block.timestamp)
+                .map(|timestamp| timestamp.and_utc().timestamp().to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EpochTime {
+    pub epoch: u64,
+    pub block_height: Option<u64>,
+    pub time: String,
+    pub estimated: bool,
+}
+
+impl EpochTime {
+    /// Resolves the time an epoch started at from its first indexed block,
+    /// falling back to an estimation based on the chain parameters when the
+    /// block is not (yet) indexed.
+    pub fn from_db(
+        epoch: i32,
+        first_block: Option<&BlockDb>,
+        chain_state: &ChainCrawlerStateDb,
+        max_block_time: i32,
+        min_duration: i32,
+    ) -> Self {
+        let block_time = first_block.and_then(|block| {
+            block.timestamp.map(|timestamp| {
+                (block.height, timestamp.and_utc().timestamp())
+            })
+        });
+
+        match block_time {
+            Some((height, timestamp)) => Self {
+                epoch: epoch as u64,
+                block_height: Some(height as u64),
+                time: timestamp.to_string(),
+                estimated: false,
+            },
+            None => {
+                let blocks_per_epoch = min_duration / max_block_time;
+
+                let epoch_progress = epoch_progress(
+                    chain_state.last_processed_block,
+                    chain_state.first_block_in_epoch,
+                    blocks_per_epoch,
+                );
+
+                let to_epoch = time_between_epochs(
+                    blocks_per_epoch,
+                    epoch_progress,
+                    chain_state.last_processed_epoch,
+                    epoch,
+                    min_duration,
+                );
+
+                let time_now = chain_state.timestamp.and_utc().timestamp();
+
+                Self {
+                    epoch: epoch as u64,
+                    block_height: None,
+                    time: (time_now + i64::from(to_epoch)).to_string(),
+                    estimated: true,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ProposalVoteCount {
+    pub epoch: Option<u64>,
+    pub yay: u64,
+    pub nay: u64,
+    pub abstain: u64,
+    pub total: u64,
+}
+
+impl ProposalVoteCount {
+    /// Builds the per epoch vote counts, `total` being the running number of
+    /// votes cast up to and including the epoch.
+    pub fn from_db(
+        vote_counts: Vec<(Option<i32>, GovernanceVoteKindDb, i64)>,
+    ) -> Vec<Self> {
+        let mut total = 0;
+
+        vote_counts.into_iter().fold(
+            Vec::<Self>::new(),
+            |mut acc, (epoch, kind, count)| {
+                let epoch = epoch.map(|epoch| epoch as u64);
+                let count = count as u64;
+
+                let entry = match acc.last_mut() {
+                    Some(last) if last.epoch == epoch => last,
+                    _ => {
+                        acc.push(Self {
+                            epoch,
+                            yay: 0,
+                            nay: 0,
+                            abstain: 0,
+                            total,
+                        });
+                        acc.last_mut().expect("Entry was just pushed")
+                    }
+                };
+
+                match kind {
+                    GovernanceVoteKindDb::Yay => entry.yay += count,
+                    GovernanceVoteKindDb::Nay => entry.nay += count,
+                    GovernanceVoteKindDb::Abstain => entry.abstain += count,
+                    GovernanceVoteKindDb::Unknown => (),
+                }
+                entry.total += count;
+                total += count;
+
+                acc
+            },
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ProposalTally {
+    pub tally_type: TallyType,
+    pub yay_votes: BigDecimal,
+    pub nay_votes: BigDecimal,
+    pub abstain_votes: BigDecimal,
+    pub total_voting_power: Option<BigDecimal>,
+    pub quorum_reached: Option<bool>,
+    pub passing: Option<bool>,
+}
+
+impl ProposalTally {
+    /// Applies the rules of the tally type to the voting power, mirroring the
+    /// way the protocol tallies proposals. The outcome is unknown as long as
+    /// the total voting power of the proposal has not been indexed.
+    pub fn new(
+        tally_type: TallyType,
+        yay_votes: BigDecimal,
+        nay_votes: BigDecimal,
+        abstain_votes: BigDecimal,
+        total_voting_power: Option<BigDecimal>,
+    ) -> Self {
+        let (quorum_num, quorum_den) = tally_type.quorum();
+        let (yay_num, yay_den) = tally_type.yay_threshold();
+
+        let quorum_reached = total_voting_power.as_ref().map(|total| {
+            (&yay_votes + &nay_votes + &abstain_votes) * quorum_den
+                >= total * quorum_num
+        });
+
+        let yay_reached = match tally_type {
+            TallyType::TwoFifths => {
+                &yay_votes * yay_den >= (&yay_votes + &nay_votes) * yay_num
+            }
+            TallyType::OneHalfOverOneThird
+            | TallyType::LessOneHalfOverOneThirdNay => {
+                &yay_votes * yay_den > (&yay_votes + &nay_votes) * yay_num
+            }
+        };
+
+        let passing = quorum_reached.map(|quorum_reached| match tally_type {
+            TallyType::LessOneHalfOverOneThirdNay => {
+                !quorum_reached || yay_reached
+            }
+            _ => quorum_reached && yay_reached,
+        });
+
+        Self {
+            tally_type,
+            yay_votes,
+            nay_votes,
+            abstain_votes,
+            total_voting_power,
+            quorum_reached,
+            passing,
+        }
+    }
+
+    pub fn from_db(
+        tally_type: GovernanceProposalTallyTypeDb,
+        yay_votes: &str,
+        nay_votes: &str,
+        abstain_votes: &str,
+        total_voting_power: Option<&str>,
+    ) -> Result<Self, ParseBigDecimalError> {
+        Ok(Self::new(
+            TallyType::from(tally_type),
+            BigDecimal::from_str(yay_votes)?,
+            BigDecimal::from_str(nay_votes)?,
+            BigDecimal::from_str(abstain_votes)?,
+            total_voting_power.map(BigDecimal::from_str).transpose()?,
+        ))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ProposalExecution {
+    pub activation: EpochTime,
+    pub executed: bool,
+    pub passed: Option<bool>,
+    pub wasm_hash: Option<String>,
+    pub pgf_payments: Vec<PgfPayment>,
+}
+
+impl ProposalExecution {
+    pub fn new(
+        status: &ProposalStatus,
+        activation: EpochTime,
+        wasm_hash: Option<String>,
+        pgf_payments: Vec<PgfPayment>,
+    ) -> Self {
+        let (executed, passed) = match status {
+            ProposalStatus::ExecutedPassed => (true, Some(true)),
+            ProposalStatus::ExecutedRejected => (true, Some(false)),
+            ProposalStatus::Passed => (false, Some(true)),
+            ProposalStatus::Rejected => (false, Some(false)),
+            ProposalStatus::Pending
+            | ProposalStatus::Voting
+            | ProposalStatus::Unknown => (false, None),
+        };
+
+        Self {
+            activation,
+            executed,
+            passed,
+            wasm_hash,
+            pgf_payments,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ProposalTimeline {
+    pub id: u64,
+    pub r#type: ProposalType,
+    pub status: ProposalStatus,
+    pub submission: ProposalSubmission,
+    pub voting_start: EpochTime,
+    pub voting_end: EpochTime,
+    pub votes: Vec<ProposalVoteCount>,
+    pub tally: ProposalTally,
+    pub execution: Option<ProposalExecution>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProposalTallyProjection {
+    pub proposal_id: u64,
+    pub epoch: u64,
+    pub tally: ProposalTally,
+    pub yay_margin: BigDecimal,
+    pub quorum_margin: BigDecimal,
+    pub indexed_total_voting_power: bool,
+}
+
+impl ProposalTallyProjection {
+    /// Projects the outcome of a proposal from the indexed votes, following
+    /// the protocol rules: a validator votes with its whole stake, except for
+    /// the stake of delegators that cast their own vote, which is moved to
+    /// the delegator's side. The total voting power indexed by the
+    /// governance crawler is preferred over the one estimated from the
+    /// stakes.
+    pub fn compute(
+        proposal_id: u64,
+        epoch: u64,
+        tally_type: TallyType,
+        votes: Vec<GovernanceProposalVoteDb>,
+        stakes: Vec<(String, String, BigDecimal)>,
+        total_voting_power: Option<BigDecimal>,
+    ) -> Self {
+        let votes = votes
+            .into_iter()
+            .map(|vote| (vote.voter_address, vote.kind))
+            .collect::<HashMap<_, _>>();
+
+        let mut yay = BigDecimal::from(0);
+        let mut nay = BigDecimal::from(0);
+        let mut abstain = BigDecimal::from(0);
+        let mut total_stake = BigDecimal::from(0);
+
+        for (delegator, validator, raw_amount) in stakes {
+            let amount = to_native(raw_amount);
+            total_stake += &amount;
+
+            // Self-bonds are covered by the validator vote
+            let delegator_vote = if delegator == validator {
+                None
+            } else {
+                votes.get(&delegator)
+            };
+
+            match delegator_vote.or_else(|| votes.get(&validator)) {
+                Some(GovernanceVoteKindDb::Yay) => yay += amount,
+                Some(GovernanceVoteKindDb::Nay) => nay += amount,
+                Some(GovernanceVoteKindDb::Abstain) => abstain += amount,
+                Some(GovernanceVoteKindDb::Unknown) | None => (),
+            }
+        }
+
+        let indexed_total_voting_power = total_voting_power.is_some();
+        let total_voting_power = total_voting_power.unwrap_or(total_stake);
+
+        let (yay_num, yay_den) = tally_type.yay_threshold();
+        let yay_margin = (&yay
+            - (&yay + &nay) * BigDecimal::from(yay_num)
+                / BigDecimal::from(yay_den))
+        .with_scale_round(NATIVE_MAX_DECIMAL_PLACES.into(), RoundingMode::Down);
+
+        let (quorum_num, quorum_den) = tally_type.quorum();
+        let quorum_margin = (&yay + &nay + &abstain
+            - &total_voting_power * BigDecimal::from(quorum_num)
+                / BigDecimal::from(quorum_den))
+        .with_scale_round(NATIVE_MAX_DECIMAL_PLACES.into(), RoundingMode::Down);
+
+        Self {
+            proposal_id,
+            epoch,
+            tally: ProposalTally::new(
+                tally_type,
+                yay,
+                nay,
+                abstain,
+                Some(total_voting_power),
+            ),
+            yay_margin,
+            quorum_margin,
+            indexed_total_voting_power,
+        }
+    }
+}
+
+fn to_native(raw_amount: BigDecimal) -> BigDecimal {
+    let (digits, scale) = raw_amount.into_bigint_and_exponent();
+    BigDecimal::new(digits, scale + i64::from(NATIVE_MAX_DECIMAL_PLACES))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParticipationStatus {
+    Voting,
+    NonVoting,
+    NoEligibleProposals,
+}
+
+#[derive(Clone, Debug)]
+pub struct VoterParticipation {
+    pub status: ParticipationStatus,
+    pub eligible_proposals: u64,
+    pub yay: u64,
+    pub nay: u64,
+    pub abstain: u64,
+    pub no_vote: u64,
+    pub participation_rate: f64,
+}
+
+impl VoterParticipation {
+    pub fn from_db(
+        eligible_proposals: i64,
+        vote_counts: Vec<(GovernanceVoteKindDb, i64)>,
+    ) -> Self {
+        let (yay, nay, abstain, unknown) = vote_counts.into_iter().fold(
+            (0, 0, 0, 0),
+            |(yay, nay, abstain, unknown), (kind, count)| {
+                let count = count as u64;
+                match kind {
+                    GovernanceVoteKindDb::Yay => {
+                        (yay + count, nay, abstain, unknown)
+                    }
+                    GovernanceVoteKindDb::Nay => {
+                        (yay, nay + count, abstain, unknown)
+                    }
+                    GovernanceVoteKindDb::Abstain => {
+                        (yay, nay, abstain + count, unknown)
+                    }
+                    GovernanceVoteKindDb::Unknown => {
+                        (yay, nay, abstain, unknown + count)
+                    }
+                }
+            },
+        );
+
+        let eligible_proposals = eligible_proposals as u64;
+        let voted = yay + nay + abstain + unknown;
+        let participation_rate = if eligible_proposals == 0 {
+            0.0
+        } else {
+            voted as f64 / eligible_proposals as f64
+        };
+
+        let status = if eligible_proposals == 0 {
+            ParticipationStatus::NoEligibleProposals
+        } else if voted == 0 {
+            ParticipationStatus::NonVoting
+        } else {
+            ParticipationStatus::Voting
+        };
+
+        Self {
+            status,
+            eligible_proposals,
+            yay,
+            nay,
+            abstain,
+            no_vote: eligible_proposals.saturating_sub(voted),
+            participation_rate,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ValidatorParticipation {
+    pub validator: Validator,
+    pub participation: VoterParticipation,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProposalSearchResult {
+    pub proposal: Proposal,
+    pub rank: f32,
+    pub title_highlight: String,
+    pub abstract_highlight: String,
+}
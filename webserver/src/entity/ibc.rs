@@ -1,3 +1,9 @@
+use bigdecimal::{BigDecimal, ToPrimitive, Zero};
+use chrono::NaiveDateTime;
+use orm::ibc::{
+    IbcAckDb, IbcAckStatusDb, IbcChannelDb, IbcChannelOrderingDb,
+    IbcChannelStateDb, IbcClientDb, IbcConnectionDb,
+};
 use shared::id::Id;
 
 #[derive(Clone, Debug)]
@@ -8,6 +14,17 @@ pub enum IbcAckStatus {
     Unknown,
 }
 
+impl From<IbcAckStatusDb> for IbcAckStatus {
+    fn from(value: IbcAckStatusDb) -> Self {
+        match value {
+            IbcAckStatusDb::Unknown => Self::Unknown,
+            IbcAckStatusDb::Timeout => Self::Timeout,
+            IbcAckStatusDb::Fail => Self::Fail,
+            IbcAckStatusDb::Success => Self::Success,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct IbcAck {
     pub status: IbcAckStatus,
@@ -32,3 +49,253 @@ pub struct IbcTokenThroughput {
     pub throughput: u64,
     pub limit: u64,
 }
+
+/// Throughput limit of a token and the amounts that crossed it in an epoch
+#[derive(Clone, Debug)]
+pub struct IbcRateLimitUsage {
+    pub epoch: u64,
+    pub throughput_limit: BigDecimal,
+    pub deposit: BigDecimal,
+    pub withdraw: BigDecimal,
+}
+
+impl IbcRateLimitUsage {
+    /// The protocol checks the net flow of the epoch against the limit, so
+    /// deposits make room for withdrawals and vice versa
+    pub fn throughput(&self) -> BigDecimal {
+        (&self.deposit - &self.withdraw).abs()
+    }
+
+    pub fn deposit_headroom(&self) -> BigDecimal {
+        (&self.throughput_limit + &self.withdraw - &self.deposit)
+            .max(BigDecimal::zero())
+    }
+
+    pub fn withdraw_headroom(&self) -> BigDecimal {
+        (&self.throughput_limit + &self.deposit - &self.withdraw)
+            .max(BigDecimal::zero())
+    }
+
+    /// Share of the limit used by the net flow, none if the limit is zero
+    pub fn utilisation(&self) -> Option<f64> {
+        if self.throughput_limit.is_zero() {
+            return None;
+        }
+
+        (self.throughput() / &self.throughput_limit).to_f64()
+    }
+}
+
+impl From<(i32, BigDecimal, Option<BigDecimal>, Option<BigDecimal>)>
+    for IbcRateLimitUsage
+{
+    fn from(
+        (epoch, throughput_limit, deposit, withdraw): (
+            i32,
+            BigDecimal,
+            Option<BigDecimal>,
+            Option<BigDecimal>,
+        ),
+    ) -> Self {
+        Self {
+            epoch: epoch as u64,
+            throughput_limit,
+            deposit: deposit.unwrap_or_default(),
+            withdraw: withdraw.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum IbcTransferStatus {
+    Pending,
+    Stuck,
+    Completed,
+    Refunded,
+    Unknown,
+}
+
+#[derive(Clone, Debug)]
+pub struct IbcTransferAck {
+    pub tx_id: Id,
+    pub height: Option<u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct IbcTransfer {
+    pub tx_id: Id,
+    pub height: Option<u64>,
+    pub source_port: String,
+    pub source_channel: String,
+    pub dest_port: String,
+    pub dest_channel: String,
+    pub sequence: u64,
+    pub denom: Option<String>,
+    pub amount: Option<BigDecimal>,
+    pub sender: Option<String>,
+    pub receiver: Option<String>,
+    pub memo: Option<String>,
+    pub timeout_timestamp: u64,
+    pub timeout_height: Option<String>,
+    pub status: IbcTransferStatus,
+    pub ack_status: IbcAckStatus,
+    pub ack: Option<IbcTransferAck>,
+    pub refund_tx_id: Option<Id>,
+}
+
+impl From<IbcAckDb> for IbcTransfer {
+    fn from(value: IbcAckDb) -> Self {
+        let ack = value.ack_tx_hash.map(|tx_hash| IbcTransferAck {
+            tx_id: Id::Hash(tx_hash),
+            height: value.ack_height.map(|height| height as u64),
+        });
+
+        let status = match (&value.status, &ack) {
+            (IbcAckStatusDb::Success, _) => IbcTransferStatus::Completed,
+            (IbcAckStatusDb::Fail | IbcAckStatusDb::Timeout, _) => {
+                IbcTransferStatus::Refunded
+            }
+            (IbcAckStatusDb::Unknown, None) if value.stuck => {
+                IbcTransferStatus::Stuck
+            }
+            (IbcAckStatusDb::Unknown, None) => IbcTransferStatus::Pending,
+            (IbcAckStatusDb::Unknown, Some(_)) => IbcTransferStatus::Unknown,
+        };
+
+        Self {
+            tx_id: Id::Hash(value.tx_hash),
+            height: value.send_height.map(|height| height as u64),
+            source_port: value.source_port,
+            source_channel: value.source_channel,
+            dest_port: value.dest_port,
+            dest_channel: value.dest_channel,
+            sequence: value.sequence as u64,
+            denom: value.denom,
+            amount: value.amount,
+            sender: value.sender,
+            receiver: value.receiver,
+            memo: value.memo,
+            timeout_timestamp: value.timeout as u64,
+            timeout_height: value.timeout_height,
+            status,
+            ack_status: IbcAckStatus::from(value.status),
+            ack,
+            refund_tx_id: value.refund_tx_hash.map(Id::Hash),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum IbcChannelState {
+    Uninitialized,
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+impl From<IbcChannelStateDb> for IbcChannelState {
+    fn from(value: IbcChannelStateDb) -> Self {
+        match value {
+            IbcChannelStateDb::Uninitialized => Self::Uninitialized,
+            IbcChannelStateDb::Init => Self::Init,
+            IbcChannelStateDb::TryOpen => Self::TryOpen,
+            IbcChannelStateDb::Open => Self::Open,
+            IbcChannelStateDb::Closed => Self::Closed,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum IbcChannelOrdering {
+    None,
+    Unordered,
+    Ordered,
+}
+
+impl From<IbcChannelOrderingDb> for IbcChannelOrdering {
+    fn from(value: IbcChannelOrderingDb) -> Self {
+        match value {
+            IbcChannelOrderingDb::None => Self::None,
+            IbcChannelOrderingDb::Unordered => Self::Unordered,
+            IbcChannelOrderingDb::Ordered => Self::Ordered,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IbcCounterparty {
+    pub chain_id: Option<String>,
+    pub client_id: Option<String>,
+    pub connection_id: Option<String>,
+    pub port_id: String,
+    pub channel_id: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct IbcChannel {
+    pub port_id: String,
+    pub channel_id: String,
+    pub connection_id: Option<String>,
+    pub client_id: Option<String>,
+    pub client_frozen: Option<bool>,
+    pub state: IbcChannelState,
+    pub ordering: IbcChannelOrdering,
+    pub version: String,
+    pub counterparty: IbcCounterparty,
+    pub volumes: Vec<IbcChannelTokenVolume>,
+}
+
+/// Amounts of a token that crossed a channel
+#[derive(Clone, Debug)]
+pub struct IbcChannelTokenVolume {
+    pub token: String,
+    pub deposit: BigDecimal,
+    pub withdraw: BigDecimal,
+}
+
+impl IbcChannel {
+    pub fn from_db(
+        channel: IbcChannelDb,
+        connection: Option<IbcConnectionDb>,
+        client: Option<IbcClientDb>,
+        volumes: Vec<IbcChannelTokenVolume>,
+    ) -> Self {
+        Self {
+            port_id: channel.port_id,
+            channel_id: channel.channel_id,
+            connection_id: channel.connection_id,
+            client_id: connection.as_ref().map(|c| c.client_id.clone()),
+            client_frozen: client.as_ref().map(|c| c.frozen),
+            state: IbcChannelState::from(channel.state),
+            ordering: IbcChannelOrdering::from(channel.ordering),
+            version: channel.version,
+            counterparty: IbcCounterparty {
+                chain_id: client.map(|c| c.chain_id),
+                client_id: connection
+                    .as_ref()
+                    .map(|c| c.counterparty_client_id.clone()),
+                connection_id: connection
+                    .and_then(|c| c.counterparty_connection_id),
+                port_id: channel.counterparty_port_id,
+                channel_id: channel.counterparty_channel_id,
+            },
+            volumes,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IbcPendingTransfer {
+    pub transfer: IbcTransfer,
+    pub sent_at: Option<i64>,
+}
+
+impl IbcPendingTransfer {
+    pub fn from_db(value: IbcAckDb, sent_at: Option<NaiveDateTime>) -> Self {
+        Self {
+            transfer: IbcTransfer::from(value),
+            sent_at: sent_at.map(|sent_at| sent_at.and_utc().timestamp()),
+        }
+    }
+}
@@ -1,5 +1,7 @@
+use bigdecimal::{BigDecimal, FromPrimitive, ToPrimitive, Zero};
 use orm::masp::{
     MaspPoolAggregateKindDb, MaspPoolAggregateWindowDb, MaspPoolDb,
+    MaspRewardHistoryDb,
 };
 use shared::balance::Amount;
 use shared::id::Id;
@@ -56,3 +58,175 @@ impl From<MaspPoolDb> for MaspPoolAggregate {
         }
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct MaspPoolSeriesPoint {
+    /// Unix timestamp, or epoch when the interval is epoch
+    pub start: i64,
+    pub inflow: BigDecimal,
+    pub outflow: BigDecimal,
+    pub net: BigDecimal,
+    /// Amount held by the masp at the end of the interval
+    pub total: BigDecimal,
+}
+
+#[derive(Clone, Debug)]
+pub struct MaspPoolSeries {
+    pub token_address: Id,
+    pub points: Vec<MaspPoolSeriesPoint>,
+}
+
+impl MaspPoolSeries {
+    pub fn from_db(
+        token_address: String,
+        opening: (Option<BigDecimal>, Option<BigDecimal>),
+        series: Vec<(i64, Option<BigDecimal>, Option<BigDecimal>)>,
+    ) -> Self {
+        let (opening_inflow, opening_outflow) = opening;
+        let mut total = opening_inflow.unwrap_or_default()
+            - opening_outflow.unwrap_or_default();
+
+        let points = series
+            .into_iter()
+            .map(|(start, inflow, outflow)| {
+                let inflow = inflow.unwrap_or_default();
+                let outflow = outflow.unwrap_or_default();
+                let net = &inflow - &outflow;
+                total += &net;
+
+                MaspPoolSeriesPoint {
+                    start,
+                    inflow,
+                    outflow,
+                    net,
+                    total: total.clone(),
+                }
+            })
+            .collect();
+
+        Self {
+            token_address: Id::Account(token_address),
+            points,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MaspRewardHistory {
+    pub token: Id,
+    pub epoch: i32,
+    pub max_reward_rate: String,
+    pub kp_gain: String,
+    pub kd_gain: String,
+    pub locked_amount_target: Amount,
+    pub last_locked_amount: Amount,
+    pub last_inflation: Amount,
+    /// Yearly rate of the rewards paid in the last masp epoch, relative to
+    /// the amount that was locked. Rewards are minted in the native token, so
+    /// the rate is only known for the native token itself.
+    pub realised_reward_rate: Option<f64>,
+}
+
+impl MaspRewardHistory {
+    pub fn from_db(value: MaspRewardHistoryDb, native_token: &str) -> Self {
+        let realised_reward_rate = Some(&value.last_locked_amount)
+            .filter(|_| value.token == native_token)
+            .filter(|locked| !locked.is_zero())
+            .and_then(|locked| (&value.last_inflation / locked).to_f64())
+            .map(|rate| rate * value.masp_epochs_per_year as f64);
+
+        Self {
+            token: Id::Account(value.token),
+            epoch: value.epoch,
+            max_reward_rate: value.max_reward_rate,
+            kp_gain: value.kp_gain,
+            kd_gain: value.kd_gain,
+            locked_amount_target: value.locked_amount_target.into(),
+            last_locked_amount: value.last_locked_amount.into(),
+            last_inflation: value.last_inflation.into(),
+            realised_reward_rate,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MaspRewardEstimate {
+    pub rates: MaspRewardHistory,
+    pub amount: BigDecimal,
+    pub epochs: u64,
+    pub projected_rewards: Option<Amount>,
+}
+
+impl MaspRewardEstimate {
+    /// Projects the rewards assuming the realised reward rate stays the same
+    /// for the given number of epochs. There is no projection when the rate
+    /// is unknown.
+    pub fn new(
+        rates: MaspRewardHistory,
+        amount: BigDecimal,
+        epochs: u64,
+        epochs_per_year: u64,
+    ) -> Self {
+        let projected_rewards = rates
+            .realised_reward_rate
+            .and_then(BigDecimal::from_f64)
+            .map(|rate| {
+                if epochs_per_year == 0 {
+                    BigDecimal::zero()
+                } else {
+                    (&amount * rate * BigDecimal::from(epochs)
+                        / BigDecimal::from(epochs_per_year))
+                    .with_scale(0)
+                }
+            })
+            .map(Amount::from);
+
+        Self {
+            rates,
+            amount,
+            epochs,
+            projected_rewards,
+        }
+    }
+}
+
+/// Aggregate shielded activity of an epoch. Only counts are kept, so that no
+/// data can be traced back to a user.
+#[derive(Clone, Debug)]
+pub struct MaspActivity {
+    pub epoch: i32,
+    pub shielded: u64,
+    pub shielding: u64,
+    pub unshielding: u64,
+    pub mixed: u64,
+    pub ibc_shielding: u64,
+    pub ibc_unshielding: u64,
+    /// Wrappers containing at least one masp tx
+    pub masp_wrappers: u64,
+    /// Wrappers containing masp txs whose fees were paid through the masp
+    pub masp_fee_payments: u64,
+    /// Size of the note commitment tree at the start of the epoch
+    pub note_commitments: Option<u64>,
+    /// Note commitments added during the epoch, unknown until the next epoch
+    /// starts
+    pub new_note_commitments: Option<u64>,
+    /// Number of masp txs included up to the end of the epoch, each being a
+    /// possible origin of a shielded note
+    pub anonymity_set_estimate: u64,
+}
+
+impl MaspActivity {
+    pub fn total(&self) -> u64 {
+        self.shielded
+            + self.shielding
+            + self.unshielding
+            + self.mixed
+            + self.ibc_shielding
+            + self.ibc_unshielding
+    }
+
+    pub fn masp_fee_payment_share(&self) -> Option<f64> {
+        (self.masp_wrappers > 0)
+            .then(|| self.masp_fee_payments as f64 / self.masp_wrappers as f64)
+    }
+}
@@ -1,3 +1,8 @@
+use bigdecimal::BigDecimal;
+use orm::pgf::{
+    PaymentKindDb, PaymentRecurrenceDb, PgfScheduledPaymentDb, PgfStewardDb,
+    PublicGoodFundingPaymentDb,
+};
 use shared::balance::Amount;
 use shared::id::Id;
 
@@ -7,6 +12,15 @@ pub enum PaymentRecurrence {
     Retro,
 }
 
+impl From<PaymentRecurrenceDb> for PaymentRecurrence {
+    fn from(value: PaymentRecurrenceDb) -> Self {
+        match value {
+            PaymentRecurrenceDb::Continuous => Self::Continuous,
+            PaymentRecurrenceDb::Retro => Self::Retro,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 
 pub enum PaymentKind {
@@ -14,6 +28,15 @@ pub enum PaymentKind {
     Native,
 }
 
+impl From<PaymentKindDb> for PaymentKind {
+    fn from(value: PaymentKindDb) -> Self {
+        match value {
+            PaymentKindDb::Ibc => Self::Ibc,
+            PaymentKindDb::Native => Self::Native,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 
 pub enum PgfAction {
@@ -29,3 +52,119 @@ pub struct PgfPayment {
     pub receipient: Id,
     pub amount: Amount,
 }
+
+impl From<PublicGoodFundingPaymentDb> for PgfPayment {
+    fn from(payment: PublicGoodFundingPaymentDb) -> Self {
+        Self {
+            recurrence: match payment.payment_recurrence {
+                PaymentRecurrenceDb::Continuous => {
+                    PaymentRecurrence::Continuous
+                }
+                PaymentRecurrenceDb::Retro => PaymentRecurrence::Retro,
+            },
+            proposal_id: payment.proposal_id as u64,
+            kind: match payment.payment_kind {
+                PaymentKindDb::Ibc => PaymentKind::Ibc,
+                PaymentKindDb::Native => PaymentKind::Native,
+            },
+            receipient: Id::Account(payment.receipient),
+            amount: payment.amount.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PgfSteward {
+    pub address: Id,
+    pub is_steward: bool,
+    pub epoch: u64,
+}
+
+impl From<PgfStewardDb> for PgfSteward {
+    fn from(steward: PgfStewardDb) -> Self {
+        Self {
+            address: Id::Account(steward.address),
+            is_steward: steward.is_steward,
+            epoch: steward.epoch as u64,
+        }
+    }
+}
+
+/// A continuous payment that is still being funded. Namada keeps paying
+/// continuous fundings every epoch until a later proposal removes them, so
+/// there is no end epoch to report.
+#[derive(Debug, Clone)]
+pub struct PgfContinuousPayment {
+    pub payment: PgfPayment,
+    pub activation_epoch: u64,
+    pub epochs_scheduled: u64,
+    pub total_scheduled: Amount,
+}
+
+impl PgfContinuousPayment {
+    pub fn from_db(
+        payment: PublicGoodFundingPaymentDb,
+        activation_epoch: i32,
+        epochs_scheduled: i64,
+        total_scheduled: Option<BigDecimal>,
+    ) -> Self {
+        Self {
+            payment: PgfPayment::from(payment),
+            activation_epoch: activation_epoch as u64,
+            epochs_scheduled: epochs_scheduled as u64,
+            total_scheduled: total_scheduled
+                .map(Amount::from)
+                .unwrap_or_else(Amount::zero),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PgfScheduledPayment {
+    pub payment: PgfPayment,
+    pub epoch: u64,
+}
+
+impl From<PgfScheduledPaymentDb> for PgfScheduledPayment {
+    fn from(scheduled_payment: PgfScheduledPaymentDb) -> Self {
+        Self {
+            payment: PgfPayment {
+                proposal_id: scheduled_payment.proposal_id as u64,
+                recurrence: scheduled_payment.payment_recurrence.into(),
+                kind: scheduled_payment.payment_kind.into(),
+                receipient: Id::Account(scheduled_payment.receipient),
+                amount: scheduled_payment.amount.into(),
+            },
+            epoch: scheduled_payment.epoch as u64,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PgfRecipientTotal {
+    pub receipient: Id,
+    pub total_scheduled: Amount,
+    pub scheduled_payments: u64,
+    pub first_epoch: Option<u64>,
+    pub last_epoch: Option<u64>,
+}
+
+impl PgfRecipientTotal {
+    pub fn from_db(
+        receipient: String,
+        total_scheduled: Option<BigDecimal>,
+        scheduled_payments: i64,
+        first_epoch: Option<i32>,
+        last_epoch: Option<i32>,
+    ) -> Self {
+        Self {
+            receipient: Id::Account(receipient),
+            total_scheduled: total_scheduled
+                .map(Amount::from)
+                .unwrap_or_else(Amount::zero),
+            scheduled_payments: scheduled_payments as u64,
+            first_epoch: first_epoch.map(|epoch| epoch as u64),
+            last_epoch: last_epoch.map(|epoch| epoch as u64),
+        }
+    }
+}
@@ -1,13 +1,17 @@
-use bigdecimal::BigDecimal;
+use bigdecimal::{BigDecimal, ToPrimitive, Zero};
+use chrono::NaiveDateTime;
 use orm::bond::BondDb;
 use orm::crawler_state::{ChainCrawlerStateDb, EpochCrawlerStateDb};
-use orm::pos_rewards::PoSRewardDb;
+use orm::pos_rewards::{PoSRewardDb, PosRewardClaimDb};
+use orm::pos_stats::PosStatsDb;
+use orm::redelegation::{RedelegationDb, RedelegationHistoryDb};
 use orm::unbond::UnbondDb;
 use orm::validators::{ValidatorDb, ValidatorStateDb};
 use serde::{Deserialize, Serialize};
 use shared::balance::Amount;
 use shared::crawler_state::ChainCrawlerState;
 use shared::id::Id;
+use shared::parameters::Parameters;
 
 use crate::response::utils::{epoch_progress, time_between_epochs};
 
@@ -77,6 +81,69 @@ pub struct MergedBond {
     pub redelegation: Option<MergedBondRedelegation>,
 }
 
+#[derive(Clone, Debug)]
+pub struct Redelegation {
+    pub tx_id: String,
+    pub src_validator: ValidatorWithRank,
+    pub dest_validator: ValidatorWithRank,
+    pub min_denom_amount: Amount,
+    pub start_epoch: u64,
+    pub end_epoch: u64,
+    pub slashing_window_end_epoch: u64,
+    pub block_height: u64,
+}
+
+/// Whether the bond of a delegator to a validator can be redelegated, which
+/// is not the case for tokens that were themselves redelegated to that
+/// validator until the slashing window of the redelegation is over
+#[derive(Clone, Debug)]
+pub struct RedelegationEligibility {
+    pub allowed: bool,
+    pub allowed_from_epoch: Option<u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ValidatorDelegator {
+    pub address: Id,
+    pub bonded: Amount,
+    pub unbonding: Amount,
+    pub start_epoch: Option<u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct StakeDistribution {
+    pub total_stake: Amount,
+    pub delegators: u64,
+    pub top: u64,
+    pub top_share: f64,
+    pub gini_coefficient: f64,
+    pub nakamoto_coefficient: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct PosStats {
+    pub epoch: u64,
+    pub total_bonded: Amount,
+    pub total_supply: Amount,
+    pub bonded_ratio: f64,
+    pub nakamoto_coefficient: u64,
+    pub active_validators: u64,
+    pub jailed_validators: u64,
+    pub inactive_validators: u64,
+    pub median_commission: Option<String>,
+    pub unbonding_queue: Amount,
+}
+
+/// Amount becoming withdrawable at an upcoming epoch
+#[derive(Clone, Debug)]
+pub struct UnbondingScheduleEntry {
+    pub withdraw_epoch: u64,
+    pub min_denom_amount: Amount,
+    pub unbonds: u64,
+    pub withdraw_time: u64,
+    pub time_remaining: u64,
+}
+
 #[derive(Clone, Debug)]
 pub struct Unbond {
     pub min_denom_amount: Amount,
@@ -91,12 +158,44 @@ pub struct Withdraw {
     pub min_denom_amount: Amount,
     pub validator: ValidatorWithRank,
     pub withdraw_epoch: u64,
+    pub withdrawn_at_height: Option<u64>,
+    pub withdrawn_at_epoch: Option<u64>,
 }
 
 #[derive(Clone, Debug)]
 pub struct Reward {
     pub min_denom_amount: Amount,
     pub validator: ValidatorWithRank,
+    pub claimed: bool,
+    pub claim_tx_id: Option<String>,
+    pub claimed_at_height: Option<u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RewardClaim {
+    pub tx_id: String,
+    pub block_height: u64,
+    pub timestamp: Option<i64>,
+    pub epoch: Option<u64>,
+    pub min_denom_amount: Amount,
+    pub validator: ValidatorWithRank,
+}
+
+#[derive(Clone, Debug)]
+pub struct ValidatorRewardLedger {
+    pub validator: ValidatorWithRank,
+    pub earned: Amount,
+    pub claimed: Amount,
+    pub pending: Amount,
+}
+
+#[derive(Clone, Debug)]
+pub struct RewardLedger {
+    pub total_earned: Amount,
+    pub total_claimed: Amount,
+    pub total_pending: Amount,
+    pub validators: Vec<ValidatorRewardLedger>,
+    pub claims: Vec<RewardClaim>,
 }
 
 impl From<ValidatorDb> for Validator {
@@ -181,6 +280,221 @@ impl MergedBond {
     }
 }
 
+impl Redelegation {
+    pub fn from(
+        db_redelegation: RedelegationHistoryDb,
+        db_src_validator: ValidatorDb,
+        db_dest_validator: ValidatorDb,
+        parameters: &Parameters,
+    ) -> Self {
+        let start_epoch = db_redelegation.epoch as u64;
+        let end_epoch = start_epoch + parameters.pipeline_length;
+
+        Self {
+            tx_id: db_redelegation.inner_tx_id,
+            src_validator: ValidatorWithRank::from(db_src_validator, None),
+            dest_validator: ValidatorWithRank::from(db_dest_validator, None),
+            min_denom_amount: db_redelegation.raw_amount.into(),
+            start_epoch,
+            end_epoch,
+            slashing_window_end_epoch: redelegation_slashing_window_end(
+                end_epoch, parameters,
+            ),
+            block_height: db_redelegation.height as u64,
+        }
+    }
+}
+
+impl RedelegationEligibility {
+    pub fn from(
+        incoming_redelegation: Option<RedelegationDb>,
+        current_epoch: u64,
+        parameters: &Parameters,
+    ) -> Self {
+        match incoming_redelegation {
+            Some(redelegation) => {
+                let allowed_from_epoch = redelegation_slashing_window_end(
+                    redelegation.end_epoch as u64,
+                    parameters,
+                );
+
+                Self {
+                    allowed: current_epoch >= allowed_from_epoch,
+                    allowed_from_epoch: Some(allowed_from_epoch),
+                }
+            }
+            None => Self {
+                allowed: true,
+                allowed_from_epoch: None,
+            },
+        }
+    }
+}
+
+/// First epoch at which tokens redelegated with the given end epoch can be
+/// redelegated again
+fn redelegation_slashing_window_end(
+    end_epoch: u64,
+    parameters: &Parameters,
+) -> u64 {
+    (end_epoch + parameters.slash_processing_epoch_offset()).saturating_sub(1)
+}
+
+impl From<PosStatsDb> for PosStats {
+    fn from(value: PosStatsDb) -> Self {
+        let bonded_ratio = if value.total_supply.is_zero() {
+            0.0
+        } else {
+            (&value.total_bonded / &value.total_supply)
+                .to_f64()
+                .unwrap_or_default()
+        };
+
+        Self {
+            epoch: value.epoch as u64,
+            total_bonded: value.total_bonded.into(),
+            total_supply: value.total_supply.into(),
+            bonded_ratio,
+            nakamoto_coefficient: value.nakamoto_coefficient as u64,
+            active_validators: value.active_validators as u64,
+            jailed_validators: value.jailed_validators as u64,
+            inactive_validators: value.inactive_validators as u64,
+            median_commission: value
+                .median_commission
+                .map(|commission| commission.normalized().to_string()),
+            unbonding_queue: value.unbonding_queue.into(),
+        }
+    }
+}
+
+impl ValidatorDelegator {
+    pub fn from(
+        address: String,
+        bonded: BigDecimal,
+        unbonding: BigDecimal,
+        start_epoch: Option<i32>,
+    ) -> Self {
+        Self {
+            address: Id::Account(address),
+            bonded: bonded.into(),
+            unbonding: unbonding.into(),
+            start_epoch: start_epoch.map(|epoch| epoch as u64),
+        }
+    }
+}
+
+impl StakeDistribution {
+    /// Summarizes how concentrated the stake of the given delegators is. The
+    /// Nakamoto coefficient is the smallest number of delegators holding more
+    /// than a third of the stake.
+    pub fn compute(mut stakes: Vec<BigDecimal>, top: u64) -> Self {
+        stakes.sort_by(|a, b| b.cmp(a));
+
+        let total_stake = stakes.iter().sum::<BigDecimal>();
+        let delegators = stakes.len() as u64;
+
+        if total_stake.is_zero() {
+            return Self {
+                total_stake: total_stake.into(),
+                delegators,
+                top,
+                top_share: 0.0,
+                gini_coefficient: 0.0,
+                nakamoto_coefficient: 0,
+            };
+        }
+
+        let share = |stake: &BigDecimal| {
+            (stake / &total_stake).to_f64().unwrap_or_default()
+        };
+
+        let top_share =
+            share(&stakes.iter().take(top as usize).sum::<BigDecimal>());
+
+        let mut cumulated = BigDecimal::zero();
+        let nakamoto_coefficient = stakes
+            .iter()
+            .take_while(|stake| {
+                let below_third = &cumulated * 3 <= total_stake;
+                cumulated += *stake;
+                below_third
+            })
+            .count() as u64;
+
+        // Gini coefficient over the stakes sorted in ascending order
+        let n = stakes.len() as f64;
+        let weighted_sum = stakes
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, stake)| (i + 1) as f64 * share(stake))
+            .sum::<f64>();
+        let gini_coefficient = (2.0 * weighted_sum) / n - (n + 1.0) / n;
+
+        Self {
+            total_stake: total_stake.into(),
+            delegators,
+            top,
+            top_share,
+            gini_coefficient,
+            nakamoto_coefficient,
+        }
+    }
+}
+
+/// Seconds from the last indexed block until the start of the given epoch
+fn time_until_epoch(
+    epoch: i32,
+    chain_state: &ChainCrawlerStateDb,
+    max_block_time: i32,
+    min_duration: i32,
+) -> i32 {
+    let blocks_per_epoch = min_duration / max_block_time;
+
+    let epoch_progress = epoch_progress(
+        chain_state.last_processed_block,
+        chain_state.first_block_in_epoch,
+        blocks_per_epoch,
+    );
+
+    time_between_epochs(
+        blocks_per_epoch,
+        epoch_progress,
+        chain_state.last_processed_epoch,
+        epoch,
+        min_duration,
+    )
+}
+
+impl UnbondingScheduleEntry {
+    pub fn from(
+        withdraw_epoch: i32,
+        raw_amount: BigDecimal,
+        unbonds: i64,
+        chain_state: &ChainCrawlerStateDb,
+        max_block_time: i32,
+        min_duration: i32,
+    ) -> Self {
+        let time_remaining = time_until_epoch(
+            withdraw_epoch,
+            chain_state,
+            max_block_time,
+            min_duration,
+        );
+
+        let time_now = chain_state.timestamp.and_utc().timestamp();
+        let withdraw_time = time_now + i64::from(time_remaining);
+
+        Self {
+            withdraw_epoch: withdraw_epoch as u64,
+            min_denom_amount: raw_amount.into(),
+            unbonds: unbonds as u64,
+            withdraw_time: withdraw_time as u64,
+            time_remaining: time_remaining.max(0) as u64,
+        }
+    }
+}
+
 impl Unbond {
     pub fn from(
         raw_amount: BigDecimal,
@@ -190,19 +504,10 @@ impl Unbond {
         max_block_time: i32,
         min_duration: i32,
     ) -> Self {
-        let blocks_per_epoch = min_duration / max_block_time;
-
-        let epoch_progress = epoch_progress(
-            chain_state.last_processed_block,
-            chain_state.first_block_in_epoch,
-            blocks_per_epoch,
-        );
-
-        let to_withdraw = time_between_epochs(
-            blocks_per_epoch,
-            epoch_progress,
-            chain_state.last_processed_epoch,
+        let to_withdraw = time_until_epoch(
             withdraw_epoch,
+            chain_state,
+            max_block_time,
             min_duration,
         );
 
@@ -225,6 +530,12 @@ impl Withdraw {
             min_denom_amount: db_unbond.raw_amount.into(),
             validator: ValidatorWithRank::from(db_validator, None),
             withdraw_epoch: db_unbond.withdraw_epoch as u64,
+            withdrawn_at_height: db_unbond
+                .withdrawn_at_height
+                .map(|height| height as u64),
+            withdrawn_at_epoch: db_unbond
+                .withdrawn_at_epoch
+                .map(|epoch| epoch as u64),
         }
     }
 }
@@ -234,6 +545,130 @@ impl Reward {
         Self {
             min_denom_amount: db_reward.raw_amount.into(),
             validator: ValidatorWithRank::from(db_validator, None),
+            claimed: db_reward.claimed,
+            claim_tx_id: db_reward.claim_tx_id,
+            claimed_at_height: db_reward.claimed_at_height.map(|h| h as u64),
+        }
+    }
+}
+
+impl Reward {
+    pub fn from_claim(
+        db_claim: PosRewardClaimDb,
+        db_validator: ValidatorDb,
+    ) -> Self {
+        Self {
+            min_denom_amount: db_claim.raw_amount.into(),
+            validator: ValidatorWithRank::from(db_validator, None),
+            claimed: true,
+            claim_tx_id: Some(db_claim.claim_tx_id),
+            claimed_at_height: Some(db_claim.block_height as u64),
         }
     }
 }
+
+impl RewardClaim {
+    pub fn from(
+        db_claim: PosRewardClaimDb,
+        db_validator: ValidatorDb,
+        timestamp: Option<NaiveDateTime>,
+    ) -> Self {
+        Self {
+            tx_id: db_claim.claim_tx_id,
+            block_height: db_claim.block_height as u64,
+            timestamp: timestamp.map(|t| t.and_utc().timestamp()),
+            epoch: db_claim.epoch.map(|epoch| epoch as u64),
+            min_denom_amount: db_claim.raw_amount.into(),
+            validator: ValidatorWithRank::from(db_validator, None),
+        }
+    }
+}
+
+impl ValidatorRewardLedger {
+    pub fn from(
+        claimed: BigDecimal,
+        pending: BigDecimal,
+        db_validator: ValidatorDb,
+    ) -> Self {
+        Self {
+            earned: (&claimed + &pending).into(),
+            claimed: claimed.into(),
+            pending: pending.into(),
+            validator: ValidatorWithRank::from(db_validator, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stakes(amounts: &[u64]) -> Vec<BigDecimal> {
+        amounts
+            .iter()
+            .map(|amount| BigDecimal::from(*amount))
+            .collect()
+    }
+
+    #[test]
+    fn stake_distribution_of_no_delegators() {
+        let distribution = StakeDistribution::compute(vec![], 10);
+
+        assert_eq!(distribution.total_stake, Amount::zero());
+        assert_eq!(distribution.delegators, 0);
+        assert_eq!(distribution.top_share, 0.0);
+        assert_eq!(distribution.gini_coefficient, 0.0);
+        assert_eq!(distribution.nakamoto_coefficient, 0);
+    }
+
+    #[test]
+    fn stake_distribution_of_zero_stakes() {
+        let distribution = StakeDistribution::compute(stakes(&[0, 0]), 10);
+
+        assert_eq!(distribution.delegators, 2);
+        assert_eq!(distribution.top_share, 0.0);
+        assert_eq!(distribution.gini_coefficient, 0.0);
+        assert_eq!(distribution.nakamoto_coefficient, 0);
+    }
+
+    #[test]
+    fn stake_distribution_of_single_delegator() {
+        let distribution = StakeDistribution::compute(stakes(&[42]), 10);
+
+        assert_eq!(
+            distribution.total_stake,
+            Amount::from(BigDecimal::from(42))
+        );
+        assert_eq!(distribution.delegators, 1);
+        assert_eq!(distribution.top_share, 1.0);
+        assert_eq!(distribution.gini_coefficient, 0.0);
+        assert_eq!(distribution.nakamoto_coefficient, 1);
+    }
+
+    #[test]
+    fn stake_distribution_of_equal_stakes() {
+        let distribution = StakeDistribution::compute(stakes(&[10, 10, 10]), 1);
+
+        // A single delegator holds exactly a third, which is not more than a
+        // third
+        assert_eq!(distribution.nakamoto_coefficient, 2);
+        assert!((distribution.top_share - 1.0 / 3.0).abs() < 1e-9);
+        assert!(distribution.gini_coefficient.abs() < 1e-9);
+    }
+
+    #[test]
+    fn stake_distribution_of_concentrated_stakes() {
+        let distribution =
+            StakeDistribution::compute(stakes(&[1, 97, 1, 1]), 1);
+
+        assert_eq!(
+            distribution.total_stake,
+            Amount::from(BigDecimal::from(100))
+        );
+        assert_eq!(distribution.delegators, 4);
+        assert_eq!(distribution.nakamoto_coefficient, 1);
+        assert!((distribution.top_share - 0.97).abs() < 1e-9);
+        // The maximum for 4 delegators is 0.75
+        assert!((distribution.gini_coefficient - 0.72).abs() < 1e-9);
+    }
+}
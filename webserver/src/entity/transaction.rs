@@ -1,3 +1,5 @@
+use namada_sdk::token::storage_key::is_any_token_balance_key;
+use namada_sdk::tx::data::DryRunResult;
 use orm::token::{IbcTokenDb, TokenDb};
 use orm::transactions::{
     InnerTransactionDb, TransactionHistoryDb, TransactionHistoryKindDb,
@@ -202,3 +204,114 @@ impl TransactionHistory {
         }
     }
 }
+
+#[derive(Clone, Debug)]
+pub enum DryRunInnerStatus {
+    /// Applied and accepted by all the triggered VPs
+    Accepted,
+    /// Applied but rejected by at least one VP
+    Rejected,
+    /// Failed to execute
+    Failed,
+}
+
+/// Balance touched by an inner transaction. The dry-run query only exposes
+/// the changed storage keys, not their new values, so the amount is unknown.
+#[derive(Clone, Debug)]
+pub struct DryRunBalanceChange {
+    pub owner: Id,
+    pub token: Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct DryRunInnerTransaction {
+    pub id: Id,
+    pub status: DryRunInnerStatus,
+    pub errors: Vec<String>,
+    pub rejected_vps: Vec<Id>,
+    pub initialized_accounts: Vec<Id>,
+    pub changed_keys: Vec<String>,
+    pub balance_changes: Vec<DryRunBalanceChange>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TransactionDryRun {
+    pub gas_used: u64,
+    pub inner_transactions: Vec<DryRunInnerTransaction>,
+}
+
+impl TransactionDryRun {
+    pub fn from_result(result: DryRunResult) -> Self {
+        let DryRunResult(tx_result, gas_used) = result;
+
+        let mut inner_transactions = tx_result
+            .iter()
+            .map(|(hash, result)| {
+                let id = Id::from(*hash);
+
+                match result {
+                    Ok(result) => DryRunInnerTransaction {
+                        id,
+                        status: if result.is_accepted() {
+                            DryRunInnerStatus::Accepted
+                        } else {
+                            DryRunInnerStatus::Rejected
+                        },
+                        errors: result
+                            .vps_result
+                            .errors
+                            .iter()
+                            .map(|(address, error)| {
+                                format!("{}: {}", address, error)
+                            })
+                            .collect(),
+                        rejected_vps: result
+                            .vps_result
+                            .rejected_vps
+                            .iter()
+                            .cloned()
+                            .map(Id::from)
+                            .collect(),
+                        initialized_accounts: result
+                            .initialized_accounts
+                            .iter()
+                            .cloned()
+                            .map(Id::from)
+                            .collect(),
+                        changed_keys: result
+                            .changed_keys
+                            .iter()
+                            .map(|key| key.to_string())
+                            .collect(),
+                        balance_changes: result
+                            .changed_keys
+                            .iter()
+                            .filter_map(is_any_token_balance_key)
+                            .map(|[token, owner]| DryRunBalanceChange {
+                                owner: Id::from(owner.clone()),
+                                token: Id::from(token.clone()),
+                            })
+                            .collect(),
+                    },
+                    Err(error) => DryRunInnerTransaction {
+                        id,
+                        status: DryRunInnerStatus::Failed,
+                        errors: vec![error.clone()],
+                        rejected_vps: vec![],
+                        initialized_accounts: vec![],
+                        changed_keys: vec![],
+                        balance_changes: vec![],
+                    },
+                }
+            })
+            .collect::<Vec<_>>();
+        // Batch results are stored in a hash map, sort them for a stable
+        // output
+        inner_transactions.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Self {
+            gas_used: gas_used.into(),
+            inner_transactions,
+        }
+    }
+}
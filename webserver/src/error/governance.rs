@@ -12,6 +12,8 @@ pub enum GovernanceError {
     NotFound(u64),
     #[error("Proposal {0} has no associated data")]
     DataNotFound(u64),
+    #[error("Invalid voting power: {0}")]
+    InvalidVotingPower(String),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Unknown error: {0}")]
@@ -24,9 +26,9 @@ impl IntoResponse for GovernanceError {
             GovernanceError::TooShortPattern(_) => StatusCode::BAD_REQUEST,
             GovernanceError::NotFound(_) => StatusCode::NOT_FOUND,
             GovernanceError::DataNotFound(_) => StatusCode::NOT_FOUND,
-            GovernanceError::Unknown(_) | GovernanceError::Database(_) => {
-                StatusCode::INTERNAL_SERVER_ERROR
-            }
+            GovernanceError::InvalidVotingPower(_)
+            | GovernanceError::Unknown(_)
+            | GovernanceError::Database(_) => StatusCode::INTERNAL_SERVER_ERROR,
         };
 
         ApiErrorResponse::send(status_code.as_u16(), Some(self.to_string()))
@@ -8,6 +8,14 @@ use crate::response::api::ApiErrorResponse;
 pub enum IbcError {
     #[error("Revealed public key {0} not found")]
     NotFound(u64),
+    #[error("Channel {0} not found")]
+    ChannelNotFound(String),
+    #[error("No rate limit found for token {0}")]
+    RateLimitNotFound(String),
+    #[error("Invalid older_than value: {0}")]
+    InvalidOlderThan(u64),
+    #[error("Invalid query parameters: {0}")]
+    InvalidQueryParams(String),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Unknown error: {0}")]
@@ -18,6 +26,11 @@ impl IntoResponse for IbcError {
     fn into_response(self) -> axum::response::Response {
         let status_code = match self {
             IbcError::NotFound(_) => StatusCode::NOT_FOUND,
+            IbcError::ChannelNotFound(_) => StatusCode::NOT_FOUND,
+            IbcError::RateLimitNotFound(_) => StatusCode::NOT_FOUND,
+            IbcError::InvalidOlderThan(_) | IbcError::InvalidQueryParams(_) => {
+                StatusCode::BAD_REQUEST
+            }
             IbcError::Unknown(_) | IbcError::Database(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
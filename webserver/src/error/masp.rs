@@ -7,6 +7,12 @@ use crate::response::api::ApiErrorResponse;
 pub enum MaspError {
     #[error("Database error: {0}")]
     Database(String),
+    #[error("No masp rewards found for token {0}")]
+    RewardsNotFound(String),
+    #[error("Invalid amount: {0}")]
+    InvalidAmount(String),
+    #[error("Invalid range: from {0} is after to {1}")]
+    InvalidRange(i64, i64),
     #[error("Unknown error: {0}")]
     Unknown(String),
 }
@@ -14,6 +20,10 @@ pub enum MaspError {
 impl IntoResponse for MaspError {
     fn into_response(self) -> axum::response::Response {
         let status_code = match self {
+            MaspError::RewardsNotFound(_) => StatusCode::NOT_FOUND,
+            MaspError::InvalidAmount(_) | MaspError::InvalidRange(_, _) => {
+                StatusCode::BAD_REQUEST
+            }
             MaspError::Unknown(_) | MaspError::Database(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
@@ -10,6 +10,10 @@ pub enum TransactionError {
     InvalidTxId,
     #[error("The tx id {0} does not exist")]
     TxIdNotFound(String),
+    #[error("Invalid transaction: {0}")]
+    InvalidTx(String),
+    #[error("Transaction rejected: {0}")]
+    DryRunRejected(String),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Rpc error: {0}")]
@@ -23,6 +27,10 @@ impl IntoResponse for TransactionError {
         let status_code = match self {
             TransactionError::InvalidTxId => StatusCode::BAD_REQUEST,
             TransactionError::TxIdNotFound(_) => StatusCode::NOT_FOUND,
+            TransactionError::InvalidTx(_) => StatusCode::BAD_REQUEST,
+            TransactionError::DryRunRejected(_) => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
             TransactionError::Unknown(_)
             | TransactionError::Database(_)
             | TransactionError::Rpc(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -2,7 +2,7 @@ use std::convert::Infallible;
 use std::time::Duration;
 
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Path, State};
 use axum::http::HeaderMap;
 use axum::response::Sse;
 use axum::response::sse::{Event, KeepAlive};
@@ -11,14 +11,18 @@ use futures::Stream;
 use tokio_stream::StreamExt;
 
 use crate::dto::chain::{
-    CirculatingSupply as CirculatingSupplyDto, TokenSupply as TokenSupplyDto,
+    CirculatingSupply as CirculatingSupplyDto,
+    NativeInflation as NativeInflationDto, TokenHoldersQueryParams,
+    TokenSupply as TokenSupplyDto, TokenSupplyHistory as TokenSupplyHistoryDto,
 };
 use crate::error::api::ApiError;
 use crate::response::chain::{
-    CirculatingSupplyResponse, LastProcessedBlockResponse,
-    LastProcessedEpochResponse, ParametersResponse, RpcUrlResponse,
-    TokenResponse, TokenSupplyResponse,
+    CirculatingSupplyBreakdownResponse, CirculatingSupplyResponse,
+    EpochResponse, LastProcessedBlockResponse, LastProcessedEpochResponse,
+    NativeInflationResponse, ParametersResponse, RpcUrlResponse,
+    TokenHoldersResponse, TokenMetadataResponse, TokenSupplyResponse,
 };
+use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
 
 #[derive(serde::Serialize)]
@@ -81,13 +85,39 @@ pub async fn get_rpc_url(
 
 pub async fn get_tokens(
     State(state): State<CommonState>,
-) -> Result<Json<Vec<TokenResponse>>, ApiError> {
-    let tokens = state.chain_service.find_tokens().await?;
-    let res = tokens.into_iter().map(TokenResponse::from).collect();
+) -> Result<Json<Vec<TokenMetadataResponse>>, ApiError> {
+    let tokens = state.chain_service.find_tokens_metadata().await?;
+    let res = tokens
+        .into_iter()
+        .map(TokenMetadataResponse::from)
+        .collect();
 
     Ok(Json(res))
 }
 
+pub async fn get_token_holders(
+    Path(address): Path<String>,
+    Query(query): Query<TokenHoldersQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<TokenHoldersResponse>, ApiError> {
+    let page = query.page.unwrap_or(1);
+    let (holders, total_pages, total_items) = state
+        .chain_service
+        .get_token_holders(
+            address,
+            query.exclude_protocol.unwrap_or(false),
+            page,
+        )
+        .await?;
+
+    Ok(Json(TokenHoldersResponse::new(
+        holders,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
 pub async fn get_last_processed_block(
     State(state): State<CommonState>,
 ) -> Result<Json<LastProcessedBlockResponse>, ApiError> {
@@ -110,6 +140,15 @@ pub async fn get_last_processed_epoch(
     }))
 }
 
+pub async fn get_epoch(
+    Path(epoch): Path<u32>,
+    State(state): State<CommonState>,
+) -> Result<Json<EpochResponse>, ApiError> {
+    let epoch = state.chain_service.find_epoch(epoch).await?;
+
+    Ok(Json(EpochResponse::from(epoch)))
+}
+
 pub async fn get_token_supply(
     Query(query): Query<TokenSupplyDto>,
     State(state): State<CommonState>,
@@ -124,6 +163,50 @@ pub async fn get_token_supply(
     Ok(Json(response))
 }
 
+pub async fn get_token_supply_history(
+    Query(query): Query<TokenSupplyHistoryDto>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<TokenSupplyResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+    let (supplies, total_pages, total_items) = state
+        .chain_service
+        .get_token_supply_history(query.address, page)
+        .await?;
+
+    let response = supplies
+        .into_iter()
+        .map(TokenSupplyResponse::from)
+        .collect();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
+pub async fn get_native_inflation(
+    Query(query): Query<NativeInflationDto>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<NativeInflationResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+    let (inflation, total_pages, total_items) =
+        state.chain_service.get_native_inflation(page).await?;
+
+    let response = inflation
+        .into_iter()
+        .map(NativeInflationResponse::from)
+        .collect();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
 pub async fn get_circulating_supply(
     Query(query): Query<CirculatingSupplyDto>,
     State(state): State<CommonState>,
@@ -135,3 +218,14 @@ pub async fn get_circulating_supply(
 
     Ok(Json(CirculatingSupplyResponse::from(supply)))
 }
+
+pub async fn get_circulating_supply_breakdown(
+    State(state): State<CommonState>,
+) -> Result<Json<CirculatingSupplyBreakdownResponse>, ApiError> {
+    let breakdown = state
+        .chain_service
+        .get_circulating_supply_breakdown()
+        .await?;
+
+    Ok(Json(CirculatingSupplyBreakdownResponse::from(breakdown)))
+}
@@ -3,9 +3,11 @@ use axum::extract::{Path, Query, State};
 use axum::http::HeaderMap;
 use axum_macros::debug_handler;
 
-use crate::dto::gas::GasEstimateQuery;
+use crate::dto::gas::{GasEstimateBatchBody, GasEstimateQuery};
 use crate::error::api::ApiError;
-use crate::response::gas::{GasEstimateResponse, GasPriceResponse};
+use crate::response::gas::{
+    GasEstimateIntervalResponse, GasEstimateResponse, GasPriceResponse,
+};
 use crate::state::common::CommonState;
 
 #[debug_handler]
@@ -78,3 +80,19 @@ pub async fn get_gas_estimate(
 
     Ok(Json(response))
 }
+
+#[debug_handler]
+pub async fn get_gas_estimate_for_batch(
+    _headers: HeaderMap,
+    State(state): State<CommonState>,
+    Json(body): Json<GasEstimateBatchBody>,
+) -> Result<Json<GasEstimateIntervalResponse>, ApiError> {
+    body.is_valid()?;
+
+    let estimate = state
+        .gas_service
+        .estimate_gas_for_batch(body.into())
+        .await?;
+
+    Ok(Json(estimate.into()))
+}
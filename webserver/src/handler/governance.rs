@@ -1,13 +1,21 @@
 use axum::Json;
-use axum::extract::{Path, Query, State};
+use axum::extract::{Path, State};
 use axum::http::HeaderMap;
+use axum_extra::extract::Query;
 use axum_macros::debug_handler;
 
-use crate::dto::governance::{ProposalQueryParams, ProposalVotesQueryparams};
+use crate::dto::governance::{
+    ProposalQueryParams, ProposalSearchQueryParams, ProposalVotesQueryparams,
+    ValidatorParticipationQueryParams,
+};
+use crate::dto::pos::ValidatorStateDto;
 use crate::error::api::ApiError;
 use crate::error::governance::GovernanceError;
 use crate::response::governance::{
-    ProposalDataResponse, ProposalResponse, ProposalVoteResponse,
+    ProposalDataResponse, ProposalResponse, ProposalSearchResultResponse,
+    ProposalTallyProjectionResponse, ProposalTimelineResponse,
+    ProposalVoteResponse, ValidatorParticipationResponse,
+    VoterParticipationResponse,
 };
 use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
@@ -42,6 +50,32 @@ pub async fn get_governance_proposals(
     )))
 }
 
+#[debug_handler]
+pub async fn search_governance_proposals(
+    _headers: HeaderMap,
+    Query(query): Query<ProposalSearchQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<ProposalSearchResultResponse>>>, ApiError>
+{
+    let page = query.page.unwrap_or(1);
+    let (results, total_pages, total_items) = state
+        .gov_service
+        .search_governance_proposals(query.q, page)
+        .await?;
+
+    let response = results
+        .into_iter()
+        .map(ProposalSearchResultResponse::from)
+        .collect::<Vec<_>>();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
 #[debug_handler]
 pub async fn get_governance_proposal_by_id(
     _headers: HeaderMap,
@@ -60,6 +94,34 @@ pub async fn get_governance_proposal_by_id(
     }
 }
 
+#[debug_handler]
+pub async fn get_governance_proposal_timeline(
+    _headers: HeaderMap,
+    Path(proposal_id): Path<u64>,
+    State(state): State<CommonState>,
+) -> Result<Json<ProposalTimelineResponse>, ApiError> {
+    let timeline = state
+        .gov_service
+        .find_governance_proposal_timeline(proposal_id)
+        .await?;
+
+    Ok(Json(ProposalTimelineResponse::from(timeline)))
+}
+
+#[debug_handler]
+pub async fn get_governance_proposal_tally_projection(
+    _headers: HeaderMap,
+    Path(proposal_id): Path<u64>,
+    State(state): State<CommonState>,
+) -> Result<Json<ProposalTallyProjectionResponse>, ApiError> {
+    let projection = state
+        .gov_service
+        .project_governance_proposal_tally(proposal_id)
+        .await?;
+
+    Ok(Json(ProposalTallyProjectionResponse::from(projection)))
+}
+
 #[debug_handler]
 pub async fn get_proposal_data_by_proposal_id(
     _headers: HeaderMap,
@@ -136,3 +198,53 @@ pub async fn get_governance_proposal_votes_by_voter(
 
     Ok(Json(response))
 }
+
+#[debug_handler]
+pub async fn get_validators_participation(
+    _headers: HeaderMap,
+    Query(query): Query<ValidatorParticipationQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<ValidatorParticipationResponse>>, ApiError> {
+    let states = query.state.unwrap_or_else(ValidatorStateDto::all);
+    let participations = state
+        .gov_service
+        .get_validators_participation(states)
+        .await?;
+
+    let response = participations
+        .into_iter()
+        .map(ValidatorParticipationResponse::from)
+        .collect();
+
+    Ok(Json(response))
+}
+
+#[debug_handler]
+pub async fn get_non_voting_validators(
+    _headers: HeaderMap,
+    Query(query): Query<ValidatorParticipationQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<ValidatorParticipationResponse>>, ApiError> {
+    let states = query.state.unwrap_or_else(ValidatorStateDto::all);
+    let validators =
+        state.gov_service.get_non_voting_validators(states).await?;
+
+    let response = validators
+        .into_iter()
+        .map(ValidatorParticipationResponse::from)
+        .collect();
+
+    Ok(Json(response))
+}
+
+#[debug_handler]
+pub async fn get_voter_participation(
+    _headers: HeaderMap,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<VoterParticipationResponse>, ApiError> {
+    let participation =
+        state.gov_service.get_voter_participation(address).await?;
+
+    Ok(Json(VoterParticipationResponse::from(participation)))
+}
@@ -4,15 +4,22 @@ use axum::http::HeaderMap;
 use axum_extra::extract::Query;
 use axum_macros::debug_handler;
 use bigdecimal::BigDecimal;
+use validator::Validate;
 
 use crate::dto::ibc::{
-    IbcRateLimit as IbcRateLimitDto, IbcTokenFlow as IbcTokenFlowDto,
+    IbcChannelQueryParams, IbcPendingQueryParams,
+    IbcRateLimit as IbcRateLimitDto, IbcRateLimitHistoryQueryParams,
+    IbcTokenFlow as IbcTokenFlowDto, IbcTransferQueryParams,
 };
 use crate::error::api::ApiError;
+use crate::error::ibc::IbcError;
 use crate::response::ibc::{
-    IbcAckResponse, IbcRateLimitResponse, IbcTokenFlowResponse,
-    IbcTokenThroughputResponse,
+    IbcAckResponse, IbcChannelResponse, IbcPendingTransferResponse,
+    IbcRateLimitHeadroomResponse, IbcRateLimitResponse,
+    IbcRateLimitUsageResponse, IbcTokenFlowResponse,
+    IbcTokenThroughputResponse, IbcTransferResponse,
 };
+use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
 
 #[debug_handler]
@@ -28,6 +35,91 @@ pub async fn get_ibc_status(
     Ok(Json(response))
 }
 
+#[debug_handler]
+pub async fn get_ibc_transfers(
+    _headers: HeaderMap,
+    Query(query): Query<IbcTransferQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<IbcTransferResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+    let (transfers, total_pages, total_items) = state
+        .ibc_service
+        .find_ibc_transfers(
+            query.sender,
+            query.receiver,
+            query.channel,
+            query.status,
+            page,
+        )
+        .await?;
+
+    let response = transfers
+        .into_iter()
+        .map(IbcTransferResponse::from)
+        .collect::<Vec<_>>();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
+#[debug_handler]
+pub async fn get_ibc_pending_transfers(
+    _headers: HeaderMap,
+    Query(query): Query<IbcPendingQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<IbcPendingTransferResponse>>>, ApiError>
+{
+    query
+        .validate()
+        .map_err(|e| IbcError::InvalidQueryParams(e.to_string()))?;
+
+    let page = query.page.unwrap_or(1);
+    let (transfers, total_pages, total_items) = state
+        .ibc_service
+        .find_ibc_pending_transfers(query.older_than, page)
+        .await?;
+
+    let response = transfers
+        .into_iter()
+        .map(IbcPendingTransferResponse::from)
+        .collect::<Vec<_>>();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
+#[debug_handler]
+pub async fn get_ibc_channels(
+    _headers: HeaderMap,
+    Query(query): Query<IbcChannelQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<IbcChannelResponse>>, ApiError> {
+    let channels = state.ibc_service.find_ibc_channels(query.state).await?;
+
+    let response = channels.into_iter().map(IbcChannelResponse::from).collect();
+
+    Ok(Json(response))
+}
+
+#[debug_handler]
+pub async fn get_ibc_channel(
+    _headers: HeaderMap,
+    Path(channel_id): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<IbcChannelResponse>, ApiError> {
+    let channel = state.ibc_service.find_ibc_channel(channel_id).await?;
+
+    Ok(Json(IbcChannelResponse::from(channel)))
+}
+
 #[debug_handler]
 pub async fn get_ibc_rate_limits(
     Query(query): Query<IbcRateLimitDto>,
@@ -49,6 +141,44 @@ pub async fn get_ibc_rate_limits(
     Ok(Json(response))
 }
 
+#[debug_handler]
+pub async fn get_ibc_rate_limit_history(
+    _headers: HeaderMap,
+    Path(token): Path<String>,
+    Query(query): Query<IbcRateLimitHistoryQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<IbcRateLimitUsageResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+    let (usage, total_pages, total_items) =
+        state.ibc_service.find_rate_limit_usage(token, page).await?;
+
+    let response = usage
+        .into_iter()
+        .map(IbcRateLimitUsageResponse::from)
+        .collect::<Vec<_>>();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
+#[debug_handler]
+pub async fn get_ibc_rate_limit_headroom(
+    _headers: HeaderMap,
+    Path(token): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<IbcRateLimitHeadroomResponse>, ApiError> {
+    let usage = state
+        .ibc_service
+        .get_rate_limit_headroom(token.clone())
+        .await?;
+
+    Ok(Json(IbcRateLimitHeadroomResponse::from((token, usage))))
+}
+
 #[debug_handler]
 pub async fn get_ibc_token_flows(
     Query(query): Query<IbcTokenFlowDto>,
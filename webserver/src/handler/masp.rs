@@ -1,14 +1,21 @@
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Path, State};
 use axum::http::HeaderMap;
 use axum_extra::extract::Query;
 use axum_macros::debug_handler;
 
-use crate::dto::masp::MaspAggregatesQueryParams;
+use crate::dto::masp::{
+    MaspActivityQueryParams, MaspAggregatesQueryParams,
+    MaspPoolSeriesQueryParams, MaspRewardEstimateQueryParams,
+    MaspRewardHistoryQueryParams,
+};
 use crate::error::api::ApiError;
 use crate::response::masp::{
-    MaspPoolAggregateResponse, MaspRewardDataResponse,
+    MaspActivityResponse, MaspPoolAggregateResponse, MaspPoolSeriesResponse,
+    MaspRewardDataResponse, MaspRewardEstimateResponse,
+    MaspRewardHistoryResponse,
 };
+use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
 
 #[debug_handler]
@@ -44,3 +51,85 @@ pub async fn get_masp_rates(
 
     Ok(Json(response))
 }
+
+#[debug_handler]
+pub async fn get_masp_pool_series(
+    _headers: HeaderMap,
+    Path(token): Path<String>,
+    State(state): State<CommonState>,
+    Query(query): Query<MaspPoolSeriesQueryParams>,
+) -> Result<Json<MaspPoolSeriesResponse>, ApiError> {
+    let interval = query.interval.unwrap_or_default();
+
+    let series = state
+        .masp_service
+        .find_masp_pool_series(token, interval.clone(), query.from, query.to)
+        .await?;
+
+    Ok(Json(MaspPoolSeriesResponse::new(series, interval)))
+}
+
+#[debug_handler]
+pub async fn get_masp_rates_history(
+    _headers: HeaderMap,
+    Path(token): Path<String>,
+    State(state): State<CommonState>,
+    Query(query): Query<MaspRewardHistoryQueryParams>,
+) -> Result<Json<PaginatedResponse<Vec<MaspRewardHistoryResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (history, total_pages, total_items) = state
+        .masp_service
+        .find_masp_rates_history(token, page)
+        .await?;
+
+    let response = history
+        .into_iter()
+        .map(MaspRewardHistoryResponse::from)
+        .collect();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
+#[debug_handler]
+pub async fn get_masp_rewards_estimate(
+    _headers: HeaderMap,
+    State(state): State<CommonState>,
+    Query(query): Query<MaspRewardEstimateQueryParams>,
+) -> Result<Json<MaspRewardEstimateResponse>, ApiError> {
+    let estimate = state
+        .masp_service
+        .estimate_masp_rewards(query.token, query.amount, query.epochs)
+        .await?;
+
+    Ok(Json(MaspRewardEstimateResponse::from(estimate)))
+}
+
+#[debug_handler]
+pub async fn get_masp_activity(
+    _headers: HeaderMap,
+    State(state): State<CommonState>,
+    Query(query): Query<MaspActivityQueryParams>,
+) -> Result<Json<PaginatedResponse<Vec<MaspActivityResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (activity, total_pages, total_items) =
+        state.masp_service.find_masp_activity(page).await?;
+
+    let response = activity
+        .into_iter()
+        .map(MaspActivityResponse::from)
+        .collect();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
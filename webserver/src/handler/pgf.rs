@@ -4,9 +4,15 @@ use axum::http::HeaderMap;
 use axum_extra::extract::Query;
 use axum_macros::debug_handler;
 
-use crate::dto::pgf::PgfQueryParams;
+use crate::dto::pgf::{
+    PgfQueryParams, PgfScheduledPaymentsQueryParams,
+    PgfStewardHistoryQueryParams,
+};
 use crate::error::api::ApiError;
-use crate::response::pgf::PgfPaymentResponse;
+use crate::response::pgf::{
+    PgfContinuousPaymentResponse, PgfPaymentResponse,
+    PgfRecipientTotalResponse, PgfScheduledPaymentResponse, PgfStewardResponse,
+};
 use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
 
@@ -52,3 +58,98 @@ pub async fn get_pgf_payment_by_proposal_id(
 
     Ok(Json(response))
 }
+
+#[debug_handler]
+pub async fn get_pgf_stewards(
+    _headers: HeaderMap,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<PgfStewardResponse>>, ApiError> {
+    let stewards = state.pgf_service.get_current_stewards().await?;
+
+    let response = stewards.into_iter().map(|steward| steward.into()).collect();
+
+    Ok(Json(response))
+}
+
+#[debug_handler]
+pub async fn get_pgf_steward_history(
+    _headers: HeaderMap,
+    Query(query): Query<PgfStewardHistoryQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<PgfStewardResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (stewards, total_pages, total_items) = state
+        .pgf_service
+        .get_steward_history(query.address, page)
+        .await?;
+
+    let response = stewards.into_iter().map(|steward| steward.into()).collect();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
+#[debug_handler]
+pub async fn get_pgf_active_continuous_payments(
+    _headers: HeaderMap,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<PgfContinuousPaymentResponse>>, ApiError> {
+    let payments = state.pgf_service.get_active_continuous_payments().await?;
+
+    let response = payments.into_iter().map(|payment| payment.into()).collect();
+
+    Ok(Json(response))
+}
+
+#[debug_handler]
+pub async fn get_pgf_scheduled_payments(
+    _headers: HeaderMap,
+    Query(query): Query<PgfScheduledPaymentsQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<PgfScheduledPaymentResponse>>>, ApiError>
+{
+    let page = query.page.unwrap_or(1);
+
+    let (scheduled_payments, total_pages, total_items) = state
+        .pgf_service
+        .get_scheduled_payments(query.receipient, query.epoch, page)
+        .await?;
+
+    let response = scheduled_payments
+        .into_iter()
+        .map(|scheduled_payment| scheduled_payment.into())
+        .collect();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
+#[debug_handler]
+pub async fn get_pgf_recipient_totals(
+    _headers: HeaderMap,
+    Query(query): Query<PgfQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<PgfRecipientTotalResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (totals, total_pages, total_items) =
+        state.pgf_service.get_recipient_totals(page).await?;
+
+    let response = totals.into_iter().map(|total| total.into()).collect();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
@@ -5,13 +5,18 @@ use axum_extra::extract::Query;
 use axum_macros::debug_handler;
 
 use crate::dto::pos::{
-    AllValidatorsQueryParams, BondsDto, RewardsDto, UnbondsDto,
-    ValidatorQueryParams, ValidatorStateDto, WithdrawsDto,
+    AllValidatorsQueryParams, BondsDto, DelegatorsQueryParams,
+    PosStatsHistoryQueryParams, PosStatsQueryParams, RedelegationsDto,
+    RewardsDto, StakeDistributionQueryParams, UnbondsDto, ValidatorQueryParams,
+    ValidatorStateDto, WithdrawsDto,
 };
 use crate::error::api::ApiError;
 use crate::response::pos::{
-    BondResponse, MergedBondResponse, RewardResponse, TotalVotingPowerResponse,
-    UnbondResponse, ValidatorWithRankResponse, WithdrawResponse,
+    BondResponse, MergedBondResponse, PosStatsResponse,
+    RedelegationEligibilityResponse, RedelegationResponse,
+    RewardLedgerResponse, RewardResponse, StakeDistributionResponse,
+    TotalVotingPowerResponse, UnbondResponse, UnbondingScheduleEntryResponse,
+    ValidatorDelegatorResponse, ValidatorWithRankResponse, WithdrawResponse,
 };
 use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
@@ -155,6 +160,161 @@ pub async fn get_merged_unbonds(
     )))
 }
 
+#[debug_handler]
+pub async fn get_validator_delegators(
+    _headers: HeaderMap,
+    Query(query): Query<DelegatorsQueryParams>,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<ValidatorDelegatorResponse>>>, ApiError>
+{
+    let page = query.page.unwrap_or(1);
+
+    let (delegators, total_pages, total_delegators) = state
+        .pos_service
+        .get_delegators_by_validator(
+            address,
+            query.sort_field,
+            query.sort_order,
+            page,
+        )
+        .await?;
+
+    let response = delegators
+        .into_iter()
+        .map(ValidatorDelegatorResponse::from)
+        .collect();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_delegators,
+    )))
+}
+
+#[debug_handler]
+pub async fn get_validator_stake_distribution(
+    _headers: HeaderMap,
+    Query(query): Query<StakeDistributionQueryParams>,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<StakeDistributionResponse>, ApiError> {
+    let distribution = state
+        .pos_service
+        .get_stake_distribution(address, query.top.unwrap_or(10))
+        .await?;
+
+    Ok(Json(StakeDistributionResponse::from(distribution)))
+}
+
+#[debug_handler]
+pub async fn get_unbonding_schedule(
+    _headers: HeaderMap,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<UnbondingScheduleEntryResponse>>, ApiError> {
+    let schedule = state.pos_service.get_unbonding_schedule(None).await?;
+
+    let response = schedule
+        .into_iter()
+        .map(UnbondingScheduleEntryResponse::from)
+        .collect();
+
+    Ok(Json(response))
+}
+
+#[debug_handler]
+pub async fn get_unbonding_schedule_by_address(
+    _headers: HeaderMap,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<UnbondingScheduleEntryResponse>>, ApiError> {
+    let schedule = state
+        .pos_service
+        .get_unbonding_schedule(Some(address))
+        .await?;
+
+    let response = schedule
+        .into_iter()
+        .map(UnbondingScheduleEntryResponse::from)
+        .collect();
+
+    Ok(Json(response))
+}
+
+#[debug_handler]
+pub async fn get_pos_stats(
+    _headers: HeaderMap,
+    Query(query): Query<PosStatsQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PosStatsResponse>, ApiError> {
+    let stats = state.pos_service.get_pos_stats(query.epoch).await?;
+
+    Ok(Json(PosStatsResponse::from(stats)))
+}
+
+#[debug_handler]
+pub async fn get_pos_stats_history(
+    _headers: HeaderMap,
+    Query(query): Query<PosStatsHistoryQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<PosStatsResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (stats, total_pages, total_items) =
+        state.pos_service.get_pos_stats_history(page).await?;
+
+    let response = stats.into_iter().map(PosStatsResponse::from).collect();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
+#[debug_handler]
+pub async fn get_redelegations(
+    _headers: HeaderMap,
+    query: Query<RedelegationsDto>,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<RedelegationResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (redelegations, total_pages, total_redelegations) = state
+        .pos_service
+        .get_redelegations_by_address(address, page)
+        .await?;
+
+    let response = redelegations
+        .into_iter()
+        .map(RedelegationResponse::from)
+        .collect();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_redelegations,
+    )))
+}
+
+#[debug_handler]
+pub async fn get_redelegation_eligibility(
+    _headers: HeaderMap,
+    Path((delegator, validator)): Path<(String, String)>,
+    State(state): State<CommonState>,
+) -> Result<Json<RedelegationEligibilityResponse>, ApiError> {
+    let eligibility = state
+        .pos_service
+        .get_redelegation_eligibility(delegator, validator)
+        .await?;
+
+    Ok(Json(RedelegationEligibilityResponse::from(eligibility)))
+}
+
 #[debug_handler]
 pub async fn get_withdraws(
     _headers: HeaderMap,
@@ -166,7 +326,12 @@ pub async fn get_withdraws(
 
     let (withdraws, total_pages, total_withdraws) = state
         .pos_service
-        .get_withdraws_by_address(address, query.epoch, page)
+        .get_withdraws_by_address(
+            address,
+            query.epoch,
+            query.include_history.unwrap_or(false),
+            page,
+        )
         .await?;
 
     let response = withdraws.into_iter().map(WithdrawResponse::from).collect();
@@ -188,7 +353,11 @@ pub async fn get_rewards(
 ) -> Result<Json<Vec<RewardResponse>>, ApiError> {
     let rewards = state
         .pos_service
-        .get_rewards_by_address(address, query.epoch)
+        .get_rewards_by_address(
+            address,
+            query.epoch,
+            query.include_history.unwrap_or(false),
+        )
         .await?;
     let response = rewards.into_iter().map(RewardResponse::from).collect();
 
@@ -212,6 +381,20 @@ pub async fn get_rewards_by_delegator_and_validator_and_epoch(
     Ok(Json(response))
 }
 
+#[debug_handler]
+pub async fn get_reward_ledger(
+    _headers: HeaderMap,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<RewardLedgerResponse>, ApiError> {
+    let ledger = state
+        .pos_service
+        .get_reward_ledger_by_address(address)
+        .await?;
+
+    Ok(Json(RewardLedgerResponse::from(ledger)))
+}
+
 #[debug_handler]
 pub async fn get_total_voting_power(
     _headers: HeaderMap,
@@ -5,14 +5,14 @@ use axum_extra::extract::Query;
 use axum_macros::debug_handler;
 
 use crate::dto::transaction::{
-    TransactionHistoryQueryParams, TransactionIdParam,
+    TransactionDryRunBody, TransactionHistoryQueryParams, TransactionIdParam,
     TransactionMostRecentQueryParams,
 };
 use crate::error::api::ApiError;
 use crate::error::transaction::TransactionError;
 use crate::response::transaction::{
-    InnerTransactionResponse, TransactionHistoryResponse,
-    WrapperTransactionResponse,
+    InnerTransactionResponse, TransactionDryRunResponse,
+    TransactionHistoryResponse, WrapperTransactionResponse,
 };
 use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
@@ -129,3 +129,19 @@ pub async fn get_most_recent_transactions(
 
     Ok(Json(response))
 }
+
+#[debug_handler]
+pub async fn dry_run_tx(
+    _headers: HeaderMap,
+    State(state): State<CommonState>,
+    Json(body): Json<TransactionDryRunBody>,
+) -> Result<Json<TransactionDryRunResponse>, ApiError> {
+    let tx_bytes = body.tx_bytes()?;
+
+    let dry_run = state
+        .transaction_service
+        .dry_run_tx(&state.client, tx_bytes)
+        .await?;
+
+    Ok(Json(dry_run.into()))
+}
@@ -10,3 +10,4 @@ pub mod repository;
 pub mod response;
 pub mod service;
 pub mod state;
+pub mod token_registry;
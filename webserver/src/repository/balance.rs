@@ -1,11 +1,16 @@
 use async_trait::async_trait;
+use bigdecimal::{BigDecimal, Zero};
+use diesel::dsl::{count_star, sql, sum};
+use diesel::sql_types::Integer;
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
 use orm::balances::BalanceDb;
 use orm::schema::{ibc_token, token};
 use orm::token::{IbcTokenDb, TokenDb};
 use orm::views::balances;
 
+use super::utils::{Paginate, PaginatedResponseDb};
 use crate::appstate::AppState;
+use crate::constant::HOLDER_BUCKETS;
 
 #[derive(Clone)]
 pub struct BalanceRepo {
@@ -24,6 +29,29 @@ pub trait BalanceRepoTrait {
         &self,
         address: String,
     ) -> Result<Vec<BalanceDb>, String>;
+
+    async fn find_token_balances_by_owners(
+        &self,
+        token: String,
+        owners: Vec<String>,
+    ) -> Result<Vec<(String, BigDecimal)>, String>;
+
+    async fn find_token_holders(
+        &self,
+        token: String,
+        excluded_owners: Vec<String>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<(String, BigDecimal)>, String>;
+
+    /// Groups the holders of a token by order of magnitude of their balance
+    /// in the display unit of the token, returning for each bucket its
+    /// index, the number of holders and their total balance
+    async fn find_token_holders_distribution(
+        &self,
+        token: String,
+        excluded_owners: Vec<String>,
+        denomination: u8,
+    ) -> Result<Vec<(i32, i64, Option<BigDecimal>)>, String>;
 }
 
 #[async_trait]
@@ -49,6 +77,86 @@ impl BalanceRepoTrait for BalanceRepo {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_token_balances_by_owners(
+        &self,
+        token: String,
+        owners: Vec<String>,
+    ) -> Result<Vec<(String, BigDecimal)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            balances::table
+                .filter(balances::dsl::token.eq(token))
+                .filter(balances::dsl::owner.eq_any(owners))
+                .select((balances::dsl::owner, balances::dsl::raw_amount))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_token_holders(
+        &self,
+        token: String,
+        excluded_owners: Vec<String>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<(String, BigDecimal)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            balances::table
+                .filter(balances::dsl::token.eq(token))
+                .filter(balances::dsl::raw_amount.gt(BigDecimal::zero()))
+                .filter(balances::dsl::owner.ne_all(excluded_owners))
+                .order((
+                    balances::dsl::raw_amount.desc(),
+                    balances::dsl::owner.asc(),
+                ))
+                .select((balances::dsl::owner, balances::dsl::raw_amount))
+                .paginate(page)
+                .load_and_count_pages::<(String, BigDecimal)>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_token_holders_distribution(
+        &self,
+        token: String,
+        excluded_owners: Vec<String>,
+        denomination: u8,
+    ) -> Result<Vec<(i32, i64, Option<BigDecimal>)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        // Bucket 0 holds balances below 1, the last bucket balances of at
+        // least 10^(HOLDER_BUCKETS - 2)
+        let bucket = format!(
+            "LEAST(GREATEST(FLOOR(LOG(raw_amount) - {denomination})::int + 1, \
+             0), {})",
+            HOLDER_BUCKETS - 1
+        );
+
+        conn.interact(move |conn| {
+            balances::table
+                .filter(balances::dsl::token.eq(token))
+                .filter(balances::dsl::raw_amount.gt(BigDecimal::zero()))
+                .filter(balances::dsl::owner.ne_all(excluded_owners))
+                .group_by(sql::<Integer>(&bucket))
+                .select((
+                    sql::<Integer>(&bucket),
+                    count_star(),
+                    sum(balances::dsl::raw_amount),
+                ))
+                .order(sql::<Integer>(&bucket).asc())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn get_all_token(
         &self,
     ) -> Result<Vec<(TokenDb, Option<IbcTokenDb>)>, String> {
@@ -28,6 +28,11 @@ pub trait BlockRepositoryTrait {
         &self,
         hash: String,
     ) -> Result<Option<BlockDb>, String>;
+
+    async fn find_first_blocks_in_epochs(
+        &self,
+        epochs: Vec<i32>,
+    ) -> Result<Vec<BlockDb>, String>;
 }
 
 #[async_trait]
@@ -96,4 +101,24 @@ impl BlockRepositoryTrait for BlockRepository {
         .await
         .map_err(|e| e.to_string())
     }
+
+    /// Gets the first indexed block of each of the given epochs
+    async fn find_first_blocks_in_epochs(
+        &self,
+        epochs: Vec<i32>,
+    ) -> Result<Vec<BlockDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            blocks::table
+                .filter(blocks::epoch.eq_any(epochs))
+                .distinct_on(blocks::epoch)
+                .order((blocks::epoch.asc(), blocks::height.asc()))
+                .select(BlockDb::as_select())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
@@ -5,13 +5,17 @@ use diesel::{
     QueryDsl, RunQueryDsl, SelectableHelper,
 };
 use orm::crawler_state::{ChainCrawlerStateDb, CrawlerNameDb};
+use orm::epochs::EpochDb;
+use orm::native_inflation_per_epoch::NativeInflationDb;
 use orm::parameters::ParametersDb;
 use orm::schema::{
-    chain_parameters, crawler_state, ibc_token, token, token_supplies_per_epoch,
+    chain_parameters, crawler_state, epochs, ibc_token,
+    native_inflation_per_epoch, token, token_supplies_per_epoch,
 };
 use orm::token::{IbcTokenDb, TokenDb};
 use orm::token_supplies_per_epoch::TokenSuppliesDb;
 
+use super::utils::{Paginate, PaginatedResponseDb};
 use crate::appstate::AppState;
 
 #[derive(Clone)]
@@ -29,6 +33,15 @@ pub trait ChainRepositoryTrait {
 
     async fn find_chain_parameters(&self) -> Result<ParametersDb, String>;
 
+    async fn find_epoch(&self, epoch: i32) -> Result<Option<EpochDb>, String>;
+
+    /// Gets the latest epochs whose first block timestamp is known, latest
+    /// first
+    async fn find_latest_epochs(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<EpochDb>, String>;
+
     async fn get_state(&self) -> Result<ChainCrawlerStateDb, String>;
 
     async fn find_tokens(
@@ -40,6 +53,26 @@ pub trait ChainRepositoryTrait {
         address: String,
         epoch: Option<i32>,
     ) -> Result<Option<TokenSuppliesDb>, String>;
+
+    /// Gets the supplies of a token, latest epoch first
+    async fn find_token_supply_history(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<TokenSuppliesDb>, String>;
+
+    async fn find_token_supplies_between(
+        &self,
+        address: String,
+        from_epoch: i32,
+        to_epoch: i32,
+    ) -> Result<Vec<TokenSuppliesDb>, String>;
+
+    /// Gets the native tokens minted per epoch, latest epoch first
+    async fn find_native_inflation(
+        &self,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<NativeInflationDb>, String>;
 }
 
 #[async_trait]
@@ -97,6 +130,40 @@ impl ChainRepositoryTrait for ChainRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_epoch(&self, epoch: i32) -> Result<Option<EpochDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            epochs::table
+                .find(epoch)
+                .select(EpochDb::as_select())
+                .first(conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_latest_epochs(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<EpochDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            epochs::table
+                .filter(epochs::timestamp.is_not_null())
+                .order(epochs::epoch.desc())
+                .limit(limit)
+                .select(EpochDb::as_select())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_chain_parameters(&self) -> Result<ParametersDb, String> {
         let conn = self.app_state.get_db_connection().await;
 
@@ -173,4 +240,63 @@ impl ChainRepositoryTrait for ChainRepository {
         .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())
     }
+
+    async fn find_token_supply_history(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<TokenSuppliesDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            token_supplies_per_epoch::table
+                .filter(token_supplies_per_epoch::dsl::address.eq(address))
+                .order(token_supplies_per_epoch::dsl::epoch.desc())
+                .select(TokenSuppliesDb::as_select())
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_token_supplies_between(
+        &self,
+        address: String,
+        from_epoch: i32,
+        to_epoch: i32,
+    ) -> Result<Vec<TokenSuppliesDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            token_supplies_per_epoch::table
+                .filter(token_supplies_per_epoch::dsl::address.eq(address))
+                .filter(token_supplies_per_epoch::dsl::epoch.ge(from_epoch))
+                .filter(token_supplies_per_epoch::dsl::epoch.le(to_epoch))
+                .select(TokenSuppliesDb::as_select())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_native_inflation(
+        &self,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<NativeInflationDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            native_inflation_per_epoch::table
+                .order(native_inflation_per_epoch::dsl::epoch.desc())
+                .select(NativeInflationDb::as_select())
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
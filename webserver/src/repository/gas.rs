@@ -1,16 +1,38 @@
 use async_trait::async_trait;
 use bigdecimal::BigDecimal;
-use diesel::dsl::{avg, count, max, min};
+use diesel::dsl::{avg, count, exists, max, min, not};
 use diesel::sql_types::{BigInt, Integer, Nullable, Numeric};
 use diesel::{
-    ExpressionMethods, IntoSql, JoinOnDsl, QueryDsl, RunQueryDsl,
-    SelectableHelper,
+    ExpressionMethods, IntoSql, JoinOnDsl, NullableExpressionMethods, QueryDsl,
+    RunQueryDsl, SelectableHelper,
 };
-use orm::gas::GasPriceDb;
-use orm::schema::{gas_estimations, gas_price, wrapper_transactions};
+use orm::gas::{GasEstimationDb, GasPriceDb};
+use orm::schema::{
+    gas_estimations, gas_price, inner_transactions, wrapper_transactions,
+};
+use orm::transactions::{TransactionKindDb, TransactionResultDb};
 
 use crate::appstate::AppState;
 
+/// Inner transaction kinds counted by the gas model, see
+/// `GAS_MODEL_TX_KINDS`
+const GAS_MODEL_TX_KINDS_DB: [TransactionKindDb; 14] = [
+    TransactionKindDb::TransparentTransfer,
+    TransactionKindDb::ShieldedTransfer,
+    TransactionKindDb::ShieldingTransfer,
+    TransactionKindDb::UnshieldingTransfer,
+    TransactionKindDb::IbcTransparentTransfer,
+    TransactionKindDb::IbcShieldingTransfer,
+    TransactionKindDb::IbcUnshieldingTransfer,
+    TransactionKindDb::Bond,
+    TransactionKindDb::Redelegation,
+    TransactionKindDb::Unbond,
+    TransactionKindDb::Withdraw,
+    TransactionKindDb::ClaimRewards,
+    TransactionKindDb::VoteProposal,
+    TransactionKindDb::RevealPk,
+];
+
 #[derive(Clone)]
 pub struct GasRepository {
     pub(crate) app_state: AppState,
@@ -47,6 +69,14 @@ pub trait GasRepositoryTrait {
         signatures: u64,
         tx_size: u64,
     ) -> Result<(Option<i32>, Option<i32>, Option<BigDecimal>, i64), String>;
+
+    /// Gets the batch composition and gas used of the most recent applied
+    /// wrapper transactions, skipping the ones with inner transactions the
+    /// gas model has no regressor for
+    async fn find_gas_model_samples(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<(GasEstimationDb, i32)>, String>;
 }
 
 #[async_trait]
@@ -191,4 +221,46 @@ impl GasRepositoryTrait for GasRepository {
         .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())
     }
+
+    async fn find_gas_model_samples(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<(GasEstimationDb, i32)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            gas_estimations::table
+                .inner_join(
+                    wrapper_transactions::table
+                        .on(gas_estimations::dsl::wrapper_id
+                            .eq(wrapper_transactions::dsl::id)),
+                )
+                .filter(
+                    wrapper_transactions::dsl::exit_code
+                        .eq(TransactionResultDb::Applied),
+                )
+                .filter(wrapper_transactions::dsl::gas_used.is_not_null())
+                .filter(not(exists(
+                    inner_transactions::table
+                        .filter(
+                            inner_transactions::dsl::wrapper_id
+                                .eq(gas_estimations::dsl::wrapper_id),
+                        )
+                        .filter(
+                            inner_transactions::dsl::kind
+                                .ne_all(GAS_MODEL_TX_KINDS_DB),
+                        ),
+                )))
+                .order(wrapper_transactions::dsl::block_height.desc())
+                .limit(limit)
+                .select((
+                    GasEstimationDb::as_select(),
+                    wrapper_transactions::dsl::gas_used.assume_not_null(),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
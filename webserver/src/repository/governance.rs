@@ -1,20 +1,91 @@
 use async_trait::async_trait;
-use diesel::dsl::{IntoBoxed, exists};
+use bigdecimal::BigDecimal;
+use diesel::dsl::{AsExprOf, IntoBoxed, count_star, exists, sql};
+use diesel::expression::{SqlLiteral, UncheckedBind};
 use diesel::pg::Pg;
+use diesel::sql_types::{Bool, Float, Text};
 use diesel::{
-    BoolExpressionMethods, ExpressionMethods, OptionalExtension,
-    PgTextExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, select,
+    BoolExpressionMethods, ExpressionMethods, JoinOnDsl,
+    NullableExpressionMethods, OptionalExtension, PgConnection,
+    PgSortExpressionMethods, PgTextExpressionMethods, QueryDsl, QueryResult,
+    RunQueryDsl, SelectableHelper, select,
 };
 use orm::governance_proposal::{
-    GovernanceProposalData, GovernanceProposalKindDb,
+    GovernanceProposalData, GovernanceProposalDb, GovernanceProposalKindDb,
     GovernanceProposalNoDataDb, GovernanceProposalResultDb,
 };
-use orm::governance_votes::GovernanceProposalVoteDb;
-use orm::schema::{governance_proposals, governance_votes};
+use orm::governance_votes::{GovernanceProposalVoteDb, GovernanceVoteKindDb};
+use orm::schema::{
+    blocks, bonds, governance_proposals, governance_votes, unbonds, validators,
+};
 
 use crate::appstate::AppState;
 use crate::repository::utils::{Paginate, PaginatedResponseDb};
 
+/// Weighted document the proposals are searched in, it has to match the
+/// expression of `index_governance_proposals_search`
+const PROPOSAL_SEARCH_DOCUMENT: &str =
+    "setweight(to_tsvector('english', COALESCE(title, '')), 'A') || \
+     setweight(to_tsvector('english', COALESCE(abstract, '')), 'B') || \
+     setweight(to_tsvector('english', COALESCE(authors, '')), 'C') || \
+     setweight(to_tsvector('english', content), 'D')";
+
+/// Whether the validator was active, i.e. in the consensus or below capacity
+/// set, at the given epoch expression. Epochs before the validator state
+/// history was recorded fall back to the given condition.
+fn validator_active_at_epoch(epoch: &str, fallback: &str) -> String {
+    format!(
+        "(EXISTS (SELECT 1 FROM validator_state_history WHERE \
+         validator_state_history.validator_id = validators.id AND \
+         validator_state_history.epoch = {epoch} AND \
+         validator_state_history.state IN ('consensus', 'below_capacity')) OR \
+         (NOT EXISTS (SELECT 1 FROM validator_state_history WHERE \
+         validator_state_history.epoch = {epoch}) AND {fallback}))"
+    )
+}
+
+/// Whether the validator was active at the start of the voting period of the
+/// proposal. Epochs before the validator state history was recorded fall back
+/// to the validator having stake bonded by then, so that validators created
+/// later are not eligible.
+fn validator_active_at_proposal_start() -> String {
+    validator_active_at_epoch(
+        "governance_proposals.start_epoch",
+        "EXISTS (SELECT 1 FROM bonds WHERE bonds.validator_id = validators.id \
+         AND bonds.start <= governance_proposals.start_epoch)",
+    )
+}
+
+/// Whether the voter had stake bonded at the start of the voting period of
+/// the proposal
+fn voter_bonded_at_proposal_start(
+    voter_address: String,
+) -> SqlLiteral<Bool, UncheckedBind<SqlLiteral<Bool>, AsExprOf<String, Text>>> {
+    sql::<Bool>("EXISTS (SELECT 1 FROM bonds WHERE bonds.address = ")
+        .bind::<Text, _>(voter_address)
+        .sql(" AND bonds.start <= governance_proposals.start_epoch)")
+}
+
+/// Counts, per validator, the proposals whose voting period ended before the
+/// given epoch and started while the validator was active
+fn validator_eligible_proposal_counts(
+    conn: &mut PgConnection,
+    epoch: i32,
+) -> QueryResult<Vec<(String, i64)>> {
+    validators::table
+        .inner_join(
+            governance_proposals::table
+                .on(governance_proposals::end_epoch.lt(epoch)),
+        )
+        .filter(sql::<Bool>(&validator_active_at_proposal_start()))
+        .group_by(validators::namada_address)
+        .select((validators::namada_address, count_star()))
+        .load(conn)
+}
+
+pub type ProposalSearchResultDb =
+    (GovernanceProposalNoDataDb, f32, String, String);
+
 #[derive(Clone)]
 pub struct GovernanceRepo {
     pub(crate) app_state: AppState,
@@ -47,6 +118,12 @@ pub trait GovernanceRepoTrait {
         proposal_id: i32,
     ) -> Result<Option<GovernanceProposalNoDataDb>, String>;
 
+    async fn search_governance_proposals(
+        &self,
+        query: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<ProposalSearchResultDb>, String>;
+
     async fn find_governance_proposal_votes(
         &self,
         proposal_id: i32,
@@ -63,6 +140,51 @@ pub trait GovernanceRepoTrait {
         &self,
         voter_address: String,
     ) -> Result<Vec<GovernanceProposalVoteDb>, String>;
+
+    async fn find_governance_proposal_with_data_by_id(
+        &self,
+        proposal_id: i32,
+    ) -> Result<Option<GovernanceProposalDb>, String>;
+
+    async fn find_governance_proposal_vote_counts_by_epoch(
+        &self,
+        proposal_id: i32,
+    ) -> Result<Vec<(Option<i32>, GovernanceVoteKindDb, i64)>, String>;
+
+    async fn find_all_governance_proposal_votes(
+        &self,
+        proposal_id: i32,
+    ) -> Result<Vec<GovernanceProposalVoteDb>, String>;
+
+    async fn find_active_stakes_at_epoch(
+        &self,
+        epoch: i32,
+        unbonding_length: i32,
+    ) -> Result<Vec<(String, String, BigDecimal)>, String>;
+
+    async fn count_voter_eligible_proposals_ended_before(
+        &self,
+        voter_address: String,
+        epoch: i32,
+    ) -> Result<i64, String>;
+
+    /// Counts, for each validator, the proposals whose voting period ended
+    /// before the given epoch and started while the validator was active
+    async fn find_validator_eligible_proposal_counts(
+        &self,
+        epoch: i32,
+    ) -> Result<Vec<(String, i64)>, String>;
+
+    async fn find_validator_vote_counts_ended_before(
+        &self,
+        epoch: i32,
+    ) -> Result<Vec<(String, GovernanceVoteKindDb, i64)>, String>;
+
+    async fn find_voter_vote_counts_ended_before(
+        &self,
+        voter_address: String,
+        epoch: i32,
+    ) -> Result<Vec<(GovernanceVoteKindDb, i64)>, String>;
 }
 
 #[async_trait]
@@ -93,6 +215,80 @@ impl GovernanceRepoTrait for GovernanceRepo {
         .map_err(|e| e.to_string())
     }
 
+    /// Full-text search of the proposals content, returning the rank of each
+    /// match and the highlighted title and abstract
+    async fn search_governance_proposals(
+        &self,
+        query: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<ProposalSearchResultDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let rank = || {
+                sql::<Float>(&format!(
+                    "ts_rank({}, websearch_to_tsquery('english', ",
+                    PROPOSAL_SEARCH_DOCUMENT
+                ))
+                .bind::<Text, _>(query.clone())
+                .sql("))")
+            };
+
+            governance_proposals::table
+                .filter(
+                    sql::<Bool>(&format!(
+                        "{} @@ websearch_to_tsquery('english', ",
+                        PROPOSAL_SEARCH_DOCUMENT
+                    ))
+                    .bind::<Text, _>(query.clone())
+                    .sql(")"),
+                )
+                .select((
+                    governance_proposals::id,
+                    rank(),
+                    sql::<Text>(
+                        "ts_headline('english', COALESCE(title, ''), \
+                         websearch_to_tsquery('english', ",
+                    )
+                    .bind::<Text, _>(query.clone())
+                    .sql(")) AS title_highlight"),
+                    sql::<Text>(
+                        "ts_headline('english', COALESCE(abstract, content), \
+                         websearch_to_tsquery('english', ",
+                    )
+                    .bind::<Text, _>(query.clone())
+                    .sql("), 'MaxFragments=2') AS abstract_highlight"),
+                ))
+                .order((rank().desc(), governance_proposals::id.desc()))
+                .paginate(page)
+                .load_and_count_pages::<(i32, f32, String, String)>(conn)
+                .and_then(|(matches, total_pages, total_items)| {
+                    let ids = matches.iter().map(|m| m.0).collect::<Vec<_>>();
+                    let proposals = governance_proposals::table
+                        .filter(governance_proposals::id.eq_any(ids))
+                        .select(GovernanceProposalNoDataDb::as_select())
+                        .load(conn)?;
+
+                    let results = matches
+                        .into_iter()
+                        .filter_map(|(id, rank, title, r#abstract)| {
+                            proposals
+                                .iter()
+                                .find(|proposal| proposal.id == id)
+                                .map(|proposal| {
+                                    (proposal.clone(), rank, title, r#abstract)
+                                })
+                        })
+                        .collect();
+
+                    Ok((results, total_pages, total_items))
+                })
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn governance_proposal_exists(
         &self,
         proposal_id: i32,
@@ -201,6 +397,210 @@ impl GovernanceRepoTrait for GovernanceRepo {
         .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())
     }
+
+    async fn find_governance_proposal_with_data_by_id(
+        &self,
+        proposal_id: i32,
+    ) -> Result<Option<GovernanceProposalDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            governance_proposals::table
+                .find(proposal_id)
+                .select(GovernanceProposalDb::as_select())
+                .first(conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    /// Counts the votes of a proposal grouped by the epoch of the block they
+    /// were cast in. Votes indexed without a height are grouped under `None`.
+    async fn find_governance_proposal_vote_counts_by_epoch(
+        &self,
+        proposal_id: i32,
+    ) -> Result<Vec<(Option<i32>, GovernanceVoteKindDb, i64)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            governance_votes::table
+                .left_join(
+                    blocks::table.on(blocks::height
+                        .nullable()
+                        .eq(governance_votes::block_height)),
+                )
+                .filter(governance_votes::proposal_id.eq(proposal_id))
+                .group_by((blocks::epoch, governance_votes::kind))
+                .select((
+                    blocks::epoch.nullable(),
+                    governance_votes::kind,
+                    count_star(),
+                ))
+                .order(blocks::epoch.asc().nulls_first())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_all_governance_proposal_votes(
+        &self,
+        proposal_id: i32,
+    ) -> Result<Vec<GovernanceProposalVoteDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            governance_votes::table
+                .filter(governance_votes::dsl::proposal_id.eq(proposal_id))
+                .select(GovernanceProposalVoteDb::as_select())
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    /// Returns the (delegator, validator, raw amount) stakes that were bonded
+    /// to an active validator at the given epoch. Bonds that were unbonded
+    /// after the epoch are still part of the stake, as long as they have not
+    /// been withdrawn. Epochs before the validator state history was recorded
+    /// fall back to the current validator state.
+    async fn find_active_stakes_at_epoch(
+        &self,
+        epoch: i32,
+        unbonding_length: i32,
+    ) -> Result<Vec<(String, String, BigDecimal)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let active_at_epoch = validator_active_at_epoch(
+                &epoch.to_string(),
+                "validators.state IN ('consensus', 'below_capacity')",
+            );
+
+            let bonded = bonds::table
+                .inner_join(validators::table)
+                .filter(bonds::start.le(epoch))
+                .filter(sql::<Bool>(&active_at_epoch))
+                .select((
+                    bonds::address,
+                    validators::namada_address,
+                    bonds::raw_amount,
+                ))
+                .load::<(String, String, BigDecimal)>(conn)?;
+
+            let unbonding = unbonds::table
+                .inner_join(validators::table)
+                .filter(unbonds::withdraw_epoch.gt(epoch + unbonding_length))
+                .filter(unbonds::withdrawn_at_height.is_null())
+                .filter(sql::<Bool>(&active_at_epoch))
+                .select((
+                    unbonds::address,
+                    validators::namada_address,
+                    unbonds::raw_amount,
+                ))
+                .load::<(String, String, BigDecimal)>(conn)?;
+
+            Ok::<_, diesel::result::Error>([bonded, unbonding].concat())
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    /// Counts the proposals whose voting period ended before the given epoch
+    /// and started while the voter had stake bonded
+    async fn count_voter_eligible_proposals_ended_before(
+        &self,
+        voter_address: String,
+        epoch: i32,
+    ) -> Result<i64, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            governance_proposals::table
+                .filter(governance_proposals::end_epoch.lt(epoch))
+                .filter(voter_bonded_at_proposal_start(voter_address))
+                .count()
+                .get_result(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_validator_eligible_proposal_counts(
+        &self,
+        epoch: i32,
+    ) -> Result<Vec<(String, i64)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            validator_eligible_proposal_counts(conn, epoch)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    /// Counts the votes cast by validators, by kind, on the proposals whose
+    /// voting period ended before the given epoch and started while the
+    /// validator was active
+    async fn find_validator_vote_counts_ended_before(
+        &self,
+        epoch: i32,
+    ) -> Result<Vec<(String, GovernanceVoteKindDb, i64)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            governance_votes::table
+                .inner_join(governance_proposals::table)
+                .inner_join(
+                    validators::table.on(validators::namada_address
+                        .eq(governance_votes::voter_address)),
+                )
+                .filter(governance_proposals::end_epoch.lt(epoch))
+                .filter(sql::<Bool>(&validator_active_at_proposal_start()))
+                .group_by((
+                    governance_votes::voter_address,
+                    governance_votes::kind,
+                ))
+                .select((
+                    governance_votes::voter_address,
+                    governance_votes::kind,
+                    count_star(),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_voter_vote_counts_ended_before(
+        &self,
+        voter_address: String,
+        epoch: i32,
+    ) -> Result<Vec<(GovernanceVoteKindDb, i64)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            governance_votes::table
+                .inner_join(governance_proposals::table)
+                .filter(governance_proposals::end_epoch.lt(epoch))
+                .filter(voter_bonded_at_proposal_start(voter_address.clone()))
+                .filter(governance_votes::voter_address.eq(voter_address))
+                .group_by(governance_votes::kind)
+                .select((governance_votes::kind, count_star()))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
 
 #[allow(clippy::needless_lifetimes)]
@@ -232,3 +632,77 @@ impl<'a> GovernanceRepo {
         query
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+    use orm::bond::BondInsertDb;
+    use orm::governance_proposal::GovernanceProposalInsertDb;
+    use orm::validators::{ValidatorDb, ValidatorInsertDb};
+    use shared::balance::Amount;
+    use shared::pos::Bond;
+    use shared::proposal::{GovernanceProposal, TallyType};
+    use shared::validator::Validator;
+    use test_helpers::db::TestDb;
+
+    use super::*;
+
+    /// Test that a validator created after the voting period of a proposal
+    /// started is not eligible to vote on it.
+    #[tokio::test]
+    async fn test_late_validator_is_not_eligible() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let early_validator = Validator::fake();
+            let late_validator = Validator::fake();
+
+            seed_validator_with_bond(conn, early_validator.clone(), 2)?;
+            seed_validator_with_bond(conn, late_validator.clone(), 12)?;
+
+            diesel::insert_into(governance_proposals::table)
+                .values(GovernanceProposalInsertDb::from_governance_proposal(
+                    GovernanceProposal {
+                        voting_start_epoch: 10,
+                        voting_end_epoch: 15,
+                        ..GovernanceProposal::fake(0)
+                    },
+                    TallyType::TwoFifths,
+                ))
+                .execute(conn)
+                .context("Failed to insert proposal")?;
+
+            let counts = validator_eligible_proposal_counts(conn, 20)?;
+
+            assert_eq!(counts, vec![(early_validator.address.to_string(), 1)]);
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
+    fn seed_validator_with_bond(
+        conn: &mut PgConnection,
+        validator: Validator,
+        start: u32,
+    ) -> anyhow::Result<()> {
+        let validator_db: ValidatorDb = diesel::insert_into(validators::table)
+            .values(ValidatorInsertDb::from_validator(validator.clone()))
+            .get_result(conn)
+            .context("Failed to insert validator")?;
+
+        let bond = Bond {
+            source: validator.address.clone(),
+            target: validator.address,
+            amount: Amount::fake(),
+            start,
+        };
+        diesel::insert_into(bonds::table)
+            .values(BondInsertDb::from_bond(bond, validator_db.id))
+            .execute(conn)
+            .context("Failed to insert bond")?;
+
+        anyhow::Ok(())
+    }
+}
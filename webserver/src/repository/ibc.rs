@@ -1,13 +1,39 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
 use diesel::{
-    ExpressionMethods, NullableExpressionMethods, QueryDsl, RunQueryDsl,
-    SelectableHelper,
+    BoolExpressionMethods, ExpressionMethods, JoinOnDsl,
+    NullableExpressionMethods, OptionalExtension, PgSortExpressionMethods,
+    QueryDsl, RunQueryDsl, SelectableHelper,
+};
+use orm::ibc::{
+    IbcAckDb, IbcAckStatusDb, IbcChannelDb, IbcChannelStateDb, IbcClientDb,
+    IbcConnectionDb,
+};
+use orm::schema::{
+    blocks, ibc_ack, ibc_channels, ibc_clients, ibc_connections,
+    ibc_rate_limits, ibc_token, ibc_token_flows,
 };
-use orm::ibc::IbcAckDb;
-use orm::schema::{ibc_ack, ibc_rate_limits, ibc_token_flows};
 
 use crate::appstate::AppState;
+use crate::dto::ibc::IbcTransferStatusDto;
+use crate::repository::utils::{Paginate, PaginatedResponseDb};
+
+/// Port, channel, namada address of the ibc token if any, packet denom and
+/// amount sent through a channel
+pub type ChannelOutflowDb = (
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<BigDecimal>,
+);
+
+/// Epoch, throughput limit, deposited and withdrawn amounts of a token
+pub type RateLimitUsageDb =
+    (i32, BigDecimal, Option<BigDecimal>, Option<BigDecimal>);
 
 #[derive(Clone)]
 pub struct IbcRepository {
@@ -23,6 +49,51 @@ pub trait IbcRepositoryTrait {
         id: String,
     ) -> Result<Option<IbcAckDb>, String>;
 
+    async fn find_ibc_transfers(
+        &self,
+        sender: Option<String>,
+        receiver: Option<String>,
+        channel: Option<String>,
+        status: Option<IbcTransferStatusDto>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<IbcAckDb>, String>;
+
+    async fn find_ibc_pending_transfers(
+        &self,
+        sent_before: Option<NaiveDateTime>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<(IbcAckDb, Option<NaiveDateTime>)>, String>;
+
+    async fn count_ibc_stuck_transfers(&self) -> Result<i64, String>;
+
+    async fn find_ibc_channels(
+        &self,
+        state: Option<IbcChannelStateDb>,
+        channel_id: Option<String>,
+    ) -> Result<
+        Vec<(IbcChannelDb, Option<IbcConnectionDb>, Option<IbcClientDb>)>,
+        String,
+    >;
+
+    async fn find_rate_limit_usage(
+        &self,
+        token: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<RateLimitUsageDb>, String>;
+
+    async fn find_latest_rate_limit_usage(
+        &self,
+        token: String,
+    ) -> Result<Option<RateLimitUsageDb>, String>;
+
+    async fn get_ibc_trace_flows(
+        &self,
+    ) -> Result<Vec<(String, String, Option<BigDecimal>)>, String>;
+
+    async fn get_ibc_channel_outflows(
+        &self,
+    ) -> Result<Vec<ChannelOutflowDb>, String>;
+
     async fn get_throughput_limits(
         &self,
         token_address: Option<String>,
@@ -63,6 +134,319 @@ impl IbcRepositoryTrait for IbcRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_ibc_transfers(
+        &self,
+        sender: Option<String>,
+        receiver: Option<String>,
+        channel: Option<String>,
+        status: Option<IbcTransferStatusDto>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<IbcAckDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = ibc_ack::table.into_boxed();
+
+            if let Some(sender) = sender {
+                query = query.filter(ibc_ack::dsl::sender.eq(sender));
+            }
+
+            if let Some(receiver) = receiver {
+                query = query.filter(ibc_ack::dsl::receiver.eq(receiver));
+            }
+
+            if let Some(channel) = channel {
+                query = query.filter(ibc_ack::dsl::source_channel.eq(channel));
+            }
+
+            query = match status {
+                Some(IbcTransferStatusDto::Pending) => query.filter(
+                    ibc_ack::dsl::status
+                        .eq(IbcAckStatusDb::Unknown)
+                        .and(ibc_ack::dsl::ack_tx_hash.is_null())
+                        .and(ibc_ack::dsl::stuck.eq(false)),
+                ),
+                Some(IbcTransferStatusDto::Stuck) => {
+                    query.filter(ibc_ack::dsl::stuck.eq(true))
+                }
+                Some(IbcTransferStatusDto::Completed) => query
+                    .filter(ibc_ack::dsl::status.eq(IbcAckStatusDb::Success)),
+                Some(IbcTransferStatusDto::Refunded) => {
+                    query.filter(ibc_ack::dsl::status.eq_any(vec![
+                        IbcAckStatusDb::Fail,
+                        IbcAckStatusDb::Timeout,
+                    ]))
+                }
+                None => query,
+            };
+
+            query
+                .order((
+                    ibc_ack::dsl::send_height.desc().nulls_last(),
+                    ibc_ack::dsl::sequence.desc(),
+                ))
+                .select(IbcAckDb::as_select())
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_ibc_pending_transfers(
+        &self,
+        sent_before: Option<NaiveDateTime>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<(IbcAckDb, Option<NaiveDateTime>)>, String>
+    {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = ibc_ack::table
+                .left_join(
+                    blocks::table.on(ibc_ack::dsl::send_height
+                        .eq(blocks::dsl::height.nullable())),
+                )
+                .filter(ibc_ack::dsl::status.eq(IbcAckStatusDb::Unknown))
+                .filter(ibc_ack::dsl::ack_tx_hash.is_null())
+                .into_boxed();
+
+            if let Some(sent_before) = sent_before {
+                query = query.filter(blocks::dsl::timestamp.lt(sent_before));
+            }
+
+            let (pending, total_pages, total_items) = query
+                .order((
+                    ibc_ack::dsl::send_height.asc().nulls_first(),
+                    ibc_ack::dsl::sequence.asc(),
+                ))
+                .select((ibc_ack::dsl::id, blocks::dsl::timestamp.nullable()))
+                .paginate(page)
+                .load_and_count_pages::<(String, Option<NaiveDateTime>)>(
+                    conn,
+                )?;
+
+            // Paginate can not load a nested selectable, so the transfers
+            // are loaded in a second step and put back in page order
+            let ids =
+                pending.iter().map(|(id, _)| id.clone()).collect::<Vec<_>>();
+            let mut transfers: HashMap<String, IbcAckDb> = ibc_ack::table
+                .filter(ibc_ack::dsl::id.eq_any(&ids))
+                .select(IbcAckDb::as_select())
+                .load(conn)?
+                .into_iter()
+                .map(|transfer| (transfer.id.clone(), transfer))
+                .collect();
+
+            let pending = pending
+                .into_iter()
+                .filter_map(|(id, sent_at)| {
+                    transfers.remove(&id).map(|transfer| (transfer, sent_at))
+                })
+                .collect();
+
+            Ok((pending, total_pages, total_items))
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e: diesel::result::Error| e.to_string())
+    }
+
+    async fn count_ibc_stuck_transfers(&self) -> Result<i64, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_ack::table
+                .filter(ibc_ack::dsl::stuck.eq(true))
+                .count()
+                .get_result(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_ibc_channels(
+        &self,
+        state: Option<IbcChannelStateDb>,
+        channel_id: Option<String>,
+    ) -> Result<
+        Vec<(IbcChannelDb, Option<IbcConnectionDb>, Option<IbcClientDb>)>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = ibc_channels::table
+                .left_join(
+                    ibc_connections::table.on(ibc_channels::dsl::connection_id
+                        .eq(ibc_connections::dsl::connection_id.nullable())),
+                )
+                .left_join(
+                    ibc_clients::table.on(ibc_connections::dsl::client_id
+                        .eq(ibc_clients::dsl::client_id)),
+                )
+                .into_boxed();
+
+            if let Some(state) = state {
+                query = query.filter(ibc_channels::dsl::state.eq(state));
+            }
+
+            if let Some(channel_id) = channel_id {
+                query =
+                    query.filter(ibc_channels::dsl::channel_id.eq(channel_id));
+            }
+
+            query
+                .order((
+                    ibc_channels::dsl::port_id.asc(),
+                    ibc_channels::dsl::channel_id.asc(),
+                ))
+                .select((
+                    IbcChannelDb::as_select(),
+                    Option::<IbcConnectionDb>::as_select(),
+                    Option::<IbcClientDb>::as_select(),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_rate_limit_usage(
+        &self,
+        token: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<RateLimitUsageDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_rate_limits::table
+                .left_join(
+                    ibc_token_flows::table.on(ibc_token_flows::dsl::address
+                        .eq(ibc_rate_limits::dsl::address)
+                        .and(
+                            ibc_token_flows::dsl::epoch
+                                .eq(ibc_rate_limits::dsl::epoch),
+                        )),
+                )
+                .filter(ibc_rate_limits::dsl::address.eq(token))
+                .order(ibc_rate_limits::dsl::epoch.desc())
+                .select((
+                    ibc_rate_limits::dsl::epoch,
+                    ibc_rate_limits::dsl::throughput_limit,
+                    ibc_token_flows::dsl::deposit.nullable(),
+                    ibc_token_flows::dsl::withdraw.nullable(),
+                ))
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_latest_rate_limit_usage(
+        &self,
+        token: String,
+    ) -> Result<Option<RateLimitUsageDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_rate_limits::table
+                .left_join(
+                    ibc_token_flows::table.on(ibc_token_flows::dsl::address
+                        .eq(ibc_rate_limits::dsl::address)
+                        .and(
+                            ibc_token_flows::dsl::epoch
+                                .eq(ibc_rate_limits::dsl::epoch),
+                        )),
+                )
+                .filter(ibc_rate_limits::dsl::address.eq(token))
+                .order(ibc_rate_limits::dsl::epoch.desc())
+                .select((
+                    ibc_rate_limits::dsl::epoch,
+                    ibc_rate_limits::dsl::throughput_limit,
+                    ibc_token_flows::dsl::deposit.nullable(),
+                    ibc_token_flows::dsl::withdraw.nullable(),
+                ))
+                .first(conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    /// Returns the (trace, address, deposited amount) of each ibc token
+    async fn get_ibc_trace_flows(
+        &self,
+    ) -> Result<Vec<(String, String, Option<BigDecimal>)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_token_flows::table
+                .inner_join(ibc_token::table.on(
+                    ibc_token_flows::dsl::address.eq(ibc_token::dsl::address),
+                ))
+                .group_by((ibc_token::dsl::ibc_trace, ibc_token::dsl::address))
+                .select((
+                    ibc_token::dsl::ibc_trace,
+                    ibc_token::dsl::address,
+                    diesel::dsl::sum(ibc_token_flows::dsl::deposit),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    /// Returns the amount of each token sent through each source channel,
+    /// leaving out the transfers that timed out or failed, as they were
+    /// refunded. Tokens that came in over ibc are sent with their trace as
+    /// denom, which is matched back to their namada address.
+    async fn get_ibc_channel_outflows(
+        &self,
+    ) -> Result<Vec<ChannelOutflowDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_ack::table
+                .left_join(
+                    ibc_token::table.on(ibc_token::dsl::ibc_trace
+                        .nullable()
+                        .eq(ibc_ack::dsl::denom)),
+                )
+                .filter(ibc_ack::dsl::denom.is_not_null())
+                .filter(
+                    ibc_ack::dsl::status.ne_all([
+                        IbcAckStatusDb::Timeout,
+                        IbcAckStatusDb::Fail,
+                    ]),
+                )
+                .group_by((
+                    ibc_ack::dsl::source_port,
+                    ibc_ack::dsl::source_channel,
+                    ibc_token::dsl::address,
+                    ibc_ack::dsl::denom,
+                ))
+                .select((
+                    ibc_ack::dsl::source_port,
+                    ibc_ack::dsl::source_channel,
+                    ibc_token::dsl::address.nullable(),
+                    ibc_ack::dsl::denom,
+                    diesel::dsl::sum(ibc_ack::dsl::amount),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn get_throughput_limits(
         &self,
         matching_token_address: Option<String>,
@@ -1,9 +1,44 @@
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
-use orm::masp::{MaspPoolDb, MaspRewardDataDb};
-use orm::schema::{masp_pool_aggregate, masp_rates};
+use bigdecimal::BigDecimal;
+use diesel::dsl::{sql, sum};
+use diesel::sql_types::{BigInt, Bool};
+use diesel::{
+    ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+};
+use orm::masp::{
+    MaspCommitmentTreeDb, MaspPoolDb, MaspRewardDataDb, MaspRewardHistoryDb,
+};
+use orm::schema::{
+    blocks, inner_transactions, masp_commitment_tree_per_epoch,
+    masp_pool_aggregate, masp_pool_flows, masp_rates, masp_rates_per_epoch,
+    wrapper_transactions,
+};
+use orm::transactions::{TransactionKindDb, TransactionResultDb};
 
 use crate::appstate::AppState;
+use crate::dto::masp::MaspPoolSeriesIntervalDto;
+use crate::repository::utils::{Paginate, PaginatedResponseDb};
+
+/// Transactions that touch the shielded pool
+const MASP_TX_KINDS: [TransactionKindDb; 6] = [
+    TransactionKindDb::ShieldedTransfer,
+    TransactionKindDb::ShieldingTransfer,
+    TransactionKindDb::UnshieldingTransfer,
+    TransactionKindDb::MixedTransfer,
+    TransactionKindDb::IbcShieldingTransfer,
+    TransactionKindDb::IbcUnshieldingTransfer,
+];
+
+/// Epoch, number of masp txs of each kind in the order of `MASP_TX_KINDS`,
+/// number of wrappers containing masp txs and how many of them paid fees
+/// through the masp
+pub type MaspActivityDb = (Option<i32>, i64, i64, i64, i64, i64, i64, i64, i64);
+
+pub type MaspPoolSeriesDb = (
+    (Option<BigDecimal>, Option<BigDecimal>),
+    Vec<(i64, Option<BigDecimal>, Option<BigDecimal>)>,
+);
 
 #[derive(Clone)]
 pub struct MaspRepository {
@@ -22,6 +57,42 @@ pub trait MaspRepositoryTrait {
         &self,
         token: String,
     ) -> Result<Vec<MaspPoolDb>, String>;
+
+    /// Gets the inflows and outflows of the token before `from`, followed by
+    /// the inflows and outflows of every interval between `from` and `to`
+    async fn find_masp_pool_series(
+        &self,
+        token: String,
+        interval: MaspPoolSeriesIntervalDto,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> Result<MaspPoolSeriesDb, String>;
+
+    async fn find_rates_history(
+        &self,
+        token: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<MaspRewardHistoryDb>, String>;
+
+    async fn find_latest_rates_history(
+        &self,
+        token: String,
+    ) -> Result<Option<MaspRewardHistoryDb>, String>;
+
+    /// Gets the successful masp txs per epoch, most recent epoch first
+    async fn find_masp_activity(
+        &self,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<MaspActivityDb>, String>;
+
+    /// Counts the successful masp txs included before the given epoch
+    async fn count_masp_txs_before(&self, epoch: i32) -> Result<i64, String>;
+
+    async fn find_commitment_trees_between(
+        &self,
+        from: i32,
+        to: i32,
+    ) -> Result<Vec<MaspCommitmentTreeDb>, String>;
 }
 
 #[async_trait]
@@ -72,4 +143,197 @@ impl MaspRepositoryTrait for MaspRepository {
         .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())
     }
+
+    async fn find_masp_pool_series(
+        &self,
+        token: String,
+        interval: MaspPoolSeriesIntervalDto,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> Result<MaspPoolSeriesDb, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        // Start of the interval the flows belong to, as a unix timestamp or
+        // an epoch
+        let bucket = match interval {
+            MaspPoolSeriesIntervalDto::Hour => {
+                "EXTRACT(EPOCH FROM masp_pool_flows.timestamp)::BIGINT"
+            }
+            MaspPoolSeriesIntervalDto::Day => {
+                "EXTRACT(EPOCH FROM date_trunc('day', \
+                 masp_pool_flows.timestamp))::BIGINT"
+            }
+            MaspPoolSeriesIntervalDto::Epoch => "masp_pool_flows.epoch::BIGINT",
+        };
+
+        conn.interact(move |conn| {
+            let opening = match from {
+                Some(from) => masp_pool_flows::table
+                    .filter(masp_pool_flows::dsl::token_address.eq(&token))
+                    .filter(sql::<Bool>(&format!("{} < {}", bucket, from)))
+                    .select((
+                        sum(masp_pool_flows::dsl::inflow),
+                        sum(masp_pool_flows::dsl::outflow),
+                    ))
+                    .first(conn)?,
+                None => (None, None),
+            };
+
+            let mut query = masp_pool_flows::table
+                .filter(masp_pool_flows::dsl::token_address.eq(&token))
+                .group_by(sql::<BigInt>(bucket))
+                .select((
+                    sql::<BigInt>(bucket),
+                    sum(masp_pool_flows::dsl::inflow),
+                    sum(masp_pool_flows::dsl::outflow),
+                ))
+                .order(sql::<BigInt>(bucket).asc())
+                .into_boxed();
+
+            if let Some(from) = from {
+                query = query
+                    .filter(sql::<Bool>(&format!("{} >= {}", bucket, from)));
+            }
+            if let Some(to) = to {
+                query =
+                    query.filter(sql::<Bool>(&format!("{} <= {}", bucket, to)));
+            }
+
+            let series = query.load(conn)?;
+
+            Ok((opening, series))
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e: diesel::result::Error| e.to_string())
+    }
+
+    async fn find_rates_history(
+        &self,
+        token: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<MaspRewardHistoryDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            masp_rates_per_epoch::table
+                .filter(masp_rates_per_epoch::dsl::token.eq(token))
+                .order(masp_rates_per_epoch::dsl::epoch.desc())
+                .select(MaspRewardHistoryDb::as_select())
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_latest_rates_history(
+        &self,
+        token: String,
+    ) -> Result<Option<MaspRewardHistoryDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            masp_rates_per_epoch::table
+                .filter(masp_rates_per_epoch::dsl::token.eq(token))
+                .order(masp_rates_per_epoch::dsl::epoch.desc())
+                .select(MaspRewardHistoryDb::as_select())
+                .first(conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_masp_activity(
+        &self,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<MaspActivityDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        let count_kind = |kind: &str| {
+            sql::<BigInt>(&format!(
+                "COUNT(*) FILTER (WHERE inner_transactions.kind = '{}')",
+                kind
+            ))
+        };
+
+        conn.interact(move |conn| {
+            inner_transactions::table
+                .inner_join(
+                    wrapper_transactions::table.inner_join(blocks::table),
+                )
+                .filter(inner_transactions::dsl::kind.eq_any(MASP_TX_KINDS))
+                .filter(
+                    inner_transactions::dsl::exit_code
+                        .eq(TransactionResultDb::Applied),
+                )
+                .filter(blocks::dsl::epoch.is_not_null())
+                .group_by(blocks::dsl::epoch)
+                .select((
+                    blocks::dsl::epoch,
+                    count_kind("shielded_transfer"),
+                    count_kind("shielding_transfer"),
+                    count_kind("unshielding_transfer"),
+                    count_kind("mixed_transfer"),
+                    count_kind("ibc_shielding_transfer"),
+                    count_kind("ibc_unshielding_transfer"),
+                    sql::<BigInt>("COUNT(DISTINCT wrapper_transactions.id)"),
+                    sql::<BigInt>(
+                        "COUNT(DISTINCT wrapper_transactions.id) FILTER \
+                         (WHERE wrapper_transactions.masp_fee_payment IS NOT \
+                         NULL)",
+                    ),
+                ))
+                .order(blocks::dsl::epoch.desc())
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn count_masp_txs_before(&self, epoch: i32) -> Result<i64, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            inner_transactions::table
+                .inner_join(
+                    wrapper_transactions::table.inner_join(blocks::table),
+                )
+                .filter(inner_transactions::dsl::kind.eq_any(MASP_TX_KINDS))
+                .filter(
+                    inner_transactions::dsl::exit_code
+                        .eq(TransactionResultDb::Applied),
+                )
+                .filter(blocks::dsl::epoch.lt(epoch))
+                .count()
+                .get_result(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_commitment_trees_between(
+        &self,
+        from: i32,
+        to: i32,
+    ) -> Result<Vec<MaspCommitmentTreeDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            masp_commitment_tree_per_epoch::table
+                .filter(masp_commitment_tree_per_epoch::dsl::epoch.ge(from))
+                .filter(masp_commitment_tree_per_epoch::dsl::epoch.le(to))
+                .select(MaspCommitmentTreeDb::as_select())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
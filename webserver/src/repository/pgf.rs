@@ -1,7 +1,15 @@
 use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use diesel::dsl::{count_star, max, min, sum};
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
-use orm::pgf::PublicGoodFundingPaymentDb;
-use orm::schema::public_good_funding;
+use orm::pgf::{
+    PaymentRecurrenceDb, PgfScheduledPaymentDb, PgfStewardDb,
+    PublicGoodFundingPaymentDb,
+};
+use orm::schema::{
+    governance_proposals, pgf_scheduled_payments, pgf_stewards,
+    public_good_funding,
+};
 
 use super::utils::{Paginate, PaginatedResponseDb};
 use crate::appstate::AppState;
@@ -24,6 +32,53 @@ pub trait PgfRepoTrait {
         &self,
         proposal_id: i32,
     ) -> Result<Vec<PublicGoodFundingPaymentDb>, String>;
+
+    /// Gets the last recorded steward state of every address
+    async fn find_latest_steward_states(
+        &self,
+    ) -> Result<Vec<PgfStewardDb>, String>;
+
+    async fn find_steward_history(
+        &self,
+        address: Option<String>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<PgfStewardDb>, String>;
+
+    /// Gets the continuous payments along with the activation epoch of their
+    /// proposal
+    async fn find_active_continuous_payments(
+        &self,
+    ) -> Result<Vec<(PublicGoodFundingPaymentDb, i32)>, String>;
+
+    /// Gets the number of epochs each continuous payment was scheduled at and
+    /// the amount scheduled so far, by proposal and recipient
+    async fn find_continuous_scheduled_totals(
+        &self,
+    ) -> Result<Vec<(i32, String, i64, Option<BigDecimal>)>, String>;
+
+    async fn find_scheduled_payments(
+        &self,
+        receipient: Option<String>,
+        epoch: Option<i32>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<PgfScheduledPaymentDb>, String>;
+
+    /// Gets the amount scheduled to each recipient, the number of scheduled
+    /// payments and the first and last epoch they were scheduled at, largest
+    /// amount first
+    async fn find_recipient_totals(
+        &self,
+        page: i64,
+    ) -> Result<
+        PaginatedResponseDb<(
+            String,
+            Option<BigDecimal>,
+            i64,
+            Option<i32>,
+            Option<i32>,
+        )>,
+        String,
+    >;
 }
 
 #[async_trait]
@@ -66,4 +121,174 @@ impl PgfRepoTrait for PgfRepo {
         .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())
     }
+
+    async fn find_latest_steward_states(
+        &self,
+    ) -> Result<Vec<PgfStewardDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            pgf_stewards::table
+                .distinct_on(pgf_stewards::dsl::address)
+                .order((
+                    pgf_stewards::dsl::address,
+                    pgf_stewards::dsl::epoch.desc(),
+                ))
+                .select(PgfStewardDb::as_select())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_steward_history(
+        &self,
+        address: Option<String>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<PgfStewardDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = pgf_stewards::table
+                .select(PgfStewardDb::as_select())
+                .order((
+                    pgf_stewards::dsl::epoch.desc(),
+                    pgf_stewards::dsl::address.asc(),
+                ))
+                .into_boxed();
+
+            if let Some(address) = address {
+                query = query.filter(pgf_stewards::dsl::address.eq(address));
+            }
+
+            query.paginate(page).load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_active_continuous_payments(
+        &self,
+    ) -> Result<Vec<(PublicGoodFundingPaymentDb, i32)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            public_good_funding::table
+                .inner_join(governance_proposals::table)
+                .filter(
+                    public_good_funding::dsl::payment_recurrence
+                        .eq(PaymentRecurrenceDb::Continuous),
+                )
+                .order(public_good_funding::dsl::proposal_id.desc())
+                .select((
+                    PublicGoodFundingPaymentDb::as_select(),
+                    governance_proposals::dsl::activation_epoch,
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_continuous_scheduled_totals(
+        &self,
+    ) -> Result<Vec<(i32, String, i64, Option<BigDecimal>)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            pgf_scheduled_payments::table
+                .filter(
+                    pgf_scheduled_payments::dsl::payment_recurrence
+                        .eq(PaymentRecurrenceDb::Continuous),
+                )
+                .group_by((
+                    pgf_scheduled_payments::dsl::proposal_id,
+                    pgf_scheduled_payments::dsl::receipient,
+                ))
+                .select((
+                    pgf_scheduled_payments::dsl::proposal_id,
+                    pgf_scheduled_payments::dsl::receipient,
+                    count_star(),
+                    sum(pgf_scheduled_payments::dsl::amount),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_scheduled_payments(
+        &self,
+        receipient: Option<String>,
+        epoch: Option<i32>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<PgfScheduledPaymentDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = pgf_scheduled_payments::table
+                .select(PgfScheduledPaymentDb::as_select())
+                .order((
+                    pgf_scheduled_payments::dsl::epoch.desc(),
+                    pgf_scheduled_payments::dsl::id.desc(),
+                ))
+                .into_boxed();
+
+            if let Some(receipient) = receipient {
+                query = query.filter(
+                    pgf_scheduled_payments::dsl::receipient.eq(receipient),
+                );
+            }
+            if let Some(epoch) = epoch {
+                query =
+                    query.filter(pgf_scheduled_payments::dsl::epoch.eq(epoch));
+            }
+
+            query.paginate(page).load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_recipient_totals(
+        &self,
+        page: i64,
+    ) -> Result<
+        PaginatedResponseDb<(
+            String,
+            Option<BigDecimal>,
+            i64,
+            Option<i32>,
+            Option<i32>,
+        )>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            pgf_scheduled_payments::table
+                .group_by(pgf_scheduled_payments::dsl::receipient)
+                .select((
+                    pgf_scheduled_payments::dsl::receipient,
+                    sum(pgf_scheduled_payments::dsl::amount),
+                    count_star(),
+                    min(pgf_scheduled_payments::dsl::epoch),
+                    max(pgf_scheduled_payments::dsl::epoch),
+                ))
+                .order((
+                    sum(pgf_scheduled_payments::dsl::amount).desc(),
+                    pgf_scheduled_payments::dsl::receipient.asc(),
+                ))
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
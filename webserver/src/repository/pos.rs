@@ -1,25 +1,36 @@
 use async_trait::async_trait;
 use bigdecimal::BigDecimal;
-use diesel::dsl::{sql, sum};
+use chrono::NaiveDateTime;
+use diesel::dsl::{count_star, sql, sum};
 use diesel::sql_types::Integer;
 use diesel::{
     BoolExpressionMethods, ExpressionMethods, JoinOnDsl,
-    NullableExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+    NullableExpressionMethods, OptionalExtension, PgSortExpressionMethods,
+    QueryDsl, RunQueryDsl, SelectableHelper,
 };
 use orm::bond::BondDb;
 use orm::crawler_state::{CrawlerNameDb, EpochCrawlerStateDb};
 use orm::helpers::OrderByDb;
-use orm::pos_rewards::PoSRewardDb;
+use orm::pos_rewards::{PoSRewardDb, PosRewardClaimDb};
+use orm::pos_stats::PosStatsDb;
+use orm::redelegation::{RedelegationDb, RedelegationHistoryDb};
 use orm::schema::{
-    bonds, crawler_state, pos_rewards, redelegation, unbonds, validators,
+    blocks, bonds, crawler_state, pos_reward_claims, pos_rewards, pos_stats,
+    redelegation, redelegation_history, unbonds, validators,
 };
 use orm::unbond::UnbondDb;
 use orm::validators::{
     ValidatorDb, ValidatorSortByDb, ValidatorStateDb, validator_sort_by,
 };
+use orm::views::validator_delegators;
 
 use super::utils::{Paginate, PaginatedResponseDb};
 use crate::appstate::AppState;
+use crate::dto::pos::{DelegatorSortFieldDto, OrderByDto};
+
+/// Address, bonded amount, unbonding amount and first bond start epoch of a
+/// delegator
+pub type ValidatorDelegatorDb = (String, BigDecimal, BigDecimal, Option<i32>);
 
 #[derive(Clone)]
 pub struct PosRepository {
@@ -54,6 +65,50 @@ pub trait PosRepositoryTrait {
         address: String,
     ) -> Result<Option<ValidatorDb>, String>;
 
+    async fn find_redelegations_by_address(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<
+        PaginatedResponseDb<(RedelegationHistoryDb, ValidatorDb, ValidatorDb)>,
+        String,
+    >;
+
+    async fn find_delegators_by_validator(
+        &self,
+        validator_id: i32,
+        sort_field: DelegatorSortFieldDto,
+        sort_order: OrderByDto,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<ValidatorDelegatorDb>, String>;
+
+    async fn find_delegator_stakes_by_validator(
+        &self,
+        validator_id: i32,
+    ) -> Result<Vec<Option<BigDecimal>>, String>;
+
+    async fn find_incoming_redelegation(
+        &self,
+        delegator: String,
+        validator_id: i32,
+    ) -> Result<Option<RedelegationDb>, String>;
+
+    async fn find_pos_stats(
+        &self,
+        epoch: Option<i32>,
+    ) -> Result<Option<PosStatsDb>, String>;
+
+    async fn find_unbonding_schedule(
+        &self,
+        address: Option<String>,
+        after_epoch: i32,
+    ) -> Result<Vec<(i32, Option<BigDecimal>, i64)>, String>;
+
+    async fn find_pos_stats_history(
+        &self,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<PosStatsDb>, String>;
+
     async fn find_merged_bonds_by_address(
         &self,
         address: String,
@@ -96,6 +151,7 @@ pub trait PosRepositoryTrait {
         &self,
         address: String,
         current_epoch: i32,
+        include_history: bool,
         page: i64,
     ) -> Result<PaginatedResponseDb<(ValidatorDb, UnbondDb)>, String>;
 
@@ -112,6 +168,19 @@ pub trait PosRepositoryTrait {
         epoch: u64,
     ) -> Result<Vec<PoSRewardDb>, String>;
 
+    async fn find_reward_claims_by_address(
+        &self,
+        address: String,
+    ) -> Result<
+        Vec<(PosRewardClaimDb, ValidatorDb, Option<NaiveDateTime>)>,
+        String,
+    >;
+
+    async fn find_pending_rewards_by_address(
+        &self,
+        address: String,
+    ) -> Result<Vec<(PoSRewardDb, ValidatorDb)>, String>;
+
     async fn get_total_voting_power(&self) -> Result<Option<i64>, String>;
 
     async fn get_state(&self) -> Result<EpochCrawlerStateDb, String>;
@@ -201,6 +270,218 @@ impl PosRepositoryTrait for PosRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_redelegations_by_address(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<
+        PaginatedResponseDb<(RedelegationHistoryDb, ValidatorDb, ValidatorDb)>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let (src_validators, dest_validators) = diesel::alias!(
+                validators as src_validators,
+                validators as dest_validators
+            );
+
+            redelegation_history::table
+                .inner_join(
+                    src_validators.on(redelegation_history::src_validator_id
+                        .eq(src_validators.field(validators::id))),
+                )
+                .inner_join(
+                    dest_validators.on(redelegation_history::dest_validator_id
+                        .eq(dest_validators.field(validators::id))),
+                )
+                .filter(redelegation_history::delegator.eq(address))
+                .order((
+                    redelegation_history::height.desc(),
+                    redelegation_history::id.desc(),
+                ))
+                .select((
+                    redelegation_history::all_columns,
+                    src_validators.fields(validators::all_columns),
+                    dest_validators.fields(validators::all_columns),
+                ))
+                .paginate(page)
+                .load_and_count_pages::<(
+                    RedelegationHistoryDb,
+                    ValidatorDb,
+                    ValidatorDb,
+                )>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    /// Returns the delegators of the validator, i.e. the addresses with
+    /// stake bonded to it or still unbonding from it
+    async fn find_delegators_by_validator(
+        &self,
+        validator_id: i32,
+        sort_field: DelegatorSortFieldDto,
+        sort_order: OrderByDto,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<ValidatorDelegatorDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let query = validator_delegators::table
+                .filter(validator_delegators::validator_id.eq(validator_id))
+                .select((
+                    validator_delegators::address,
+                    validator_delegators::bonded,
+                    validator_delegators::unbonding,
+                    validator_delegators::start_epoch,
+                ))
+                .into_boxed();
+
+            let query = match (sort_field, sort_order) {
+                (DelegatorSortFieldDto::BondedAmount, OrderByDto::Asc) => query
+                    .order((
+                        validator_delegators::bonded.asc(),
+                        validator_delegators::address.asc(),
+                    )),
+                (DelegatorSortFieldDto::BondedAmount, OrderByDto::Desc) => {
+                    query.order((
+                        validator_delegators::bonded.desc(),
+                        validator_delegators::address.asc(),
+                    ))
+                }
+                (DelegatorSortFieldDto::StartEpoch, OrderByDto::Asc) => query
+                    .order((
+                        validator_delegators::start_epoch.asc().nulls_last(),
+                        validator_delegators::address.asc(),
+                    )),
+                (DelegatorSortFieldDto::StartEpoch, OrderByDto::Desc) => query
+                    .order((
+                        validator_delegators::start_epoch.desc().nulls_last(),
+                        validator_delegators::address.asc(),
+                    )),
+            };
+
+            query
+                .paginate(page)
+                .load_and_count_pages::<ValidatorDelegatorDb>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_delegator_stakes_by_validator(
+        &self,
+        validator_id: i32,
+    ) -> Result<Vec<Option<BigDecimal>>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            bonds::table
+                .filter(bonds::validator_id.eq(validator_id))
+                .group_by(bonds::address)
+                .select(sum(bonds::raw_amount))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_incoming_redelegation(
+        &self,
+        delegator: String,
+        validator_id: i32,
+    ) -> Result<Option<RedelegationDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            redelegation::table
+                .filter(redelegation::delegator.eq(delegator))
+                .filter(redelegation::validator_id.eq(validator_id))
+                .select(RedelegationDb::as_select())
+                .first(conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_unbonding_schedule(
+        &self,
+        address: Option<String>,
+        after_epoch: i32,
+    ) -> Result<Vec<(i32, Option<BigDecimal>, i64)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = unbonds::table
+                .filter(unbonds::withdraw_epoch.gt(after_epoch))
+                .filter(unbonds::withdrawn_at_height.is_null())
+                .group_by(unbonds::withdraw_epoch)
+                .select((
+                    unbonds::withdraw_epoch,
+                    sum(unbonds::raw_amount),
+                    count_star(),
+                ))
+                .into_boxed();
+
+            if let Some(address) = address {
+                query = query.filter(unbonds::address.eq(address));
+            }
+
+            query.order(unbonds::withdraw_epoch.asc()).load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_pos_stats(
+        &self,
+        epoch: Option<i32>,
+    ) -> Result<Option<PosStatsDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = pos_stats::table.into_boxed();
+
+            if let Some(epoch) = epoch {
+                query = query.filter(pos_stats::epoch.eq(epoch));
+            }
+
+            query
+                .order(pos_stats::epoch.desc())
+                .select(PosStatsDb::as_select())
+                .first(conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_pos_stats_history(
+        &self,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<PosStatsDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            pos_stats::table
+                .order(pos_stats::epoch.desc())
+                .select(PosStatsDb::as_select())
+                .paginate(page)
+                .load_and_count_pages(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_validator_by_address(
         &self,
         address: String,
@@ -298,8 +579,10 @@ impl PosRepositoryTrait for PosRepository {
         let conn = self.app_state.get_db_connection().await;
 
         conn.interact(move |conn| {
-            let mut query =
-                validators::table.inner_join(unbonds::table).into_boxed();
+            let mut query = validators::table
+                .inner_join(unbonds::table)
+                .filter(unbonds::dsl::withdrawn_at_height.is_null())
+                .into_boxed();
 
             if let Some(at) = active_at {
                 query = query.filter(unbonds::dsl::withdraw_epoch.lt(at));
@@ -331,6 +614,7 @@ impl PosRepositoryTrait for PosRepository {
                 validators::table
                 .inner_join(unbonds::table)
                 .filter(unbonds::dsl::address.eq(address))
+                .filter(unbonds::dsl::withdrawn_at_height.is_null())
                 .group_by((unbonds::address, validators::id, sql::<Integer>(&format!("CASE WHEN withdraw_epoch <= {} THEN 0 ELSE withdraw_epoch END", current_epoch))))
                 .select((
                         unbonds::address,
@@ -350,18 +634,30 @@ impl PosRepositoryTrait for PosRepository {
         &self,
         address: String,
         current_epoch: i32,
+        include_history: bool,
         page: i64,
     ) -> Result<PaginatedResponseDb<(ValidatorDb, UnbondDb)>, String> {
         let conn = self.app_state.get_db_connection().await;
 
         conn.interact(move |conn| {
-            validators::table
-                .inner_join(unbonds::table)
+            let mut query =
+                validators::table.inner_join(unbonds::table).into_boxed();
+
+            if !include_history {
+                query =
+                    query.filter(unbonds::dsl::withdrawn_at_height.is_null());
+            }
+
+            query
                 .filter(
                     unbonds::dsl::address
                         .eq(address)
                         .and(unbonds::dsl::withdraw_epoch.le(current_epoch)),
                 )
+                .order((
+                    unbonds::dsl::withdraw_epoch.desc(),
+                    unbonds::dsl::id.desc(),
+                ))
                 .select((validators::all_columns, unbonds::all_columns))
                 .paginate(page)
                 .load_and_count_pages::<(ValidatorDb, UnbondDb)>(conn)
@@ -404,6 +700,63 @@ impl PosRepositoryTrait for PosRepository {
         .map_err(|e| e.to_string())
     }
 
+    /// Returns every reward claim of the address, together with the claim
+    /// block timestamp
+    async fn find_reward_claims_by_address(
+        &self,
+        address: String,
+    ) -> Result<
+        Vec<(PosRewardClaimDb, ValidatorDb, Option<NaiveDateTime>)>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            pos_reward_claims::table
+                .inner_join(validators::table)
+                .left_join(
+                    blocks::table
+                        .on(blocks::height.eq(pos_reward_claims::block_height)),
+                )
+                .filter(pos_reward_claims::dsl::owner.eq(address))
+                .select((
+                    PosRewardClaimDb::as_select(),
+                    ValidatorDb::as_select(),
+                    blocks::timestamp.nullable(),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    /// Returns the most recent unclaimed reward row of the address for each
+    /// validator
+    async fn find_pending_rewards_by_address(
+        &self,
+        address: String,
+    ) -> Result<Vec<(PoSRewardDb, ValidatorDb)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            pos_rewards::table
+                .inner_join(validators::table)
+                .filter(pos_rewards::dsl::owner.eq(address))
+                .filter(pos_rewards::dsl::claimed.eq(false))
+                .distinct_on(pos_rewards::dsl::validator_id)
+                .order((
+                    pos_rewards::dsl::validator_id,
+                    pos_rewards::dsl::epoch.desc(),
+                ))
+                .select((PoSRewardDb::as_select(), ValidatorDb::as_select()))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn get_total_voting_power(&self) -> Result<Option<i64>, String> {
         let conn = self.app_state.get_db_connection().await;
 
@@ -2,7 +2,12 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as SerdeJSONValue;
 use shared::token::Token as SharedToken;
 
-use crate::entity::chain::{CirculatingSupply, Parameters, TokenSupply};
+use super::utils::PaginatedResponse;
+use crate::entity::chain::{
+    CirculatingSupply, CirculatingSupplyBreakdown, EpochInfo, HolderBucket,
+    LockedSupply, LockedSupplyKind, NativeInflation, Parameters, TokenHolder,
+    TokenHolders, TokenMetadata, TokenSupply,
+};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -102,20 +107,79 @@ impl From<SharedToken> for TokenResponse {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenMetadataResponse {
+    #[serde(flatten)]
+    pub token: TokenResponse,
+    pub denomination: Option<u8>,
+    pub symbol: Option<String>,
+    pub name: Option<String>,
+    pub logo_uri: Option<String>,
+    pub coingecko_id: Option<String>,
+}
+
+impl From<TokenMetadata> for TokenMetadataResponse {
+    fn from(value: TokenMetadata) -> Self {
+        Self {
+            token: TokenResponse::from(value.token),
+            denomination: value.denomination,
+            symbol: value.symbol,
+            name: value.name,
+            logo_uri: value.logo_uri,
+            coingecko_id: value.coingecko_id,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TokenSupplyResponse {
     pub address: String,
-    pub total_supply: u64,
-    pub effective_supply: Option<u64>,
+    pub epoch: String,
+    pub total_supply: String,
+    pub effective_supply: Option<String>,
+    pub denominated_total_supply: Option<String>,
+    pub denominated_effective_supply: Option<String>,
 }
 
 impl From<TokenSupply> for TokenSupplyResponse {
     fn from(value: TokenSupply) -> Self {
         Self {
             address: value.address.to_string(),
-            total_supply: value.total_supply,
-            effective_supply: value.effective_supply,
+            epoch: value.epoch.to_string(),
+            total_supply: value.total_supply.to_string(),
+            effective_supply: value
+                .effective_supply
+                .map(|amount| amount.to_string()),
+            denominated_total_supply: value.denominated_total_supply,
+            denominated_effective_supply: value.denominated_effective_supply,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeInflationResponse {
+    pub epoch: String,
+    pub total_supply: Option<String>,
+    pub minted: Option<String>,
+    pub pos: String,
+    pub residual: Option<String>,
+    pub masp: String,
+    pub annual_rate: Option<f64>,
+}
+
+impl From<NativeInflation> for NativeInflationResponse {
+    fn from(value: NativeInflation) -> Self {
+        Self {
+            epoch: value.epoch.to_string(),
+            total_supply: value.total_supply.map(|amount| amount.to_string()),
+            minted: value.minted.map(|amount| amount.to_string()),
+            pos: value.pos.to_string(),
+            residual: value.residual.map(|amount| amount.to_string()),
+            masp: value.masp.to_string(),
+            annual_rate: value.annual_rate,
         }
     }
 }
@@ -133,3 +197,192 @@ impl From<CirculatingSupply> for CirculatingSupplyResponse {
         }
     }
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LockedSupplyKindResponse {
+    Bonded,
+    Unbonding,
+    Shielded,
+    Pgf,
+    IbcEscrow,
+    Vesting,
+}
+
+impl From<LockedSupplyKind> for LockedSupplyKindResponse {
+    fn from(value: LockedSupplyKind) -> Self {
+        match value {
+            LockedSupplyKind::Bonded => Self::Bonded,
+            LockedSupplyKind::Unbonding => Self::Unbonding,
+            LockedSupplyKind::Shielded => Self::Shielded,
+            LockedSupplyKind::Pgf => Self::Pgf,
+            LockedSupplyKind::IbcEscrow => Self::IbcEscrow,
+            LockedSupplyKind::Vesting => Self::Vesting,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedAccountResponse {
+    pub address: String,
+    pub amount: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedSupplyResponse {
+    pub category: LockedSupplyKindResponse,
+    pub amount: String,
+    pub share: f64,
+    pub circulating: bool,
+    pub accounts: Vec<LockedAccountResponse>,
+}
+
+impl From<LockedSupply> for LockedSupplyResponse {
+    fn from(value: LockedSupply) -> Self {
+        Self {
+            category: value.kind.into(),
+            amount: value.amount.to_string(),
+            share: value.share,
+            circulating: value.circulating,
+            accounts: value
+                .accounts
+                .into_iter()
+                .map(|(address, amount)| LockedAccountResponse {
+                    address: address.to_string(),
+                    amount: amount.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CirculatingSupplyBreakdownResponse {
+    pub epoch: i32,
+    pub total_supply: String,
+    pub circulating_supply: String,
+    pub locked: Vec<LockedSupplyResponse>,
+}
+
+impl From<CirculatingSupplyBreakdown> for CirculatingSupplyBreakdownResponse {
+    fn from(value: CirculatingSupplyBreakdown) -> Self {
+        Self {
+            epoch: value.epoch,
+            total_supply: value.total_supply.to_string(),
+            circulating_supply: value.circulating_supply.to_string(),
+            locked: value
+                .locked
+                .into_iter()
+                .map(LockedSupplyResponse::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochResponse {
+    pub epoch: String,
+    pub first_block_height: Option<String>,
+    pub start_time: String,
+    pub estimated: bool,
+}
+
+impl From<EpochInfo> for EpochResponse {
+    fn from(value: EpochInfo) -> Self {
+        Self {
+            epoch: value.epoch.to_string(),
+            first_block_height: value
+                .first_block_height
+                .map(|height| height.to_string()),
+            start_time: value.start_time.to_string(),
+            estimated: value.estimated,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenHolderResponse {
+    pub address: String,
+    pub min_denom_amount: String,
+    pub amount: Option<String>,
+    pub share: f64,
+}
+
+impl From<TokenHolder> for TokenHolderResponse {
+    fn from(value: TokenHolder) -> Self {
+        Self {
+            address: value.address.to_string(),
+            min_denom_amount: value.amount.to_string(),
+            amount: value.denominated_amount,
+            share: value.share,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HolderBucketResponse {
+    pub min_amount: String,
+    pub max_amount: Option<String>,
+    pub holders: u64,
+    pub min_denom_amount: String,
+    pub share: f64,
+}
+
+impl From<HolderBucket> for HolderBucketResponse {
+    fn from(value: HolderBucket) -> Self {
+        Self {
+            min_amount: value.min_amount,
+            max_amount: value.max_amount,
+            holders: value.holders,
+            min_denom_amount: value.amount.to_string(),
+            share: value.share,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenHoldersResponse {
+    pub token: String,
+    pub holder_count: u64,
+    pub total_supply: String,
+    pub distribution: Vec<HolderBucketResponse>,
+    #[serde(flatten)]
+    pub holders: PaginatedResponse<Vec<TokenHolderResponse>>,
+}
+
+impl TokenHoldersResponse {
+    pub fn new(
+        value: TokenHolders,
+        page: u64,
+        total_pages: u64,
+        total_items: u64,
+    ) -> Self {
+        Self {
+            token: value.token.to_string(),
+            holder_count: value.holder_count,
+            total_supply: value.total_supply.to_string(),
+            distribution: value
+                .distribution
+                .into_iter()
+                .map(HolderBucketResponse::from)
+                .collect(),
+            holders: PaginatedResponse::new(
+                value
+                    .holders
+                    .into_iter()
+                    .map(TokenHolderResponse::from)
+                    .collect(),
+                page,
+                total_pages,
+                total_items,
+            ),
+        }
+    }
+}
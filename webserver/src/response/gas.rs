@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use super::chain::TokenResponse;
-use crate::entity::gas::GasPrice;
+use super::transaction::TransactionKindResponse;
+use crate::entity::gas::{GasComponentEstimate, GasEstimateInterval, GasPrice};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -27,3 +28,53 @@ pub struct GasEstimateResponse {
     pub avg: u64,
     pub total_estimates: u64,
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasComponentEstimateResponse {
+    pub kind: TransactionKindResponse,
+    pub count: u64,
+    pub gas_per_tx: u64,
+    pub modelled: bool,
+}
+
+impl From<GasComponentEstimate> for GasComponentEstimateResponse {
+    fn from(value: GasComponentEstimate) -> Self {
+        Self {
+            kind: value.kind.into(),
+            count: value.count,
+            gas_per_tx: value.gas_per_tx,
+            modelled: value.modelled,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasEstimateIntervalResponse {
+    pub estimate: u64,
+    pub lower: u64,
+    pub upper: u64,
+    pub confidence: f64,
+    pub samples: u64,
+    pub epoch: Option<i32>,
+    pub components: Vec<GasComponentEstimateResponse>,
+}
+
+impl From<GasEstimateInterval> for GasEstimateIntervalResponse {
+    fn from(value: GasEstimateInterval) -> Self {
+        Self {
+            estimate: value.estimate,
+            lower: value.lower,
+            upper: value.upper,
+            confidence: value.confidence,
+            samples: value.samples,
+            epoch: value.epoch,
+            components: value
+                .components
+                .into_iter()
+                .map(GasComponentEstimateResponse::from)
+                .collect(),
+        }
+    }
+}
@@ -3,9 +3,14 @@ use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 
 use crate::entity::governance::{
-    Proposal, ProposalData, ProposalStatus, ProposalType, ProposalVote,
-    TallyType, VoteType,
+    EpochTime, ParticipationStatus, Proposal, ProposalData, ProposalExecution,
+    ProposalSearchResult, ProposalStatus, ProposalSubmission, ProposalTally,
+    ProposalTallyProjection, ProposalTimeline, ProposalType, ProposalVote,
+    ProposalVoteCount, TallyType, ValidatorParticipation, VoteType,
+    VoterParticipation,
 };
+use crate::response::pgf::PgfPaymentResponse;
+use crate::response::pos::ValidatorResponse;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -29,6 +34,17 @@ impl Display for ProposalTypeResponse {
     }
 }
 
+impl From<ProposalType> for ProposalTypeResponse {
+    fn from(value: ProposalType) -> Self {
+        match value {
+            ProposalType::Default => Self::Default,
+            ProposalType::DefaultWithWasm => Self::DefaultWithWasm,
+            ProposalType::PgfSteward => Self::PgfSteward,
+            ProposalType::PgfFunding => Self::PgfFunding,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum TallyTypeResponse {
@@ -51,6 +67,18 @@ impl Display for TallyTypeResponse {
     }
 }
 
+impl From<TallyType> for TallyTypeResponse {
+    fn from(value: TallyType) -> Self {
+        match value {
+            TallyType::TwoFifths => Self::TwoFifths,
+            TallyType::OneHalfOverOneThird => Self::OneHalfOverOneThird,
+            TallyType::LessOneHalfOverOneThirdNay => {
+                Self::LessOneHalfOverOneThirdNay
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum VoteTypeResponse {
@@ -101,11 +129,29 @@ impl Display for ProposalStatusResponse {
     }
 }
 
+impl From<ProposalStatus> for ProposalStatusResponse {
+    fn from(value: ProposalStatus) -> Self {
+        match value {
+            ProposalStatus::Pending => Self::Pending,
+            ProposalStatus::Rejected => Self::Rejected,
+            ProposalStatus::Passed => Self::Passed,
+            ProposalStatus::Voting => Self::Voting,
+            ProposalStatus::ExecutedPassed => Self::ExecutedPassed,
+            ProposalStatus::ExecutedRejected => Self::ExecutedRejected,
+            ProposalStatus::Unknown => Self::Unknown,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProposalResponse {
     pub id: u64,
     pub content: String,
+    pub title: Option<String>,
+    pub authors: Option<String>,
+    pub discussions_to: Option<String>,
+    pub r#abstract: Option<String>,
     pub r#type: ProposalTypeResponse,
     pub tally_type: TallyTypeResponse,
     pub author: String,
@@ -127,23 +173,12 @@ impl From<Proposal> for ProposalResponse {
         Self {
             id: value.id,
             content: value.content,
-            r#type: match value.r#type {
-                ProposalType::Default => ProposalTypeResponse::Default,
-                ProposalType::DefaultWithWasm => {
-                    ProposalTypeResponse::DefaultWithWasm
-                }
-                ProposalType::PgfSteward => ProposalTypeResponse::PgfSteward,
-                ProposalType::PgfFunding => ProposalTypeResponse::PgfFunding,
-            },
-            tally_type: match value.tally_type {
-                TallyType::TwoFifths => TallyTypeResponse::TwoFifths,
-                TallyType::OneHalfOverOneThird => {
-                    TallyTypeResponse::OneHalfOverOneThird
-                }
-                TallyType::LessOneHalfOverOneThirdNay => {
-                    TallyTypeResponse::LessOneHalfOverOneThirdNay
-                }
-            },
+            title: value.title,
+            authors: value.authors,
+            discussions_to: value.discussions_to,
+            r#abstract: value.r#abstract,
+            r#type: ProposalTypeResponse::from(value.r#type),
+            tally_type: TallyTypeResponse::from(value.tally_type),
             author: value.author.to_string(),
             start_epoch: value.start_epoch,
             end_epoch: value.end_epoch,
@@ -152,19 +187,7 @@ impl From<Proposal> for ProposalResponse {
             end_time: value.end_time,
             current_time: value.current_time,
             activation_time: value.activation_time,
-            status: match value.status {
-                ProposalStatus::Pending => ProposalStatusResponse::Pending,
-                ProposalStatus::Rejected => ProposalStatusResponse::Rejected,
-                ProposalStatus::Passed => ProposalStatusResponse::Passed,
-                ProposalStatus::Voting => ProposalStatusResponse::Voting,
-                ProposalStatus::ExecutedPassed => {
-                    ProposalStatusResponse::ExecutedPassed
-                }
-                ProposalStatus::ExecutedRejected => {
-                    ProposalStatusResponse::ExecutedRejected
-                }
-                ProposalStatus::Unknown => ProposalStatusResponse::Unknown,
-            },
+            status: ProposalStatusResponse::from(value.status),
             yay_votes: value.yay_votes,
             nay_votes: value.nay_votes,
             abstain_votes: value.abstain_votes,
@@ -208,14 +231,288 @@ impl From<ProposalData> for ProposalDataResponse {
         Self {
             data: value.data,
             hash: value.hash,
-            r#type: match value.r#type {
-                ProposalType::Default => ProposalTypeResponse::Default,
-                ProposalType::DefaultWithWasm => {
-                    ProposalTypeResponse::DefaultWithWasm
-                }
-                ProposalType::PgfSteward => ProposalTypeResponse::PgfSteward,
-                ProposalType::PgfFunding => ProposalTypeResponse::PgfFunding,
+            r#type: ProposalTypeResponse::from(value.r#type),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalSubmissionResponse {
+    pub tx_id: Option<String>,
+    pub block_height: Option<u64>,
+    pub time: Option<String>,
+}
+
+impl From<ProposalSubmission> for ProposalSubmissionResponse {
+    fn from(value: ProposalSubmission) -> Self {
+        Self {
+            tx_id: value.tx_id.map(|id| id.to_string()),
+            block_height: value.block_height,
+            time: value.time,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochTimeResponse {
+    pub epoch: u64,
+    pub block_height: Option<u64>,
+    pub time: String,
+    pub estimated: bool,
+}
+
+impl From<EpochTime> for EpochTimeResponse {
+    fn from(value: EpochTime) -> Self {
+        Self {
+            epoch: value.epoch,
+            block_height: value.block_height,
+            time: value.time,
+            estimated: value.estimated,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalVoteCountResponse {
+    pub epoch: Option<u64>,
+    pub yay: u64,
+    pub nay: u64,
+    pub abstain: u64,
+    pub total: u64,
+}
+
+impl From<ProposalVoteCount> for ProposalVoteCountResponse {
+    fn from(value: ProposalVoteCount) -> Self {
+        Self {
+            epoch: value.epoch,
+            yay: value.yay,
+            nay: value.nay,
+            abstain: value.abstain,
+            total: value.total,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalTallyResponse {
+    pub tally_type: TallyTypeResponse,
+    pub yay_votes: String,
+    pub nay_votes: String,
+    pub abstain_votes: String,
+    pub total_voting_power: Option<String>,
+    pub quorum: String,
+    pub yay_threshold: String,
+    pub quorum_reached: Option<bool>,
+    pub passing: Option<bool>,
+}
+
+impl From<ProposalTally> for ProposalTallyResponse {
+    fn from(value: ProposalTally) -> Self {
+        let (quorum_num, quorum_den) = value.tally_type.quorum();
+        let (yay_num, yay_den) = value.tally_type.yay_threshold();
+
+        Self {
+            tally_type: TallyTypeResponse::from(value.tally_type),
+            yay_votes: value.yay_votes.to_string(),
+            nay_votes: value.nay_votes.to_string(),
+            abstain_votes: value.abstain_votes.to_string(),
+            total_voting_power: value
+                .total_voting_power
+                .map(|total| total.to_string()),
+            quorum: format!("{}/{}", quorum_num, quorum_den),
+            yay_threshold: format!("{}/{}", yay_num, yay_den),
+            quorum_reached: value.quorum_reached,
+            passing: value.passing,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalExecutionResponse {
+    pub activation: EpochTimeResponse,
+    pub executed: bool,
+    pub passed: Option<bool>,
+    pub wasm_hash: Option<String>,
+    pub pgf_payments: Vec<PgfPaymentResponse>,
+}
+
+impl From<ProposalExecution> for ProposalExecutionResponse {
+    fn from(value: ProposalExecution) -> Self {
+        Self {
+            activation: EpochTimeResponse::from(value.activation),
+            executed: value.executed,
+            passed: value.passed,
+            wasm_hash: value.wasm_hash,
+            pgf_payments: value
+                .pgf_payments
+                .into_iter()
+                .map(PgfPaymentResponse::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalTimelineResponse {
+    pub id: u64,
+    pub r#type: ProposalTypeResponse,
+    pub status: ProposalStatusResponse,
+    pub submission: ProposalSubmissionResponse,
+    pub voting_start: EpochTimeResponse,
+    pub voting_end: EpochTimeResponse,
+    pub votes: Vec<ProposalVoteCountResponse>,
+    pub tally: ProposalTallyResponse,
+    pub execution: Option<ProposalExecutionResponse>,
+}
+
+impl From<ProposalTimeline> for ProposalTimelineResponse {
+    fn from(value: ProposalTimeline) -> Self {
+        Self {
+            id: value.id,
+            r#type: ProposalTypeResponse::from(value.r#type),
+            status: ProposalStatusResponse::from(value.status),
+            submission: ProposalSubmissionResponse::from(value.submission),
+            voting_start: EpochTimeResponse::from(value.voting_start),
+            voting_end: EpochTimeResponse::from(value.voting_end),
+            votes: value
+                .votes
+                .into_iter()
+                .map(ProposalVoteCountResponse::from)
+                .collect(),
+            tally: ProposalTallyResponse::from(value.tally),
+            execution: value.execution.map(ProposalExecutionResponse::from),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TallyOutcomeResponse {
+    Passing,
+    Failing,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalTallyProjectionResponse {
+    pub proposal_id: u64,
+    pub epoch: u64,
+    pub outcome: TallyOutcomeResponse,
+    pub quorum_reached: bool,
+    pub yay_margin: String,
+    pub quorum_margin: String,
+    pub indexed_total_voting_power: bool,
+    pub tally: ProposalTallyResponse,
+}
+
+impl From<ProposalTallyProjection> for ProposalTallyProjectionResponse {
+    fn from(value: ProposalTallyProjection) -> Self {
+        Self {
+            proposal_id: value.proposal_id,
+            epoch: value.epoch,
+            outcome: if value.tally.passing.unwrap_or_default() {
+                TallyOutcomeResponse::Passing
+            } else {
+                TallyOutcomeResponse::Failing
             },
+            quorum_reached: value.tally.quorum_reached.unwrap_or_default(),
+            yay_margin: value.yay_margin.to_string(),
+            quorum_margin: value.quorum_margin.to_string(),
+            indexed_total_voting_power: value.indexed_total_voting_power,
+            tally: ProposalTallyResponse::from(value.tally),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ParticipationStatusResponse {
+    Voting,
+    NonVoting,
+    NoEligibleProposals,
+}
+
+impl From<ParticipationStatus> for ParticipationStatusResponse {
+    fn from(value: ParticipationStatus) -> Self {
+        match value {
+            ParticipationStatus::Voting => Self::Voting,
+            ParticipationStatus::NonVoting => Self::NonVoting,
+            ParticipationStatus::NoEligibleProposals => {
+                Self::NoEligibleProposals
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoterParticipationResponse {
+    pub status: ParticipationStatusResponse,
+    pub eligible_proposals: u64,
+    pub yay: u64,
+    pub nay: u64,
+    pub abstain: u64,
+    pub no_vote: u64,
+    pub participation_rate: f64,
+}
+
+impl From<VoterParticipation> for VoterParticipationResponse {
+    fn from(value: VoterParticipation) -> Self {
+        Self {
+            status: value.status.into(),
+            eligible_proposals: value.eligible_proposals,
+            yay: value.yay,
+            nay: value.nay,
+            abstain: value.abstain,
+            no_vote: value.no_vote,
+            participation_rate: value.participation_rate,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorParticipationResponse {
+    #[serde(flatten)]
+    pub validator: ValidatorResponse,
+    #[serde(flatten)]
+    pub participation: VoterParticipationResponse,
+}
+
+impl From<ValidatorParticipation> for ValidatorParticipationResponse {
+    fn from(value: ValidatorParticipation) -> Self {
+        Self {
+            validator: ValidatorResponse::from(value.validator),
+            participation: VoterParticipationResponse::from(
+                value.participation,
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalSearchResultResponse {
+    #[serde(flatten)]
+    pub proposal: ProposalResponse,
+    pub rank: f32,
+    pub title_highlight: String,
+    pub abstract_highlight: String,
+}
+
+impl From<ProposalSearchResult> for ProposalSearchResultResponse {
+    fn from(value: ProposalSearchResult) -> Self {
+        Self {
+            proposal: ProposalResponse::from(value.proposal),
+            rank: value.rank,
+            title_highlight: value.title_highlight,
+            abstract_highlight: value.abstract_highlight,
         }
     }
 }
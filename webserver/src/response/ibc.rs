@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::entity::ibc::{
-    IbcAck, IbcAckStatus, IbcRateLimit, IbcTokenFlow, IbcTokenThroughput,
+    IbcAck, IbcAckStatus, IbcChannel, IbcChannelOrdering, IbcChannelState,
+    IbcChannelTokenVolume, IbcCounterparty, IbcPendingTransfer, IbcRateLimit,
+    IbcRateLimitUsage, IbcTokenFlow, IbcTokenThroughput, IbcTransfer,
+    IbcTransferAck, IbcTransferStatus,
 };
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -85,7 +88,286 @@ impl From<IbcTokenThroughput> for IbcTokenThroughputResponse {
     fn from(value: IbcTokenThroughput) -> Self {
         Self {
             throughput: value.throughput,
-            limit: value.throughput,
+            limit: value.limit,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IbcTransferStatusResponse {
+    Pending,
+    Stuck,
+    Completed,
+    Refunded,
+    Unknown,
+}
+
+impl From<IbcTransferStatus> for IbcTransferStatusResponse {
+    fn from(value: IbcTransferStatus) -> Self {
+        match value {
+            IbcTransferStatus::Pending => Self::Pending,
+            IbcTransferStatus::Stuck => Self::Stuck,
+            IbcTransferStatus::Completed => Self::Completed,
+            IbcTransferStatus::Refunded => Self::Refunded,
+            IbcTransferStatus::Unknown => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcTransferAckResponse {
+    pub tx_id: String,
+    pub height: Option<u64>,
+}
+
+impl From<IbcTransferAck> for IbcTransferAckResponse {
+    fn from(value: IbcTransferAck) -> Self {
+        Self {
+            tx_id: value.tx_id.to_string(),
+            height: value.height,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcTransferResponse {
+    pub tx_id: String,
+    pub height: Option<u64>,
+    pub source_port: String,
+    pub source_channel: String,
+    pub dest_port: String,
+    pub dest_channel: String,
+    pub sequence: u64,
+    pub denom: Option<String>,
+    pub amount: Option<String>,
+    pub sender: Option<String>,
+    pub receiver: Option<String>,
+    pub memo: Option<String>,
+    pub timeout_timestamp: u64,
+    pub timeout_height: Option<String>,
+    pub status: IbcTransferStatusResponse,
+    pub ack_status: IbcAckStatusResponse,
+    pub ack: Option<IbcTransferAckResponse>,
+    pub refund_tx_id: Option<String>,
+}
+
+impl From<IbcTransfer> for IbcTransferResponse {
+    fn from(value: IbcTransfer) -> Self {
+        Self {
+            tx_id: value.tx_id.to_string(),
+            height: value.height,
+            source_port: value.source_port,
+            source_channel: value.source_channel,
+            dest_port: value.dest_port,
+            dest_channel: value.dest_channel,
+            sequence: value.sequence,
+            denom: value.denom,
+            amount: value.amount.map(|amount| amount.to_string()),
+            sender: value.sender,
+            receiver: value.receiver,
+            memo: value.memo,
+            timeout_timestamp: value.timeout_timestamp,
+            timeout_height: value.timeout_height,
+            status: IbcTransferStatusResponse::from(value.status),
+            ack_status: IbcAckStatusResponse::from(value.ack_status),
+            ack: value.ack.map(IbcTransferAckResponse::from),
+            refund_tx_id: value.refund_tx_id.map(|id| id.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IbcChannelStateResponse {
+    Uninitialized,
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+impl From<IbcChannelState> for IbcChannelStateResponse {
+    fn from(value: IbcChannelState) -> Self {
+        match value {
+            IbcChannelState::Uninitialized => Self::Uninitialized,
+            IbcChannelState::Init => Self::Init,
+            IbcChannelState::TryOpen => Self::TryOpen,
+            IbcChannelState::Open => Self::Open,
+            IbcChannelState::Closed => Self::Closed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IbcChannelOrderingResponse {
+    None,
+    Unordered,
+    Ordered,
+}
+
+impl From<IbcChannelOrdering> for IbcChannelOrderingResponse {
+    fn from(value: IbcChannelOrdering) -> Self {
+        match value {
+            IbcChannelOrdering::None => Self::None,
+            IbcChannelOrdering::Unordered => Self::Unordered,
+            IbcChannelOrdering::Ordered => Self::Ordered,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcCounterpartyResponse {
+    pub chain_id: Option<String>,
+    pub client_id: Option<String>,
+    pub connection_id: Option<String>,
+    pub port_id: String,
+    pub channel_id: Option<String>,
+}
+
+impl From<IbcCounterparty> for IbcCounterpartyResponse {
+    fn from(value: IbcCounterparty) -> Self {
+        Self {
+            chain_id: value.chain_id,
+            client_id: value.client_id,
+            connection_id: value.connection_id,
+            port_id: value.port_id,
+            channel_id: value.channel_id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcChannelResponse {
+    pub port_id: String,
+    pub channel_id: String,
+    pub connection_id: Option<String>,
+    pub client_id: Option<String>,
+    pub client_frozen: Option<bool>,
+    pub state: IbcChannelStateResponse,
+    pub ordering: IbcChannelOrderingResponse,
+    pub version: String,
+    pub counterparty: IbcCounterpartyResponse,
+    pub volumes: Vec<IbcChannelTokenVolumeResponse>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcChannelTokenVolumeResponse {
+    pub token: String,
+    pub deposit: String,
+    pub withdraw: String,
+}
+
+impl From<IbcChannelTokenVolume> for IbcChannelTokenVolumeResponse {
+    fn from(value: IbcChannelTokenVolume) -> Self {
+        Self {
+            token: value.token,
+            deposit: value.deposit.to_string(),
+            withdraw: value.withdraw.to_string(),
+        }
+    }
+}
+
+impl From<IbcChannel> for IbcChannelResponse {
+    fn from(value: IbcChannel) -> Self {
+        Self {
+            port_id: value.port_id,
+            channel_id: value.channel_id,
+            connection_id: value.connection_id,
+            client_id: value.client_id,
+            client_frozen: value.client_frozen,
+            state: IbcChannelStateResponse::from(value.state),
+            ordering: IbcChannelOrderingResponse::from(value.ordering),
+            version: value.version,
+            counterparty: IbcCounterpartyResponse::from(value.counterparty),
+            volumes: value
+                .volumes
+                .into_iter()
+                .map(IbcChannelTokenVolumeResponse::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcPendingTransferResponse {
+    #[serde(flatten)]
+    pub transfer: IbcTransferResponse,
+    pub sent_at: Option<i64>,
+}
+
+impl From<IbcPendingTransfer> for IbcPendingTransferResponse {
+    fn from(value: IbcPendingTransfer) -> Self {
+        Self {
+            transfer: IbcTransferResponse::from(value.transfer),
+            sent_at: value.sent_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcRateLimitUsageResponse {
+    pub epoch: u64,
+    pub throughput_limit: String,
+    pub deposit: String,
+    pub withdraw: String,
+    pub throughput: String,
+    pub utilisation: Option<f64>,
+}
+
+impl From<IbcRateLimitUsage> for IbcRateLimitUsageResponse {
+    fn from(value: IbcRateLimitUsage) -> Self {
+        Self {
+            epoch: value.epoch,
+            throughput_limit: value.throughput_limit.to_string(),
+            deposit: value.deposit.to_string(),
+            withdraw: value.withdraw.to_string(),
+            throughput: value.throughput().to_string(),
+            utilisation: value.utilisation(),
+        }
+    }
+}
+
+/// Share of the throughput limit past which transfers are flagged as at risk
+/// of being rejected
+const RATE_LIMIT_WARNING_THRESHOLD: f64 = 0.9;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcRateLimitHeadroomResponse {
+    pub token_address: String,
+    pub epoch: u64,
+    pub throughput_limit: String,
+    pub throughput: String,
+    pub deposit_headroom: String,
+    pub withdraw_headroom: String,
+    pub utilisation: Option<f64>,
+    pub near_limit: bool,
+}
+
+impl From<(String, IbcRateLimitUsage)> for IbcRateLimitHeadroomResponse {
+    fn from((token_address, value): (String, IbcRateLimitUsage)) -> Self {
+        let utilisation = value.utilisation();
+
+        Self {
+            token_address,
+            epoch: value.epoch,
+            throughput_limit: value.throughput_limit.to_string(),
+            throughput: value.throughput().to_string(),
+            deposit_headroom: value.deposit_headroom().to_string(),
+            withdraw_headroom: value.withdraw_headroom().to_string(),
+            utilisation,
+            // A zero limit blocks every transfer
+            near_limit: utilisation
+                .is_none_or(|used| used >= RATE_LIMIT_WARNING_THRESHOLD),
         }
     }
 }
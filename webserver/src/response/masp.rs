@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 use shared::masp::MaspRewardData;
 
+use crate::dto::masp::MaspPoolSeriesIntervalDto;
 use crate::entity::masp::{
-    MaspPoolAggregate, MaspPoolAggregateKind, MaspPoolAggregateWindow,
+    MaspActivity, MaspPoolAggregate, MaspPoolAggregateKind,
+    MaspPoolAggregateWindow, MaspPoolSeries, MaspPoolSeriesPoint,
+    MaspRewardEstimate, MaspRewardHistory,
 };
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -82,3 +85,148 @@ impl From<MaspRewardData> for MaspRewardDataResponse {
         }
     }
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspPoolSeriesPointResponse {
+    pub start: i64,
+    pub inflow: String,
+    pub outflow: String,
+    pub net: String,
+    pub total: String,
+}
+
+impl From<MaspPoolSeriesPoint> for MaspPoolSeriesPointResponse {
+    fn from(value: MaspPoolSeriesPoint) -> Self {
+        Self {
+            start: value.start,
+            inflow: value.inflow.to_string(),
+            outflow: value.outflow.to_string(),
+            net: value.net.to_string(),
+            total: value.total.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspPoolSeriesResponse {
+    pub token_address: String,
+    pub interval: MaspPoolSeriesIntervalDto,
+    pub points: Vec<MaspPoolSeriesPointResponse>,
+}
+
+impl MaspPoolSeriesResponse {
+    pub fn new(
+        series: MaspPoolSeries,
+        interval: MaspPoolSeriesIntervalDto,
+    ) -> Self {
+        Self {
+            token_address: series.token_address.to_string(),
+            interval,
+            points: series
+                .points
+                .into_iter()
+                .map(MaspPoolSeriesPointResponse::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspRewardHistoryResponse {
+    pub token: String,
+    pub epoch: i32,
+    pub max_reward_rate: String,
+    pub kp_gain: String,
+    pub kd_gain: String,
+    pub locked_amount_target: String,
+    pub last_locked_amount: String,
+    pub last_inflation: String,
+    pub realised_reward_rate: Option<f64>,
+}
+
+impl From<MaspRewardHistory> for MaspRewardHistoryResponse {
+    fn from(value: MaspRewardHistory) -> Self {
+        Self {
+            token: value.token.to_string(),
+            epoch: value.epoch,
+            max_reward_rate: value.max_reward_rate,
+            kp_gain: value.kp_gain,
+            kd_gain: value.kd_gain,
+            locked_amount_target: value.locked_amount_target.to_string(),
+            last_locked_amount: value.last_locked_amount.to_string(),
+            last_inflation: value.last_inflation.to_string(),
+            realised_reward_rate: value.realised_reward_rate,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspRewardEstimateResponse {
+    pub token: String,
+    pub amount: String,
+    pub epochs: u64,
+    pub rates_epoch: i32,
+    pub max_reward_rate: String,
+    pub realised_reward_rate: Option<f64>,
+    pub projected_rewards: Option<String>,
+}
+
+impl From<MaspRewardEstimate> for MaspRewardEstimateResponse {
+    fn from(value: MaspRewardEstimate) -> Self {
+        Self {
+            token: value.rates.token.to_string(),
+            amount: value.amount.to_string(),
+            epochs: value.epochs,
+            rates_epoch: value.rates.epoch,
+            max_reward_rate: value.rates.max_reward_rate,
+            realised_reward_rate: value.rates.realised_reward_rate,
+            projected_rewards: value
+                .projected_rewards
+                .map(|amount| amount.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaspActivityResponse {
+    pub epoch: i32,
+    pub total: u64,
+    pub shielded: u64,
+    pub shielding: u64,
+    pub unshielding: u64,
+    pub mixed: u64,
+    pub ibc_shielding: u64,
+    pub ibc_unshielding: u64,
+    pub masp_wrappers: u64,
+    pub masp_fee_payments: u64,
+    pub masp_fee_payment_share: Option<f64>,
+    pub note_commitments: Option<u64>,
+    pub new_note_commitments: Option<u64>,
+    pub anonymity_set_estimate: u64,
+}
+
+impl From<MaspActivity> for MaspActivityResponse {
+    fn from(value: MaspActivity) -> Self {
+        Self {
+            epoch: value.epoch,
+            total: value.total(),
+            shielded: value.shielded,
+            shielding: value.shielding,
+            unshielding: value.unshielding,
+            mixed: value.mixed,
+            ibc_shielding: value.ibc_shielding,
+            ibc_unshielding: value.ibc_unshielding,
+            masp_wrappers: value.masp_wrappers,
+            masp_fee_payments: value.masp_fee_payments,
+            masp_fee_payment_share: value.masp_fee_payment_share(),
+            note_commitments: value.note_commitments,
+            new_note_commitments: value.new_note_commitments,
+            anonymity_set_estimate: value.anonymity_set_estimate,
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use orm::pgf::{PaymentKindDb, PaymentRecurrenceDb};
 use serde::{Deserialize, Serialize};
 
-use crate::entity::pgf::{PaymentKind, PaymentRecurrence, PgfPayment};
+use crate::entity::pgf::{
+    PaymentKind, PaymentRecurrence, PgfContinuousPayment, PgfPayment,
+    PgfRecipientTotal, PgfScheduledPayment, PgfSteward,
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -64,3 +67,81 @@ impl From<PgfPayment> for PgfPaymentResponse {
         }
     }
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PgfStewardResponse {
+    pub address: String,
+    pub is_steward: bool,
+    pub epoch: u64,
+}
+
+impl From<PgfSteward> for PgfStewardResponse {
+    fn from(value: PgfSteward) -> Self {
+        Self {
+            address: value.address.to_string(),
+            is_steward: value.is_steward,
+            epoch: value.epoch,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PgfContinuousPaymentResponse {
+    #[serde(flatten)]
+    pub payment: PgfPaymentResponse,
+    pub activation_epoch: u64,
+    pub epochs_scheduled: u64,
+    pub total_scheduled: String,
+}
+
+impl From<PgfContinuousPayment> for PgfContinuousPaymentResponse {
+    fn from(value: PgfContinuousPayment) -> Self {
+        Self {
+            payment: value.payment.into(),
+            activation_epoch: value.activation_epoch,
+            epochs_scheduled: value.epochs_scheduled,
+            total_scheduled: value.total_scheduled.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PgfScheduledPaymentResponse {
+    #[serde(flatten)]
+    pub payment: PgfPaymentResponse,
+    pub epoch: u64,
+}
+
+impl From<PgfScheduledPayment> for PgfScheduledPaymentResponse {
+    fn from(value: PgfScheduledPayment) -> Self {
+        Self {
+            payment: value.payment.into(),
+            epoch: value.epoch,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PgfRecipientTotalResponse {
+    pub receipient: String,
+    pub total_scheduled: String,
+    pub scheduled_payments: u64,
+    pub first_epoch: Option<u64>,
+    pub last_epoch: Option<u64>,
+}
+
+impl From<PgfRecipientTotal> for PgfRecipientTotalResponse {
+    fn from(value: PgfRecipientTotal) -> Self {
+        Self {
+            receipient: value.receipient.to_string(),
+            total_scheduled: value.total_scheduled.to_string(),
+            scheduled_payments: value.scheduled_payments,
+            first_epoch: value.first_epoch,
+            last_epoch: value.last_epoch,
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::entity::pos::{
-    Bond, BondStatus, MergedBond, Reward, Unbond, Validator, ValidatorState,
+    Bond, BondStatus, MergedBond, PosStats, Redelegation,
+    RedelegationEligibility, Reward, RewardClaim, RewardLedger,
+    StakeDistribution, Unbond, UnbondingScheduleEntry, Validator,
+    ValidatorDelegator, ValidatorRewardLedger, ValidatorState,
     ValidatorWithRank, Withdraw,
 };
 
@@ -68,12 +71,79 @@ pub struct UnbondResponse {
     pub can_withdraw: bool,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorDelegatorResponse {
+    pub address: String,
+    pub bonded_amount: String,
+    pub unbonding_amount: String,
+    pub start_epoch: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeDistributionResponse {
+    pub total_stake: String,
+    pub delegators: u64,
+    pub top: u64,
+    pub top_share: f64,
+    pub gini_coefficient: f64,
+    pub nakamoto_coefficient: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnbondingScheduleEntryResponse {
+    pub withdraw_epoch: String,
+    pub min_denom_amount: String,
+    pub unbonds: u64,
+    pub withdraw_time: String,
+    pub time_remaining: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PosStatsResponse {
+    pub epoch: String,
+    pub total_bonded: String,
+    pub total_supply: String,
+    pub bonded_ratio: f64,
+    pub nakamoto_coefficient: u64,
+    pub active_validators: u64,
+    pub jailed_validators: u64,
+    pub inactive_validators: u64,
+    pub median_commission: Option<String>,
+    pub unbonding_queue: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedelegationResponse {
+    pub tx_id: String,
+    pub src_validator: ValidatorWithRankResponse,
+    pub dest_validator: ValidatorWithRankResponse,
+    pub min_denom_amount: String,
+    pub start_epoch: String,
+    pub end_epoch: String,
+    pub slashing_window_end_epoch: String,
+    pub block_height: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedelegationEligibilityResponse {
+    pub allowed: bool,
+    pub allowed_from_epoch: Option<String>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawResponse {
     pub min_denom_amount: String,
     pub validator: ValidatorWithRankResponse,
     pub withdraw_epoch: String,
+    pub withdrawn_at_height: Option<String>,
+    pub withdrawn_at_epoch: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -81,6 +151,39 @@ pub struct WithdrawResponse {
 pub struct RewardResponse {
     pub min_denom_amount: String,
     pub validator: ValidatorWithRankResponse,
+    pub claimed: bool,
+    pub claim_tx_id: Option<String>,
+    pub claimed_at_height: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardClaimResponse {
+    pub tx_id: String,
+    pub block_height: String,
+    pub timestamp: Option<String>,
+    pub epoch: Option<String>,
+    pub min_denom_amount: String,
+    pub validator: ValidatorWithRankResponse,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorRewardLedgerResponse {
+    pub validator: ValidatorWithRankResponse,
+    pub earned: String,
+    pub claimed: String,
+    pub pending: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardLedgerResponse {
+    pub total_earned: String,
+    pub total_claimed: String,
+    pub total_pending: String,
+    pub validators: Vec<ValidatorRewardLedgerResponse>,
+    pub claims: Vec<RewardClaimResponse>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -181,12 +284,101 @@ impl From<Unbond> for UnbondResponse {
     }
 }
 
+impl From<ValidatorDelegator> for ValidatorDelegatorResponse {
+    fn from(value: ValidatorDelegator) -> Self {
+        ValidatorDelegatorResponse {
+            address: value.address.to_string(),
+            bonded_amount: value.bonded.to_string(),
+            unbonding_amount: value.unbonding.to_string(),
+            start_epoch: value.start_epoch.map(|epoch| epoch.to_string()),
+        }
+    }
+}
+
+impl From<StakeDistribution> for StakeDistributionResponse {
+    fn from(value: StakeDistribution) -> Self {
+        StakeDistributionResponse {
+            total_stake: value.total_stake.to_string(),
+            delegators: value.delegators,
+            top: value.top,
+            top_share: value.top_share,
+            gini_coefficient: value.gini_coefficient,
+            nakamoto_coefficient: value.nakamoto_coefficient,
+        }
+    }
+}
+
+impl From<UnbondingScheduleEntry> for UnbondingScheduleEntryResponse {
+    fn from(value: UnbondingScheduleEntry) -> Self {
+        UnbondingScheduleEntryResponse {
+            withdraw_epoch: value.withdraw_epoch.to_string(),
+            min_denom_amount: value.min_denom_amount.to_string(),
+            unbonds: value.unbonds,
+            withdraw_time: value.withdraw_time.to_string(),
+            time_remaining: value.time_remaining.to_string(),
+        }
+    }
+}
+
+impl From<PosStats> for PosStatsResponse {
+    fn from(value: PosStats) -> Self {
+        PosStatsResponse {
+            epoch: value.epoch.to_string(),
+            total_bonded: value.total_bonded.to_string(),
+            total_supply: value.total_supply.to_string(),
+            bonded_ratio: value.bonded_ratio,
+            nakamoto_coefficient: value.nakamoto_coefficient,
+            active_validators: value.active_validators,
+            jailed_validators: value.jailed_validators,
+            inactive_validators: value.inactive_validators,
+            median_commission: value.median_commission,
+            unbonding_queue: value.unbonding_queue.to_string(),
+        }
+    }
+}
+
+impl From<Redelegation> for RedelegationResponse {
+    fn from(value: Redelegation) -> Self {
+        RedelegationResponse {
+            tx_id: value.tx_id,
+            src_validator: ValidatorWithRankResponse::from(value.src_validator),
+            dest_validator: ValidatorWithRankResponse::from(
+                value.dest_validator,
+            ),
+            min_denom_amount: value.min_denom_amount.to_string(),
+            start_epoch: value.start_epoch.to_string(),
+            end_epoch: value.end_epoch.to_string(),
+            slashing_window_end_epoch: value
+                .slashing_window_end_epoch
+                .to_string(),
+            block_height: value.block_height.to_string(),
+        }
+    }
+}
+
+impl From<RedelegationEligibility> for RedelegationEligibilityResponse {
+    fn from(value: RedelegationEligibility) -> Self {
+        RedelegationEligibilityResponse {
+            allowed: value.allowed,
+            allowed_from_epoch: value
+                .allowed_from_epoch
+                .map(|epoch| epoch.to_string()),
+        }
+    }
+}
+
 impl From<Withdraw> for WithdrawResponse {
     fn from(value: Withdraw) -> Self {
         WithdrawResponse {
             min_denom_amount: value.min_denom_amount.to_string(),
             validator: ValidatorWithRankResponse::from(value.validator),
             withdraw_epoch: value.withdraw_epoch.to_string(),
+            withdrawn_at_height: value
+                .withdrawn_at_height
+                .map(|height| height.to_string()),
+            withdrawn_at_epoch: value
+                .withdrawn_at_epoch
+                .map(|epoch| epoch.to_string()),
         }
     }
 }
@@ -196,6 +388,53 @@ impl From<Reward> for RewardResponse {
         RewardResponse {
             min_denom_amount: value.min_denom_amount.to_string(),
             validator: ValidatorWithRankResponse::from(value.validator),
+            claimed: value.claimed,
+            claim_tx_id: value.claim_tx_id,
+            claimed_at_height: value.claimed_at_height.map(|h| h.to_string()),
+        }
+    }
+}
+
+impl From<RewardClaim> for RewardClaimResponse {
+    fn from(value: RewardClaim) -> Self {
+        RewardClaimResponse {
+            tx_id: value.tx_id,
+            block_height: value.block_height.to_string(),
+            timestamp: value.timestamp.map(|t| t.to_string()),
+            epoch: value.epoch.map(|epoch| epoch.to_string()),
+            min_denom_amount: value.min_denom_amount.to_string(),
+            validator: ValidatorWithRankResponse::from(value.validator),
+        }
+    }
+}
+
+impl From<ValidatorRewardLedger> for ValidatorRewardLedgerResponse {
+    fn from(value: ValidatorRewardLedger) -> Self {
+        ValidatorRewardLedgerResponse {
+            validator: ValidatorWithRankResponse::from(value.validator),
+            earned: value.earned.to_string(),
+            claimed: value.claimed.to_string(),
+            pending: value.pending.to_string(),
+        }
+    }
+}
+
+impl From<RewardLedger> for RewardLedgerResponse {
+    fn from(value: RewardLedger) -> Self {
+        RewardLedgerResponse {
+            total_earned: value.total_earned.to_string(),
+            total_claimed: value.total_claimed.to_string(),
+            total_pending: value.total_pending.to_string(),
+            validators: value
+                .validators
+                .into_iter()
+                .map(ValidatorRewardLedgerResponse::from)
+                .collect(),
+            claims: value
+                .claims
+                .into_iter()
+                .map(RewardClaimResponse::from)
+                .collect(),
         }
     }
 }
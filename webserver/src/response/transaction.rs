@@ -2,8 +2,10 @@ use serde::{Deserialize, Serialize};
 
 use super::chain::TokenResponse;
 use crate::entity::transaction::{
-    InnerTransaction, TransactionExitStatus, TransactionHistory,
-    TransactionHistoryKind, TransactionKind, WrapperTransaction,
+    DryRunBalanceChange, DryRunInnerStatus, DryRunInnerTransaction,
+    InnerTransaction, TransactionDryRun, TransactionExitStatus,
+    TransactionHistory, TransactionHistoryKind, TransactionKind,
+    WrapperTransaction,
 };
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -229,3 +231,95 @@ impl From<TransactionHistory> for TransactionHistoryResponse {
         }
     }
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DryRunInnerStatusResponse {
+    Accepted,
+    Rejected,
+    Failed,
+}
+
+impl From<DryRunInnerStatus> for DryRunInnerStatusResponse {
+    fn from(value: DryRunInnerStatus) -> Self {
+        match value {
+            DryRunInnerStatus::Accepted => Self::Accepted,
+            DryRunInnerStatus::Rejected => Self::Rejected,
+            DryRunInnerStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunBalanceChangeResponse {
+    pub owner: String,
+    pub token: String,
+}
+
+impl From<DryRunBalanceChange> for DryRunBalanceChangeResponse {
+    fn from(value: DryRunBalanceChange) -> Self {
+        Self {
+            owner: value.owner.to_string(),
+            token: value.token.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunInnerTransactionResponse {
+    pub tx_id: String,
+    pub status: DryRunInnerStatusResponse,
+    pub errors: Vec<String>,
+    pub rejected_vps: Vec<String>,
+    pub initialized_accounts: Vec<String>,
+    pub changed_keys: Vec<String>,
+    pub balance_changes: Vec<DryRunBalanceChangeResponse>,
+}
+
+impl From<DryRunInnerTransaction> for DryRunInnerTransactionResponse {
+    fn from(value: DryRunInnerTransaction) -> Self {
+        Self {
+            tx_id: value.id.to_string(),
+            status: value.status.into(),
+            errors: value.errors,
+            rejected_vps: value
+                .rejected_vps
+                .into_iter()
+                .map(|id| id.to_string())
+                .collect(),
+            initialized_accounts: value
+                .initialized_accounts
+                .into_iter()
+                .map(|id| id.to_string())
+                .collect(),
+            changed_keys: value.changed_keys,
+            balance_changes: value
+                .balance_changes
+                .into_iter()
+                .map(DryRunBalanceChangeResponse::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionDryRunResponse {
+    pub gas_used: u64,
+    pub inner_transactions: Vec<DryRunInnerTransactionResponse>,
+}
+
+impl From<TransactionDryRun> for TransactionDryRunResponse {
+    fn from(value: TransactionDryRun) -> Self {
+        Self {
+            gas_used: value.gas_used,
+            inner_transactions: value
+                .inner_transactions
+                .into_iter()
+                .map(DryRunInnerTransactionResponse::from)
+                .collect(),
+        }
+    }
+}
@@ -1,22 +1,50 @@
-use bigdecimal::{BigDecimal, ToPrimitive};
-use shared::id::Id;
-use shared::token::{IbcToken, Token};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bigdecimal::{BigDecimal, Zero};
+use namada_sdk::address::{Address, GOV, IBC, MASP, PGF, POS, POS_SLASH_POOL};
+use shared::token::Token;
 
 use crate::appstate::AppState;
-use crate::entity::chain::{CirculatingSupply, Parameters, TokenSupply};
+use crate::entity::chain::{
+    CirculatingSupply, CirculatingSupplyBreakdown, EpochInfo, LockedSupplyKind,
+    NativeInflation, Parameters, TokenHolders, TokenMetadata, TokenSupply,
+};
 use crate::error::chain::ChainError;
 use crate::repository::balance::{BalanceRepo, BalanceRepoTrait};
 use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
+use crate::repository::pos::{PosRepository, PosRepositoryTrait};
+use crate::token_registry::TokenRegistry;
+
+/// Number of indexed epochs used to project the start of other epochs
+const OBSERVED_EPOCHS: i64 = 10;
+
+/// Protocol addresses holding tokens on behalf of users, e.g. bonds, shielded
+/// and IBC escrowed balances
+const PROTOCOL_ADDRESSES: [Address; 6] =
+    [POS, POS_SLASH_POOL, PGF, MASP, IBC, GOV];
 
 #[derive(Clone)]
 pub struct ChainService {
     chain_repo: ChainRepository,
+    balance_repo: BalanceRepo,
+    pos_repo: PosRepository,
+    token_registry: Arc<TokenRegistry>,
+    locked_addresses: Arc<Vec<String>>,
 }
 
 impl ChainService {
-    pub fn new(app_state: AppState) -> Self {
+    pub fn new(
+        app_state: AppState,
+        token_registry: TokenRegistry,
+        locked_addresses: Vec<String>,
+    ) -> Self {
         Self {
-            chain_repo: ChainRepository::new(app_state),
+            chain_repo: ChainRepository::new(app_state.clone()),
+            balance_repo: BalanceRepo::new(app_state.clone()),
+            pos_repo: PosRepository::new(app_state),
+            token_registry: Arc::new(token_registry),
+            locked_addresses: Arc::new(locked_addresses),
         }
     }
 
@@ -47,7 +75,56 @@ impl ChainService {
             .map_err(ChainError::Database)
     }
 
+    pub async fn find_epoch(
+        &self,
+        epoch: u32,
+    ) -> Result<EpochInfo, ChainError> {
+        // Epochs past the range of the db can not have been indexed
+        let db_epoch = match i32::try_from(epoch) {
+            Ok(epoch) => self
+                .chain_repo
+                .find_epoch(epoch)
+                .await
+                .map_err(ChainError::Database)?,
+            Err(_) => None,
+        };
+
+        let latest_epochs = self
+            .chain_repo
+            .find_latest_epochs(OBSERVED_EPOCHS)
+            .await
+            .map_err(ChainError::Database)?;
+
+        let parameters = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map_err(ChainError::Database)?;
+
+        EpochInfo::from_db(
+            epoch,
+            db_epoch,
+            &latest_epochs,
+            parameters.min_duration,
+            parameters.min_num_of_blocks,
+        )
+        .ok_or(ChainError::EpochNotFound(epoch))
+    }
+
     pub async fn find_tokens(&self) -> Result<Vec<Token>, ChainError> {
+        let tokens = self
+            .find_tokens_metadata()
+            .await?
+            .into_iter()
+            .map(|metadata| metadata.token)
+            .collect::<Vec<Token>>();
+
+        Ok(tokens)
+    }
+
+    pub async fn find_tokens_metadata(
+        &self,
+    ) -> Result<Vec<TokenMetadata>, ChainError> {
         let tokens_db = self
             .chain_repo
             .find_tokens()
@@ -56,18 +133,75 @@ impl ChainService {
 
         let tokens = tokens_db
             .into_iter()
-            .map(|(token, ibc_token)| match ibc_token {
-                Some(ibc_token) => Token::Ibc(IbcToken {
-                    address: Id::Account(ibc_token.address),
-                    trace: Some(Id::IbcTrace(ibc_token.ibc_trace)),
-                }),
-                None => Token::Native(Id::Account(token.address)),
+            .map(|(token, ibc_token)| {
+                TokenMetadata::from_db(token, ibc_token, &self.token_registry)
             })
-            .collect::<Vec<Token>>();
+            .collect();
 
         Ok(tokens)
     }
 
+    pub async fn get_token_holders(
+        &self,
+        address: String,
+        exclude_protocol: bool,
+        page: u64,
+    ) -> Result<(TokenHolders, u64, u64), ChainError> {
+        let metadata = self
+            .find_tokens_metadata()
+            .await?
+            .into_iter()
+            .find(|metadata| metadata.token.to_string() == address)
+            .ok_or_else(|| ChainError::TokenNotFound(address.clone()))?;
+
+        let excluded_owners = if exclude_protocol {
+            PROTOCOL_ADDRESSES
+                .iter()
+                .map(|address| address.to_string())
+                .collect()
+        } else {
+            vec![]
+        };
+
+        let (db_holders, total_pages, total_items) = self
+            .balance_repo
+            .find_token_holders(
+                address.clone(),
+                excluded_owners.clone(),
+                page as i64,
+            )
+            .await
+            .map_err(ChainError::Database)?;
+
+        let db_distribution = self
+            .balance_repo
+            .find_token_holders_distribution(
+                address.clone(),
+                excluded_owners,
+                metadata.denomination.unwrap_or_default(),
+            )
+            .await
+            .map_err(ChainError::Database)?;
+
+        let total_supply = self
+            .chain_repo
+            .get_token_supply(address, None)
+            .await
+            .map_err(ChainError::Database)?
+            .map(|supply| supply.total);
+
+        Ok((
+            TokenHolders::from_db(
+                &metadata,
+                total_supply,
+                db_holders,
+                db_distribution,
+            ),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
     pub async fn get_token_supply(
         &self,
         address: String,
@@ -75,77 +209,208 @@ impl ChainService {
     ) -> Result<Option<TokenSupply>, ChainError> {
         let maybe_token_supply_db = self
             .chain_repo
-            .get_token_supply(address, epoch)
+            .get_token_supply(address.clone(), epoch)
             .await
             .map_err(ChainError::Database)?;
 
-        Ok(maybe_token_supply_db.map(|supply| TokenSupply {
-            address: Id::Account(supply.address),
-            total_supply: supply.total.to_u64().expect("Should fit in u64"),
-            effective_supply: supply
-                .effective
-                .map(|s| s.to_u64().expect("Should fit in u64")),
-        }))
+        let metadata = self
+            .find_tokens_metadata()
+            .await?
+            .into_iter()
+            .find(|metadata| metadata.token.to_string() == address);
+
+        Ok(maybe_token_supply_db
+            .map(|supply| TokenSupply::from_db(supply, metadata.as_ref())))
     }
 
-    pub async fn get_circulating_supply(
+    pub async fn get_token_supply_history(
         &self,
-        epoch: Option<i32>,
-    ) -> Result<CirculatingSupply, ChainError> {
-        // Native token address and addresses to exclude from circulating supply
-        let native_token_address =
-            "tnam1q9gr66cvu4hrzm0sd5kmlnjje82gs3xlfg3v6nu7";
-        let excluded_addresses = vec![
-            "tnam1qxdzup2hcvhswcgw5kerd5lfkf04t64y3scgqm5v",
-            "tnam1qxt7uxhj9r00mfm4u870e7ghz6j20jrdz58gm5kj",
-            "tnam1qyez9fd9nkaxfj4u2f2k0vavr8mm69azcgds45rr",
-            "tnam1qqp69rzwsgnqdm0d4qfhw4qa4s6v3tlzm5069f4j",
-            "tnam1qrucghh3hw2zq8xtqzdj44nh5nrmnkn0usqng8yq",
-        ];
+        address: String,
+        page: u64,
+    ) -> Result<(Vec<TokenSupply>, u64, u64), ChainError> {
+        let (db_supplies, total_pages, total_items) = self
+            .chain_repo
+            .find_token_supply_history(address.clone(), page as i64)
+            .await
+            .map_err(ChainError::Database)?;
 
-        // Get total supply of native token
-        let total_supply_result = self
+        let metadata = self
+            .find_tokens_metadata()
+            .await?
+            .into_iter()
+            .find(|metadata| metadata.token.to_string() == address);
+
+        let supplies = db_supplies
+            .into_iter()
+            .map(|supply| TokenSupply::from_db(supply, metadata.as_ref()))
+            .collect();
+
+        Ok((supplies, total_pages as u64, total_items as u64))
+    }
+
+    pub async fn get_native_inflation(
+        &self,
+        page: u64,
+    ) -> Result<(Vec<NativeInflation>, u64, u64), ChainError> {
+        let parameters = self.find_latest_parameters().await?;
+
+        let (db_inflation, total_pages, total_items) = self
             .chain_repo
-            .get_token_supply(native_token_address.to_string(), epoch)
+            .find_native_inflation(page as i64)
             .await
             .map_err(ChainError::Database)?;
 
-        let total_supply = total_supply_result.ok_or_else(|| {
-            ChainError::Unknown("Native token supply not found".to_string())
-        })?;
+        let epochs = db_inflation.iter().map(|inflation| inflation.epoch);
+        let (Some(from_epoch), Some(to_epoch)) =
+            (epochs.clone().min(), epochs.max())
+        else {
+            return Ok((vec![], total_pages as u64, total_items as u64));
+        };
 
-        // Get balances for all excluded addresses and sum them up
-        let balance_repo = BalanceRepo::new(self.chain_repo.app_state.clone());
-        let mut total_locked_amount = BigDecimal::from(0);
+        // Also load the supply of the epoch preceding the page, to compute
+        // the tokens minted in its first epoch
+        let supplies = self
+            .chain_repo
+            .find_token_supplies_between(
+                parameters.native_token_address.to_string(),
+                from_epoch - 1,
+                to_epoch,
+            )
+            .await
+            .map_err(ChainError::Database)?
+            .into_iter()
+            .map(|supply| (supply.epoch, supply.total))
+            .collect::<HashMap<_, _>>();
 
-        for address in excluded_addresses {
-            let balances = balance_repo
-                .get_address_balances(address.to_string())
-                .await
-                .map_err(ChainError::Database)?;
+        let inflation = db_inflation
+            .into_iter()
+            .map(|inflation| {
+                let total_supply = supplies.get(&inflation.epoch);
+                let previous_total_supply =
+                    supplies.get(&(inflation.epoch - 1));
 
-            // Find the balance of the native token for this excluded address
-            let locked_amount = balances
-                .iter()
-                .find(|balance| balance.token == native_token_address)
-                .map(|balance| balance.raw_amount.clone())
-                .unwrap_or_else(|| BigDecimal::from(0));
+                NativeInflation::from_db(
+                    inflation,
+                    total_supply,
+                    previous_total_supply,
+                    parameters.epochs_per_year,
+                )
+            })
+            .collect();
 
-            total_locked_amount += locked_amount;
-        }
+        Ok((inflation, total_pages as u64, total_items as u64))
+    }
+
+    pub async fn get_circulating_supply(
+        &self,
+        epoch: Option<i32>,
+    ) -> Result<CirculatingSupply, ChainError> {
+        let breakdown = self.circulating_supply_breakdown(epoch).await?;
+
+        Ok(CirculatingSupply {
+            circulating_supply: breakdown.circulating_supply.to_string(),
+        })
+    }
+
+    pub async fn get_circulating_supply_breakdown(
+        &self,
+    ) -> Result<CirculatingSupplyBreakdown, ChainError> {
+        self.circulating_supply_breakdown(None).await
+    }
+
+    /// Splits the native supply of the epoch, the latest one if not given,
+    /// into circulating and locked categories. PGF is the part of the total
+    /// supply excluded from the effective supply, bonded and unbonding come
+    /// from the staking statistics of the same epoch.
+    async fn circulating_supply_breakdown(
+        &self,
+        epoch: Option<i32>,
+    ) -> Result<CirculatingSupplyBreakdown, ChainError> {
+        let native_token_address = self
+            .find_latest_parameters()
+            .await?
+            .native_token_address
+            .to_string();
 
-        // Calculate circulating supply = effective supply - total locked amount
-        let effective_supply_amount =
-            total_supply.effective.ok_or_else(|| {
+        let total_supply = self
+            .chain_repo
+            .get_token_supply(native_token_address.clone(), epoch)
+            .await
+            .map_err(ChainError::Database)?
+            .ok_or_else(|| {
+                ChainError::Unknown("Native token supply not found".to_string())
+            })?;
+
+        let effective_supply =
+            total_supply.effective.clone().ok_or_else(|| {
                 ChainError::Unknown(
                     "Effective supply not found for native token".to_string(),
                 )
             })?;
-        let circulating_amount =
-            &effective_supply_amount - &total_locked_amount;
+        let pgf =
+            (&total_supply.total - effective_supply).max(BigDecimal::zero());
 
-        Ok(CirculatingSupply {
-            circulating_supply: circulating_amount.to_string(),
-        })
+        let (total_bonded, total_unbonding) = self
+            .pos_repo
+            .find_pos_stats(Some(total_supply.epoch))
+            .await
+            .map_err(ChainError::Database)?
+            .map(|stats| (stats.total_bonded, stats.unbonding_queue))
+            .unwrap_or_default();
+
+        let protocol_accounts = [
+            (LockedSupplyKind::Shielded, MASP),
+            (LockedSupplyKind::IbcEscrow, IBC),
+        ];
+
+        let owners = protocol_accounts
+            .iter()
+            .map(|(_, address)| address.to_string())
+            .chain(self.locked_addresses.iter().cloned())
+            .collect();
+
+        let balances = self
+            .balance_repo
+            .find_token_balances_by_owners(native_token_address, owners)
+            .await
+            .map_err(ChainError::Database)?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        let accounts = |addresses: Vec<String>| {
+            let accounts = addresses
+                .into_iter()
+                .map(|address| {
+                    let amount =
+                        balances.get(&address).cloned().unwrap_or_default();
+                    (address, amount)
+                })
+                .collect::<Vec<_>>();
+            let amount = accounts
+                .iter()
+                .map(|(_, amount)| amount)
+                .sum::<BigDecimal>();
+
+            (amount, accounts)
+        };
+
+        let mut locked = vec![
+            (LockedSupplyKind::Bonded, total_bonded, vec![]),
+            (LockedSupplyKind::Unbonding, total_unbonding, vec![]),
+            (LockedSupplyKind::Pgf, pgf, vec![]),
+        ];
+        locked.extend(protocol_accounts.into_iter().map(|(kind, address)| {
+            let (amount, accounts) = accounts(vec![address.to_string()]);
+            (kind, amount, accounts)
+        }));
+        let (vesting, vesting_accounts) =
+            accounts(self.locked_addresses.to_vec());
+        locked.push((LockedSupplyKind::Vesting, vesting, vesting_accounts));
+
+        Ok(CirculatingSupplyBreakdown::new(
+            total_supply.epoch,
+            total_supply.total,
+            locked,
+        ))
     }
 }
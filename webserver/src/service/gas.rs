@@ -1,7 +1,12 @@
+use std::sync::Arc;
+
 use bigdecimal::ToPrimitive;
+use tokio::sync::RwLock;
 
 use crate::appstate::AppState;
-use crate::entity::gas::{GasEstimate, GasPrice};
+use crate::entity::gas::{
+    GasBatch, GasEstimate, GasEstimateInterval, GasModel, GasPrice,
+};
 use crate::entity::transaction::TransactionKind;
 use crate::error::gas::GasError;
 use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
@@ -12,14 +17,23 @@ pub struct GasService {
     gas_repo: GasRepository,
     chain_repo: ChainRepository,
     default_gas_table: DefaultGasTable,
+    gas_model: Arc<RwLock<CachedGasModel>>,
 }
 
+// Gas model together with the epoch it was fitted at, `None` if there were
+// not enough samples to fit it
+type CachedGasModel = Option<(i32, Option<GasModel>)>;
+
+// Number of most recent wrapper transactions the gas model is fitted over
+const GAS_MODEL_SAMPLES: i64 = 10_000;
+
 impl GasService {
     pub fn new(app_state: AppState) -> Self {
         Self {
             gas_repo: GasRepository::new(app_state.clone()),
             chain_repo: ChainRepository::new(app_state),
             default_gas_table: DefaultGasTable::default(),
+            gas_model: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -185,6 +199,67 @@ impl GasService {
             })
         }
     }
+
+    pub async fn estimate_gas_for_batch(
+        &self,
+        batch: GasBatch,
+    ) -> Result<GasEstimateInterval, GasError> {
+        let fallback = |kind: &TransactionKind| {
+            self.default_gas_table.get_gas_by_tx_kind(kind.clone())
+        };
+
+        let estimate = match self.get_gas_model().await? {
+            Some(model) => model.predict(&batch, fallback),
+            None => GasEstimateInterval::from_defaults(&batch, fallback),
+        };
+
+        Ok(estimate)
+    }
+
+    /// Returns the gas model, refitting it once per epoch
+    async fn get_gas_model(&self) -> Result<Option<GasModel>, GasError> {
+        let epoch = self
+            .chain_repo
+            .find_latest_epoch()
+            .await
+            .map_err(GasError::Database)?;
+
+        if let Some((_, model)) = self
+            .gas_model
+            .read()
+            .await
+            .as_ref()
+            .filter(|(model_epoch, _)| *model_epoch == epoch)
+        {
+            return Ok(model.clone());
+        }
+
+        let mut gas_model = self.gas_model.write().await;
+
+        // Another request might have refitted the model in the meantime
+        if let Some((_, model)) = gas_model
+            .as_ref()
+            .filter(|(model_epoch, _)| *model_epoch == epoch)
+        {
+            return Ok(model.clone());
+        }
+
+        let samples = self
+            .gas_repo
+            .find_gas_model_samples(GAS_MODEL_SAMPLES)
+            .await
+            .map_err(GasError::Database)?
+            .iter()
+            .map(|(gas_estimation, gas_used)| {
+                (GasBatch::from_db(gas_estimation), *gas_used as f64)
+            })
+            .collect();
+
+        let model = GasModel::fit(epoch, samples);
+        *gas_model = Some((epoch, model.clone()));
+
+        Ok(model)
+    }
 }
 
 #[derive(Debug, Clone, Default)]
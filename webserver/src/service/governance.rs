@@ -1,3 +1,7 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
 use orm::governance_proposal::{
     GovernanceProposalKindDb, GovernanceProposalResultDb,
 };
@@ -6,24 +10,40 @@ use subtle_encoding::hex;
 
 use crate::appstate::AppState;
 use crate::dto::governance::{ProposalKind, ProposalStatus};
+use crate::dto::pos::ValidatorStateDto;
 use crate::entity::governance::{
-    Proposal, ProposalData, ProposalType, ProposalVote,
+    self, EpochTime, ParticipationStatus, Proposal, ProposalData,
+    ProposalExecution, ProposalSearchResult, ProposalSubmission, ProposalTally,
+    ProposalTallyProjection, ProposalTimeline, ProposalType, ProposalVote,
+    ProposalVoteCount, TallyType, ValidatorParticipation, VoterParticipation,
 };
+use crate::entity::pgf::PgfPayment;
+use crate::entity::pos::Validator;
 use crate::error::governance::GovernanceError;
+use crate::repository::block::{BlockRepository, BlockRepositoryTrait};
 use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
 use crate::repository::governance::{GovernanceRepo, GovernanceRepoTrait};
+use crate::repository::pgf::{PgfRepo, PgfRepoTrait};
+use crate::repository::pos::{PosRepository, PosRepositoryTrait};
+use crate::service::pos::PosService;
 
 #[derive(Clone)]
 pub struct GovernanceService {
     governance_repo: GovernanceRepo,
     chain_repo: ChainRepository,
+    block_repo: BlockRepository,
+    pgf_repo: PgfRepo,
+    pos_repo: PosRepository,
 }
 
 impl GovernanceService {
     pub fn new(app_state: AppState) -> Self {
         Self {
             governance_repo: GovernanceRepo::new(app_state.clone()),
-            chain_repo: ChainRepository::new(app_state),
+            chain_repo: ChainRepository::new(app_state.clone()),
+            block_repo: BlockRepository::new(app_state.clone()),
+            pgf_repo: PgfRepo::new(app_state.clone()),
+            pos_repo: PosRepository::new(app_state),
         }
     }
 
@@ -72,6 +92,56 @@ impl GovernanceService {
         ))
     }
 
+    pub async fn search_governance_proposals(
+        &self,
+        query: String,
+        page: u64,
+    ) -> Result<(Vec<ProposalSearchResult>, u64, u64), GovernanceError> {
+        let query = query.trim().to_string();
+        if query.len() < 3 {
+            return Err(GovernanceError::TooShortPattern(query.len()));
+        }
+
+        let (db_results, total_pages, total_items) = self
+            .governance_repo
+            .search_governance_proposals(query, page as i64)
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let chain_state = self
+            .chain_repo
+            .get_state()
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let parameters = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        Ok((
+            db_results
+                .into_iter()
+                .map(|(p, rank, title_highlight, abstract_highlight)| {
+                    ProposalSearchResult {
+                        proposal: Proposal::from_db(
+                            p,
+                            &chain_state,
+                            parameters.max_block_time,
+                            parameters.min_duration,
+                        ),
+                        rank,
+                        title_highlight,
+                        abstract_highlight,
+                    }
+                })
+                .collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
     pub async fn find_proposal_data(
         &self,
         proposal_id: u64,
@@ -88,15 +158,7 @@ impl GovernanceService {
             return Err(GovernanceError::NotFound(proposal_id));
         };
 
-        let hash = match data.kind {
-            GovernanceProposalKindDb::DefaultWithWasm => {
-                let hex_decoded_bytes =
-                    hex::decode(data.data.clone().unwrap_or_default())
-                        .unwrap_or_default();
-                Some(digest(hex_decoded_bytes))
-            }
-            _ => None,
-        };
+        let hash = self.wasm_code_hash(&data.kind, data.data.as_deref());
 
         let proposal_data = ProposalData {
             data: data.data,
@@ -216,6 +278,319 @@ impl GovernanceService {
             .collect())
     }
 
+    pub async fn find_governance_proposal_timeline(
+        &self,
+        proposal_id: u64,
+    ) -> Result<ProposalTimeline, GovernanceError> {
+        let proposal = self
+            .governance_repo
+            .find_governance_proposal_with_data_by_id(proposal_id as i32)
+            .await
+            .map_err(GovernanceError::Database)?
+            .ok_or(GovernanceError::NotFound(proposal_id))?;
+
+        let chain_state = self
+            .chain_repo
+            .get_state()
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let parameters = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let submission_block = match proposal.init_height {
+            Some(height) => self
+                .block_repo
+                .find_block_by_height(height)
+                .await
+                .map_err(GovernanceError::Database)?,
+            None => None,
+        };
+
+        let epoch_blocks = self
+            .block_repo
+            .find_first_blocks_in_epochs(vec![
+                proposal.start_epoch,
+                proposal.end_epoch,
+                proposal.activation_epoch,
+            ])
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let vote_counts = self
+            .governance_repo
+            .find_governance_proposal_vote_counts_by_epoch(proposal.id)
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let epoch_time = |epoch: i32| {
+            EpochTime::from_db(
+                epoch,
+                epoch_blocks.iter().find(|block| block.epoch == Some(epoch)),
+                &chain_state,
+                parameters.max_block_time,
+                parameters.min_duration,
+            )
+        };
+
+        let status = governance::ProposalStatus::from(proposal.result);
+
+        let execution = match proposal.kind {
+            GovernanceProposalKindDb::DefaultWithWasm
+            | GovernanceProposalKindDb::PgfSteward => {
+                Some(ProposalExecution::new(
+                    &status,
+                    epoch_time(proposal.activation_epoch),
+                    self.wasm_code_hash(
+                        &proposal.kind,
+                        proposal.data.as_deref(),
+                    ),
+                    vec![],
+                ))
+            }
+            GovernanceProposalKindDb::PgfFunding => {
+                let pgf_payments = self
+                    .pgf_repo
+                    .find_pgf_payments_by_proposal_id(proposal.id)
+                    .await
+                    .map_err(GovernanceError::Database)?
+                    .into_iter()
+                    .map(PgfPayment::from)
+                    .collect();
+
+                Some(ProposalExecution::new(
+                    &status,
+                    epoch_time(proposal.activation_epoch),
+                    None,
+                    pgf_payments,
+                ))
+            }
+            GovernanceProposalKindDb::Default => None,
+        };
+
+        Ok(ProposalTimeline {
+            id: proposal.id as u64,
+            r#type: ProposalType::from(proposal.kind),
+            status,
+            submission: ProposalSubmission::from_db(
+                proposal.init_tx_id,
+                proposal.init_height,
+                submission_block,
+            ),
+            voting_start: epoch_time(proposal.start_epoch),
+            voting_end: epoch_time(proposal.end_epoch),
+            votes: ProposalVoteCount::from_db(vote_counts),
+            tally: ProposalTally::from_db(
+                proposal.tally_type,
+                &proposal.yay_votes,
+                &proposal.nay_votes,
+                &proposal.abstain_votes,
+                proposal.total_voting_power.as_deref(),
+            )
+            .map_err(|e| GovernanceError::InvalidVotingPower(e.to_string()))?,
+            execution,
+        })
+    }
+
+    pub async fn project_governance_proposal_tally(
+        &self,
+        proposal_id: u64,
+    ) -> Result<ProposalTallyProjection, GovernanceError> {
+        let proposal = self
+            .governance_repo
+            .find_governance_proposals_by_id(proposal_id as i32)
+            .await
+            .map_err(GovernanceError::Database)?
+            .ok_or(GovernanceError::NotFound(proposal_id))?;
+
+        let parameters = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let votes = self
+            .governance_repo
+            .find_all_governance_proposal_votes(proposal.id)
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let stakes = self
+            .governance_repo
+            .find_active_stakes_at_epoch(
+                proposal.start_epoch,
+                parameters.unbonding_length,
+            )
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let total_voting_power = proposal
+            .total_voting_power
+            .as_deref()
+            .map(BigDecimal::from_str)
+            .transpose()
+            .map_err(|e| GovernanceError::InvalidVotingPower(e.to_string()))?;
+
+        Ok(ProposalTallyProjection::compute(
+            proposal.id as u64,
+            proposal.start_epoch as u64,
+            TallyType::from(proposal.tally_type),
+            votes,
+            stakes,
+            total_voting_power,
+        ))
+    }
+
+    /// Participation of validators in the proposals whose voting period has
+    /// ended and started while they were active, most active validators first
+    pub async fn get_validators_participation(
+        &self,
+        states: Vec<ValidatorStateDto>,
+    ) -> Result<Vec<ValidatorParticipation>, GovernanceError> {
+        let states = states
+            .into_iter()
+            .map(PosService::to_validator_state_db)
+            .collect();
+
+        let validators = self
+            .pos_repo
+            .find_all_validators(states)
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let chain_state = self
+            .chain_repo
+            .get_state()
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let mut eligible_proposals = self
+            .governance_repo
+            .find_validator_eligible_proposal_counts(
+                chain_state.last_processed_epoch,
+            )
+            .await
+            .map_err(GovernanceError::Database)?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        let mut vote_counts = self
+            .governance_repo
+            .find_validator_vote_counts_ended_before(
+                chain_state.last_processed_epoch,
+            )
+            .await
+            .map_err(GovernanceError::Database)?
+            .into_iter()
+            .fold(
+                HashMap::<String, Vec<_>>::new(),
+                |mut acc, (address, kind, count)| {
+                    acc.entry(address).or_default().push((kind, count));
+                    acc
+                },
+            );
+
+        let mut participations = validators
+            .into_iter()
+            .map(|validator| {
+                let counts = vote_counts
+                    .remove(&validator.namada_address)
+                    .unwrap_or_default();
+                let eligible_proposals = eligible_proposals
+                    .remove(&validator.namada_address)
+                    .unwrap_or_default();
+
+                ValidatorParticipation {
+                    validator: Validator::from(validator),
+                    participation: VoterParticipation::from_db(
+                        eligible_proposals,
+                        counts,
+                    ),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        participations.sort_by(|a, b| {
+            b.participation
+                .participation_rate
+                .total_cmp(&a.participation.participation_rate)
+                .then(b.validator.voting_power.cmp(&a.validator.voting_power))
+        });
+
+        Ok(participations)
+    }
+
+    /// Validators that did not vote on any proposal whose voting period has
+    /// ended, by voting power
+    pub async fn get_non_voting_validators(
+        &self,
+        states: Vec<ValidatorStateDto>,
+    ) -> Result<Vec<ValidatorParticipation>, GovernanceError> {
+        let participations = self.get_validators_participation(states).await?;
+
+        let mut non_voting = participations
+            .into_iter()
+            .filter(|validator| {
+                validator.participation.status == ParticipationStatus::NonVoting
+            })
+            .collect::<Vec<_>>();
+
+        non_voting.sort_by(|a, b| {
+            b.validator.voting_power.cmp(&a.validator.voting_power)
+        });
+
+        Ok(non_voting)
+    }
+
+    pub async fn get_voter_participation(
+        &self,
+        voter_address: String,
+    ) -> Result<VoterParticipation, GovernanceError> {
+        let chain_state = self
+            .chain_repo
+            .get_state()
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let eligible_proposals = self
+            .governance_repo
+            .count_voter_eligible_proposals_ended_before(
+                voter_address.clone(),
+                chain_state.last_processed_epoch,
+            )
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let vote_counts = self
+            .governance_repo
+            .find_voter_vote_counts_ended_before(
+                voter_address,
+                chain_state.last_processed_epoch,
+            )
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        Ok(VoterParticipation::from_db(eligible_proposals, vote_counts))
+    }
+
+    fn wasm_code_hash(
+        &self,
+        kind: &GovernanceProposalKindDb,
+        data: Option<&str>,
+    ) -> Option<String> {
+        match kind {
+            GovernanceProposalKindDb::DefaultWithWasm => {
+                let hex_decoded_bytes =
+                    hex::decode(data.unwrap_or_default()).unwrap_or_default();
+                Some(digest(hex_decoded_bytes))
+            }
+            _ => None,
+        }
+    }
+
     fn map_status(
         &self,
         status: Option<ProposalStatus>,
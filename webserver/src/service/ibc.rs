@@ -1,10 +1,16 @@
+use std::collections::{BTreeMap, HashMap};
+
 use bigdecimal::BigDecimal;
-use orm::ibc::IbcAckStatusDb;
+use chrono::{TimeDelta, Utc};
+use orm::ibc::IbcChannelStateDb;
 use shared::id::Id;
 
 use crate::appstate::AppState;
+use crate::dto::ibc::{IbcChannelStateDto, IbcTransferStatusDto};
 use crate::entity::ibc::{
-    IbcAck, IbcAckStatus, IbcRateLimit, IbcTokenFlow, IbcTokenThroughput,
+    IbcAck, IbcAckStatus, IbcChannel, IbcChannelTokenVolume,
+    IbcPendingTransfer, IbcRateLimit, IbcRateLimitUsage, IbcTokenFlow,
+    IbcTokenThroughput, IbcTransfer,
 };
 use crate::error::ibc::IbcError;
 use crate::repository::ibc::{IbcRepository, IbcRepositoryTrait};
@@ -31,12 +37,7 @@ impl IbcService {
             .map_err(IbcError::Database)
             .map(|ack| match ack {
                 Some(ack) => IbcAck {
-                    status: match ack.status {
-                        IbcAckStatusDb::Unknown => IbcAckStatus::Unknown,
-                        IbcAckStatusDb::Timeout => IbcAckStatus::Timeout,
-                        IbcAckStatusDb::Fail => IbcAckStatus::Fail,
-                        IbcAckStatusDb::Success => IbcAckStatus::Success,
-                    },
+                    status: IbcAckStatus::from(ack.status),
                     timeout: Some(ack.timeout),
                 },
                 None => IbcAck {
@@ -46,6 +47,186 @@ impl IbcService {
             })
     }
 
+    pub async fn find_ibc_transfers(
+        &self,
+        sender: Option<String>,
+        receiver: Option<String>,
+        channel: Option<String>,
+        status: Option<IbcTransferStatusDto>,
+        page: u64,
+    ) -> Result<(Vec<IbcTransfer>, u64, u64), IbcError> {
+        let (transfers, total_pages, total_items) = self
+            .ibc_repo
+            .find_ibc_transfers(sender, receiver, channel, status, page as i64)
+            .await
+            .map_err(IbcError::Database)?;
+
+        Ok((
+            transfers.into_iter().map(IbcTransfer::from).collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
+    pub async fn find_ibc_pending_transfers(
+        &self,
+        older_than: Option<u64>,
+        page: u64,
+    ) -> Result<(Vec<IbcPendingTransfer>, u64, u64), IbcError> {
+        let sent_before = older_than
+            .map(|older_than| {
+                i64::try_from(older_than)
+                    .ok()
+                    .and_then(TimeDelta::try_seconds)
+                    .and_then(|delta| {
+                        Utc::now().naive_utc().checked_sub_signed(delta)
+                    })
+                    .ok_or(IbcError::InvalidOlderThan(older_than))
+            })
+            .transpose()?;
+
+        let (transfers, total_pages, total_items) = self
+            .ibc_repo
+            .find_ibc_pending_transfers(sent_before, page as i64)
+            .await
+            .map_err(IbcError::Database)?;
+
+        Ok((
+            transfers
+                .into_iter()
+                .map(|(transfer, sent_at)| {
+                    IbcPendingTransfer::from_db(transfer, sent_at)
+                })
+                .collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
+    pub async fn count_ibc_stuck_transfers(&self) -> Result<u64, IbcError> {
+        self.ibc_repo
+            .count_ibc_stuck_transfers()
+            .await
+            .map(|count| count as u64)
+            .map_err(IbcError::Database)
+    }
+
+    pub async fn find_ibc_channels(
+        &self,
+        state: Option<IbcChannelStateDto>,
+    ) -> Result<Vec<IbcChannel>, IbcError> {
+        self.get_channels(state.map(Self::to_channel_state_db), None)
+            .await
+    }
+
+    pub async fn find_ibc_channel(
+        &self,
+        channel_id: String,
+    ) -> Result<IbcChannel, IbcError> {
+        self.get_channels(None, Some(channel_id.clone()))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(IbcError::ChannelNotFound(channel_id))
+    }
+
+    async fn get_channels(
+        &self,
+        state: Option<IbcChannelStateDb>,
+        channel_id: Option<String>,
+    ) -> Result<Vec<IbcChannel>, IbcError> {
+        let channels = self
+            .ibc_repo
+            .find_ibc_channels(state, channel_id)
+            .await
+            .map_err(IbcError::Database)?;
+
+        let trace_flows = self
+            .ibc_repo
+            .get_ibc_trace_flows()
+            .await
+            .map_err(IbcError::Database)?;
+
+        let outflows = self
+            .ibc_repo
+            .get_ibc_channel_outflows()
+            .await
+            .map_err(IbcError::Database)?;
+
+        // Amounts of different tokens can't be added up, so the flows are
+        // kept per channel and per token
+        let mut channel_flows: HashMap<
+            String,
+            BTreeMap<String, (BigDecimal, BigDecimal)>,
+        > = HashMap::new();
+
+        // Tokens received over ibc are traced back to the namada channel
+        // they came through, i.e. the first hop of their trace
+        for (trace, address, deposit) in trace_flows {
+            let mut hops = trace.splitn(3, '/');
+            let (Some(port_id), Some(channel_id), Some(_)) =
+                (hops.next(), hops.next(), hops.next())
+            else {
+                continue;
+            };
+
+            let entry = channel_flows
+                .entry(format!("{port_id}/{channel_id}"))
+                .or_default()
+                .entry(address)
+                .or_default();
+            entry.0 += deposit.unwrap_or_default();
+        }
+
+        // Every token sent over ibc, native ones included, is recorded with
+        // the channel it was sent through
+        for (port_id, channel_id, address, denom, withdraw) in outflows {
+            let Some(token) = address.or(denom) else {
+                continue;
+            };
+
+            let entry = channel_flows
+                .entry(format!("{port_id}/{channel_id}"))
+                .or_default()
+                .entry(token)
+                .or_default();
+            entry.1 += withdraw.unwrap_or_default();
+        }
+
+        Ok(channels
+            .into_iter()
+            .map(|(channel, connection, client)| {
+                let volumes = channel_flows
+                    .remove(&format!(
+                        "{}/{}",
+                        channel.port_id, channel.channel_id
+                    ))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(token, (deposit, withdraw))| IbcChannelTokenVolume {
+                        token,
+                        deposit,
+                        withdraw,
+                    })
+                    .collect();
+
+                IbcChannel::from_db(channel, connection, client, volumes)
+            })
+            .collect())
+    }
+
+    fn to_channel_state_db(state: IbcChannelStateDto) -> IbcChannelStateDb {
+        match state {
+            IbcChannelStateDto::Uninitialized => {
+                IbcChannelStateDb::Uninitialized
+            }
+            IbcChannelStateDto::Init => IbcChannelStateDb::Init,
+            IbcChannelStateDto::TryOpen => IbcChannelStateDb::TryOpen,
+            IbcChannelStateDto::Open => IbcChannelStateDb::Open,
+            IbcChannelStateDto::Closed => IbcChannelStateDb::Closed,
+        }
+    }
+
     pub async fn get_throughput_limits(
         &self,
         matching_token_address: Option<String>,
@@ -92,6 +273,36 @@ impl IbcService {
             })
     }
 
+    pub async fn find_rate_limit_usage(
+        &self,
+        token: String,
+        page: u64,
+    ) -> Result<(Vec<IbcRateLimitUsage>, u64, u64), IbcError> {
+        let (usage, total_pages, total_items) = self
+            .ibc_repo
+            .find_rate_limit_usage(token, page as i64)
+            .await
+            .map_err(IbcError::Database)?;
+
+        Ok((
+            usage.into_iter().map(IbcRateLimitUsage::from).collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
+    pub async fn get_rate_limit_headroom(
+        &self,
+        token: String,
+    ) -> Result<IbcRateLimitUsage, IbcError> {
+        self.ibc_repo
+            .find_latest_rate_limit_usage(token.clone())
+            .await
+            .map_err(IbcError::Database)?
+            .map(IbcRateLimitUsage::from)
+            .ok_or(IbcError::RateLimitNotFound(token))
+    }
+
     pub async fn get_token_throughput(
         &self,
         token: String,
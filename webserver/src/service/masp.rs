@@ -1,21 +1,32 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use bigdecimal::{BigDecimal, Signed};
 use shared::balance::Amount;
 use shared::id::Id;
 use shared::masp::MaspRewardData;
 
 use crate::appstate::AppState;
-use crate::entity::masp::MaspPoolAggregate;
+use crate::dto::masp::MaspPoolSeriesIntervalDto;
+use crate::entity::masp::{
+    MaspActivity, MaspPoolAggregate, MaspPoolSeries, MaspRewardEstimate,
+    MaspRewardHistory,
+};
 use crate::error::masp::MaspError;
+use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
 use crate::repository::masp::{MaspRepository, MaspRepositoryTrait};
 
 #[derive(Clone)]
 pub struct MaspService {
     pub masp_repo: MaspRepository,
+    chain_repo: ChainRepository,
 }
 
 impl MaspService {
     pub fn new(app_state: AppState) -> Self {
         Self {
-            masp_repo: MaspRepository::new(app_state),
+            masp_repo: MaspRepository::new(app_state.clone()),
+            chain_repo: ChainRepository::new(app_state),
         }
     }
 
@@ -67,4 +78,171 @@ impl MaspService {
                     .collect()
             })
     }
+
+    pub async fn find_masp_pool_series(
+        &self,
+        token: String,
+        interval: MaspPoolSeriesIntervalDto,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> Result<MaspPoolSeries, MaspError> {
+        if let (Some(from), Some(to)) = (from, to) {
+            if from > to {
+                return Err(MaspError::InvalidRange(from, to));
+            }
+        }
+
+        let (opening, series) = self
+            .masp_repo
+            .find_masp_pool_series(token.clone(), interval, from, to)
+            .await
+            .map_err(MaspError::Database)?;
+
+        Ok(MaspPoolSeries::from_db(token, opening, series))
+    }
+
+    pub async fn find_masp_rates_history(
+        &self,
+        token: String,
+        page: u64,
+    ) -> Result<(Vec<MaspRewardHistory>, u64, u64), MaspError> {
+        let (history, total_pages, total_items) = self
+            .masp_repo
+            .find_rates_history(token, page as i64)
+            .await
+            .map_err(MaspError::Database)?;
+
+        let native_token = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map_err(MaspError::Database)?
+            .native_token_address;
+
+        let history = history
+            .into_iter()
+            .map(|rates| MaspRewardHistory::from_db(rates, &native_token))
+            .collect();
+
+        Ok((history, total_pages as u64, total_items as u64))
+    }
+
+    pub async fn estimate_masp_rewards(
+        &self,
+        token: String,
+        amount: String,
+        epochs: u64,
+    ) -> Result<MaspRewardEstimate, MaspError> {
+        let amount = BigDecimal::from_str(&amount)
+            .ok()
+            .filter(|amount| !amount.is_negative())
+            .ok_or(MaspError::InvalidAmount(amount))?;
+
+        let rates = self
+            .masp_repo
+            .find_latest_rates_history(token.clone())
+            .await
+            .map_err(MaspError::Database)?
+            .ok_or(MaspError::RewardsNotFound(token))?;
+
+        let parameters = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map_err(MaspError::Database)?;
+
+        Ok(MaspRewardEstimate::new(
+            MaspRewardHistory::from_db(rates, &parameters.native_token_address),
+            amount,
+            epochs,
+            parameters.epochs_per_year as u64,
+        ))
+    }
+
+    pub async fn find_masp_activity(
+        &self,
+        page: u64,
+    ) -> Result<(Vec<MaspActivity>, u64, u64), MaspError> {
+        let (activity, total_pages, total_items) = self
+            .masp_repo
+            .find_masp_activity(page as i64)
+            .await
+            .map_err(MaspError::Database)?;
+
+        let epochs = activity.iter().filter_map(|(epoch, ..)| *epoch);
+        let (Some(from_epoch), Some(to_epoch)) =
+            (epochs.clone().min(), epochs.max())
+        else {
+            return Ok((vec![], total_pages as u64, total_items as u64));
+        };
+
+        let mut anonymity_set =
+            self.masp_repo
+                .count_masp_txs_before(from_epoch)
+                .await
+                .map_err(MaspError::Database)? as u64;
+
+        // Also load the tree of the epoch following the page, to compute the
+        // growth of its last epoch
+        let trees = self
+            .masp_repo
+            .find_commitment_trees_between(from_epoch, to_epoch + 1)
+            .await
+            .map_err(MaspError::Database)?
+            .into_iter()
+            .map(|tree| (tree.epoch, tree.size))
+            .collect::<HashMap<_, _>>();
+
+        // Epochs are sorted in descending order, the anonymity set is
+        // accumulated from the oldest one
+        let mut activity = activity
+            .into_iter()
+            .rev()
+            .map(
+                |(
+                    epoch,
+                    shielded,
+                    shielding,
+                    unshielding,
+                    mixed,
+                    ibc_shielding,
+                    ibc_unshielding,
+                    masp_wrappers,
+                    masp_fee_payments,
+                )| {
+                    let epoch = epoch.unwrap_or_default();
+                    let note_commitments = trees.get(&epoch).copied();
+                    let next_note_commitments =
+                        trees.get(&(epoch + 1)).copied();
+
+                    let mut activity = MaspActivity {
+                        epoch,
+                        shielded: shielded as u64,
+                        shielding: shielding as u64,
+                        unshielding: unshielding as u64,
+                        mixed: mixed as u64,
+                        ibc_shielding: ibc_shielding as u64,
+                        ibc_unshielding: ibc_unshielding as u64,
+                        masp_wrappers: masp_wrappers as u64,
+                        masp_fee_payments: masp_fee_payments as u64,
+                        note_commitments: note_commitments
+                            .map(|size| size as u64),
+                        new_note_commitments: next_note_commitments
+                            .zip(note_commitments)
+                            .map(|(next, size)| {
+                                next.saturating_sub(size) as u64
+                            }),
+                        anonymity_set_estimate: anonymity_set,
+                    };
+                    activity.anonymity_set_estimate += activity.total();
+                    anonymity_set = activity.anonymity_set_estimate;
+
+                    activity
+                },
+            )
+            .collect::<Vec<_>>();
+        activity.reverse();
+
+        Ok((activity, total_pages as u64, total_items as u64))
+    }
 }
@@ -1,8 +1,10 @@
-use orm::pgf::{PaymentKindDb, PaymentRecurrenceDb};
-use shared::id::Id;
+use std::collections::HashMap;
 
 use crate::appstate::AppState;
-use crate::entity::pgf::{PaymentKind, PaymentRecurrence, PgfPayment};
+use crate::entity::pgf::{
+    PgfContinuousPayment, PgfPayment, PgfRecipientTotal, PgfScheduledPayment,
+    PgfSteward,
+};
 use crate::error::pgf::PgfError;
 use crate::repository::pgf::{PgfRepo, PgfRepoTrait};
 
@@ -28,24 +30,7 @@ impl PgfService {
             .await
             .map_err(PgfError::Database)?;
 
-        let payments = payments
-            .into_iter()
-            .map(|payment| PgfPayment {
-                recurrence: match payment.payment_recurrence {
-                    PaymentRecurrenceDb::Continuous => {
-                        PaymentRecurrence::Continuous
-                    }
-                    PaymentRecurrenceDb::Retro => PaymentRecurrence::Retro,
-                },
-                proposal_id: payment.proposal_id as u64,
-                kind: match payment.payment_kind {
-                    PaymentKindDb::Ibc => PaymentKind::Ibc,
-                    PaymentKindDb::Native => PaymentKind::Native,
-                },
-                receipient: Id::Account(payment.receipient),
-                amount: payment.amount.into(),
-            })
-            .collect();
+        let payments = payments.into_iter().map(PgfPayment::from).collect();
 
         Ok((payments, total_pages as u64, total_items as u64))
     }
@@ -60,23 +45,144 @@ impl PgfService {
             .await
             .map_err(PgfError::Database)?
             .into_iter()
-            .map(|payment| PgfPayment {
-                recurrence: match payment.payment_recurrence {
-                    PaymentRecurrenceDb::Continuous => {
-                        PaymentRecurrence::Continuous
-                    }
-                    PaymentRecurrenceDb::Retro => PaymentRecurrence::Retro,
-                },
-                proposal_id: payment.proposal_id as u64,
-                kind: match payment.payment_kind {
-                    PaymentKindDb::Ibc => PaymentKind::Ibc,
-                    PaymentKindDb::Native => PaymentKind::Native,
+            .map(PgfPayment::from)
+            .collect();
+
+        Ok(payment)
+    }
+
+    pub async fn get_current_stewards(
+        &self,
+    ) -> Result<Vec<PgfSteward>, PgfError> {
+        let stewards = self
+            .pgf_repo
+            .find_latest_steward_states()
+            .await
+            .map_err(PgfError::Database)?
+            .into_iter()
+            .filter(|steward| steward.is_steward)
+            .map(PgfSteward::from)
+            .collect();
+
+        Ok(stewards)
+    }
+
+    pub async fn get_steward_history(
+        &self,
+        address: Option<String>,
+        page: u64,
+    ) -> Result<(Vec<PgfSteward>, u64, u64), PgfError> {
+        let (stewards, total_pages, total_items) = self
+            .pgf_repo
+            .find_steward_history(address, page as i64)
+            .await
+            .map_err(PgfError::Database)?;
+
+        let stewards = stewards.into_iter().map(PgfSteward::from).collect();
+
+        Ok((stewards, total_pages as u64, total_items as u64))
+    }
+
+    pub async fn get_active_continuous_payments(
+        &self,
+    ) -> Result<Vec<PgfContinuousPayment>, PgfError> {
+        let payments = self
+            .pgf_repo
+            .find_active_continuous_payments()
+            .await
+            .map_err(PgfError::Database)?;
+
+        let mut totals = self
+            .pgf_repo
+            .find_continuous_scheduled_totals()
+            .await
+            .map_err(PgfError::Database)?
+            .into_iter()
+            .map(
+                |(
+                    proposal_id,
+                    receipient,
+                    epochs_scheduled,
+                    total_scheduled,
+                )| {
+                    (
+                        (proposal_id, receipient),
+                        (epochs_scheduled, total_scheduled),
+                    )
                 },
-                receipient: Id::Account(payment.receipient),
-                amount: payment.amount.into(),
+            )
+            .collect::<HashMap<_, _>>();
+
+        let payments = payments
+            .into_iter()
+            .map(|(payment, activation_epoch)| {
+                let (epochs_scheduled, total_scheduled) = totals
+                    .remove(&(payment.proposal_id, payment.receipient.clone()))
+                    .unwrap_or((0, None));
+
+                PgfContinuousPayment::from_db(
+                    payment,
+                    activation_epoch,
+                    epochs_scheduled,
+                    total_scheduled,
+                )
             })
             .collect();
 
-        Ok(payment)
+        Ok(payments)
+    }
+
+    pub async fn get_scheduled_payments(
+        &self,
+        receipient: Option<String>,
+        epoch: Option<i32>,
+        page: u64,
+    ) -> Result<(Vec<PgfScheduledPayment>, u64, u64), PgfError> {
+        let (scheduled_payments, total_pages, total_items) = self
+            .pgf_repo
+            .find_scheduled_payments(receipient, epoch, page as i64)
+            .await
+            .map_err(PgfError::Database)?;
+
+        let scheduled_payments = scheduled_payments
+            .into_iter()
+            .map(PgfScheduledPayment::from)
+            .collect();
+
+        Ok((scheduled_payments, total_pages as u64, total_items as u64))
+    }
+
+    pub async fn get_recipient_totals(
+        &self,
+        page: u64,
+    ) -> Result<(Vec<PgfRecipientTotal>, u64, u64), PgfError> {
+        let (totals, total_pages, total_items) = self
+            .pgf_repo
+            .find_recipient_totals(page as i64)
+            .await
+            .map_err(PgfError::Database)?;
+
+        let totals = totals
+            .into_iter()
+            .map(
+                |(
+                    receipient,
+                    total_scheduled,
+                    scheduled_payments,
+                    first,
+                    last,
+                )| {
+                    PgfRecipientTotal::from_db(
+                        receipient,
+                        total_scheduled,
+                        scheduled_payments,
+                        first,
+                        last,
+                    )
+                },
+            )
+            .collect();
+
+        Ok((totals, total_pages as u64, total_items as u64))
     }
 }
@@ -1,14 +1,20 @@
+use std::collections::BTreeMap;
+
 use bigdecimal::{BigDecimal, Zero};
 use orm::helpers::OrderByDb;
-use orm::validators::{ValidatorSortByDb, ValidatorStateDb};
+use orm::validators::{ValidatorDb, ValidatorSortByDb, ValidatorStateDb};
 use shared::crawler_state::ChainCrawlerState;
 use shared::parameters::Parameters;
 
 use crate::appstate::AppState;
-use crate::dto::pos::{OrderByDto, ValidatorSortFieldDto, ValidatorStateDto};
+use crate::dto::pos::{
+    DelegatorSortFieldDto, OrderByDto, ValidatorSortFieldDto, ValidatorStateDto,
+};
 use crate::entity::pos::{
-    Bond, BondStatus, MergedBond, MergedBondRedelegation, Reward, Unbond,
-    ValidatorWithRank, Withdraw,
+    Bond, BondStatus, MergedBond, MergedBondRedelegation, PosStats,
+    Redelegation, RedelegationEligibility, Reward, RewardClaim, RewardLedger,
+    StakeDistribution, Unbond, UnbondingScheduleEntry, ValidatorDelegator,
+    ValidatorRewardLedger, ValidatorWithRank, Withdraw,
 };
 use crate::error::pos::PoSError;
 use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
@@ -187,6 +193,208 @@ impl PosService {
         Ok((bonds, total_pages as u64, total_items as u64))
     }
 
+    pub async fn get_redelegations_by_address(
+        &self,
+        address: String,
+        page: u64,
+    ) -> Result<(Vec<Redelegation>, u64, u64), PoSError> {
+        let (db_redelegations, total_pages, total_items) = self
+            .pos_repo
+            .find_redelegations_by_address(address, page as i64)
+            .await
+            .map_err(PoSError::Database)?;
+
+        let parameters = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map(Parameters::from)
+            .map_err(PoSError::Database)?;
+
+        let redelegations = db_redelegations
+            .into_iter()
+            .map(|(redelegation, src_validator, dest_validator)| {
+                Redelegation::from(
+                    redelegation,
+                    src_validator,
+                    dest_validator,
+                    &parameters,
+                )
+            })
+            .collect();
+
+        Ok((redelegations, total_pages as u64, total_items as u64))
+    }
+
+    pub async fn get_delegators_by_validator(
+        &self,
+        validator_address: String,
+        sort_field: Option<DelegatorSortFieldDto>,
+        sort_order: Option<OrderByDto>,
+        page: u64,
+    ) -> Result<(Vec<ValidatorDelegator>, u64, u64), PoSError> {
+        let validator = self
+            .pos_repo
+            .find_validator_by_address(validator_address.clone())
+            .await
+            .map_err(PoSError::Database)?
+            .ok_or(PoSError::ValidatorNotFound(validator_address))?;
+
+        let (db_delegators, total_pages, total_items) = self
+            .pos_repo
+            .find_delegators_by_validator(
+                validator.id,
+                sort_field.unwrap_or(DelegatorSortFieldDto::BondedAmount),
+                sort_order.unwrap_or(OrderByDto::Desc),
+                page as i64,
+            )
+            .await
+            .map_err(PoSError::Database)?;
+
+        let delegators = db_delegators
+            .into_iter()
+            .map(|(address, bonded, unbonding, start_epoch)| {
+                ValidatorDelegator::from(
+                    address,
+                    bonded,
+                    unbonding,
+                    start_epoch,
+                )
+            })
+            .collect();
+
+        Ok((delegators, total_pages as u64, total_items as u64))
+    }
+
+    pub async fn get_stake_distribution(
+        &self,
+        validator_address: String,
+        top: u64,
+    ) -> Result<StakeDistribution, PoSError> {
+        let validator = self
+            .pos_repo
+            .find_validator_by_address(validator_address.clone())
+            .await
+            .map_err(PoSError::Database)?
+            .ok_or(PoSError::ValidatorNotFound(validator_address))?;
+
+        let stakes = self
+            .pos_repo
+            .find_delegator_stakes_by_validator(validator.id)
+            .await
+            .map_err(PoSError::Database)?
+            .into_iter()
+            .map(Option::unwrap_or_default)
+            .collect();
+
+        Ok(StakeDistribution::compute(stakes, top))
+    }
+
+    pub async fn get_pos_stats(
+        &self,
+        epoch: Option<i32>,
+    ) -> Result<PosStats, PoSError> {
+        self.pos_repo
+            .find_pos_stats(epoch)
+            .await
+            .map_err(PoSError::Database)?
+            .map(PosStats::from)
+            .ok_or(PoSError::StatsNotFound)
+    }
+
+    pub async fn get_pos_stats_history(
+        &self,
+        page: u64,
+    ) -> Result<(Vec<PosStats>, u64, u64), PoSError> {
+        let (db_stats, total_pages, total_items) = self
+            .pos_repo
+            .find_pos_stats_history(page as i64)
+            .await
+            .map_err(PoSError::Database)?;
+
+        Ok((
+            db_stats.into_iter().map(PosStats::from).collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
+    pub async fn get_redelegation_eligibility(
+        &self,
+        delegator: String,
+        validator_address: String,
+    ) -> Result<RedelegationEligibility, PoSError> {
+        let validator = self
+            .pos_repo
+            .find_validator_by_address(validator_address.clone())
+            .await
+            .map_err(PoSError::Database)?
+            .ok_or(PoSError::ValidatorNotFound(validator_address))?;
+
+        let incoming_redelegation = self
+            .pos_repo
+            .find_incoming_redelegation(delegator, validator.id)
+            .await
+            .map_err(PoSError::Database)?;
+
+        let current_epoch = self
+            .chain_repo
+            .get_state()
+            .await
+            .map_err(PoSError::Database)?
+            .last_processed_epoch;
+
+        let parameters = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map(Parameters::from)
+            .map_err(PoSError::Database)?;
+
+        Ok(RedelegationEligibility::from(
+            incoming_redelegation,
+            current_epoch as u64,
+            &parameters,
+        ))
+    }
+
+    pub async fn get_unbonding_schedule(
+        &self,
+        address: Option<String>,
+    ) -> Result<Vec<UnbondingScheduleEntry>, PoSError> {
+        let chain_state = self
+            .chain_repo
+            .get_state()
+            .await
+            .map_err(PoSError::Database)?;
+
+        let parameters = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map_err(PoSError::Database)?;
+
+        let schedule = self
+            .pos_repo
+            .find_unbonding_schedule(address, chain_state.last_processed_epoch)
+            .await
+            .map_err(PoSError::Database)?
+            .into_iter()
+            .map(|(withdraw_epoch, raw_amount, unbonds)| {
+                UnbondingScheduleEntry::from(
+                    withdraw_epoch,
+                    raw_amount.unwrap_or_default(),
+                    unbonds,
+                    &chain_state,
+                    parameters.max_block_time,
+                    parameters.min_duration,
+                )
+            })
+            .collect();
+
+        Ok(schedule)
+    }
+
     pub async fn get_unbonds_by_address(
         &self,
         address: String,
@@ -282,6 +490,7 @@ impl PosService {
         &self,
         address: String,
         epoch: Option<u64>,
+        include_history: bool,
         page: u64,
     ) -> Result<(Vec<Withdraw>, u64, u64), PoSError> {
         let epoch = if let Some(epoch) = epoch {
@@ -296,7 +505,12 @@ impl PosService {
 
         let (db_withdraws, total_pages, total_items) = self
             .pos_repo
-            .find_withdraws_by_address(address, epoch, page as i64)
+            .find_withdraws_by_address(
+                address,
+                epoch,
+                include_history,
+                page as i64,
+            )
             .await
             .map_err(PoSError::Database)?;
 
@@ -312,10 +526,11 @@ impl PosService {
         &self,
         address: String,
         epoch: Option<u64>,
+        include_history: bool,
     ) -> Result<Vec<Reward>, PoSError> {
         let db_rewards = self
             .pos_repo
-            .find_rewards_by_address(address, epoch)
+            .find_rewards_by_address(address.clone(), epoch)
             .await
             .map_err(PoSError::Database)?;
 
@@ -333,6 +548,19 @@ impl PosService {
             }
         }
 
+        if include_history {
+            let db_claims = self
+                .pos_repo
+                .find_reward_claims_by_address(address)
+                .await
+                .map_err(PoSError::Database)?;
+            rewards.extend(db_claims.into_iter().map(
+                |(db_claim, db_validator, _)| {
+                    Reward::from_claim(db_claim, db_validator)
+                },
+            ));
+        }
+
         Ok(rewards)
     }
 
@@ -381,6 +609,71 @@ impl PosService {
         Ok(rewards)
     }
 
+    pub async fn get_reward_ledger_by_address(
+        &self,
+        address: String,
+    ) -> Result<RewardLedger, PoSError> {
+        let db_claims = self
+            .pos_repo
+            .find_reward_claims_by_address(address.clone())
+            .await
+            .map_err(PoSError::Database)?;
+
+        let db_pending = self
+            .pos_repo
+            .find_pending_rewards_by_address(address)
+            .await
+            .map_err(PoSError::Database)?;
+
+        // validator id -> (validator, claimed, pending)
+        let mut ledger: BTreeMap<i32, (ValidatorDb, BigDecimal, BigDecimal)> =
+            BTreeMap::new();
+
+        for (db_claim, db_validator, _) in &db_claims {
+            let entry = ledger.entry(db_validator.id).or_insert_with(|| {
+                (db_validator.clone(), BigDecimal::zero(), BigDecimal::zero())
+            });
+            entry.1 += &db_claim.raw_amount;
+        }
+
+        for (db_reward, db_validator) in db_pending {
+            let entry = ledger.entry(db_validator.id).or_insert_with(|| {
+                (db_validator.clone(), BigDecimal::zero(), BigDecimal::zero())
+            });
+            entry.2 += db_reward.raw_amount;
+        }
+
+        let total_claimed = ledger
+            .values()
+            .fold(BigDecimal::zero(), |acc, (_, claimed, _)| acc + claimed);
+        let total_pending = ledger
+            .values()
+            .fold(BigDecimal::zero(), |acc, (_, _, pending)| acc + pending);
+
+        let validators = ledger
+            .into_values()
+            .map(|(db_validator, claimed, pending)| {
+                ValidatorRewardLedger::from(claimed, pending, db_validator)
+            })
+            .collect();
+
+        let mut claims = db_claims
+            .into_iter()
+            .map(|(db_claim, db_validator, timestamp)| {
+                RewardClaim::from(db_claim, db_validator, timestamp)
+            })
+            .collect::<Vec<_>>();
+        claims.sort_by(|a, b| b.block_height.cmp(&a.block_height));
+
+        Ok(RewardLedger {
+            total_earned: (&total_claimed + &total_pending).into(),
+            total_claimed: total_claimed.into(),
+            total_pending: total_pending.into(),
+            validators,
+            claims,
+        })
+    }
+
     // TODO: maybe return object(struct) instead
     pub async fn get_total_voting_power(&self) -> Result<u64, PoSError> {
         let total_voting_power_db = self
@@ -393,7 +686,9 @@ impl PosService {
         Ok(total_voting_power_db.unwrap_or_default())
     }
 
-    fn to_validator_state_db(value: ValidatorStateDto) -> ValidatorStateDb {
+    pub(crate) fn to_validator_state_db(
+        value: ValidatorStateDto,
+    ) -> ValidatorStateDb {
         match value {
             ValidatorStateDto::Consensus => ValidatorStateDb::Consensus,
             ValidatorStateDto::BelowCapacity => ValidatorStateDb::BelowCapacity,
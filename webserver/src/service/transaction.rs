@@ -1,6 +1,11 @@
+use namada_sdk::io::client::Error as ClientError;
+use namada_sdk::queries::RPC;
+use namada_sdk::tendermint_rpc::HttpClient;
+use namada_sdk::tx::Tx;
+
 use crate::appstate::AppState;
 use crate::entity::transaction::{
-    InnerTransaction, TransactionHistory, WrapperTransaction,
+    InnerTransaction, TransactionDryRun, TransactionHistory, WrapperTransaction,
 };
 use crate::error::transaction::TransactionError;
 use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
@@ -109,4 +114,39 @@ impl TransactionService {
             .map(|w| WrapperTransaction::from_db(w, tokens.clone()))
             .collect())
     }
+
+    pub async fn dry_run_tx(
+        &self,
+        client: &HttpClient,
+        tx_bytes: Vec<u8>,
+    ) -> Result<TransactionDryRun, TransactionError> {
+        let tx = Tx::try_from_bytes(&tx_bytes)
+            .map_err(|e| TransactionError::InvalidTx(e.to_string()))?;
+
+        if tx.header().wrapper().is_none() {
+            return Err(TransactionError::InvalidTx(
+                "Only wrapper transactions can be dry run".to_string(),
+            ));
+        }
+
+        let result = RPC
+            .shell()
+            .dry_run_tx(client, Some(tx_bytes), None, false)
+            .await
+            .map_err(|e| match e {
+                // The node reports why the tx would fail in the query log
+                ClientError::Query(info, code) if info.is_empty() => {
+                    TransactionError::DryRunRejected(format!(
+                        "query failed with code {}",
+                        code
+                    ))
+                }
+                ClientError::Query(info, _) => {
+                    TransactionError::DryRunRejected(info)
+                }
+                e => TransactionError::Rpc(e.to_string()),
+            })?;
+
+        Ok(TransactionDryRun::from_result(result.data))
+    }
 }
@@ -16,6 +16,7 @@ use crate::service::pgf::PgfService;
 use crate::service::pos::PosService;
 use crate::service::revealed_pk::RevealedPkService;
 use crate::service::transaction::TransactionService;
+use crate::token_registry::TokenRegistry;
 
 #[derive(Clone)]
 pub struct CommonState {
@@ -36,13 +37,22 @@ pub struct CommonState {
 }
 
 impl CommonState {
-    pub fn new(client: HttpClient, config: AppConfig, data: AppState) -> Self {
+    pub fn new(
+        client: HttpClient,
+        config: AppConfig,
+        data: AppState,
+        token_registry: TokenRegistry,
+    ) -> Self {
         Self {
             block_service: BlockService::new(data.clone()),
             pos_service: PosService::new(data.clone()),
             gov_service: GovernanceService::new(data.clone()),
             balance_service: BalanceService::new(data.clone()),
-            chain_service: ChainService::new(data.clone()),
+            chain_service: ChainService::new(
+                data.clone(),
+                token_registry,
+                config.locked_addresses.clone(),
+            ),
             revealed_pk_service: RevealedPkService::new(data.clone()),
             gas_service: GasService::new(data.clone()),
             pgf_service: PgfService::new(data.clone()),
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::Context;
+use serde::Deserialize;
+
+/// Operator-supplied token metadata, read from an `assetlist.json` file in
+/// the chain-registry format. Only the fields overriding the metadata derived
+/// from the chain are kept.
+#[derive(Clone, Debug, Default)]
+pub struct TokenRegistry {
+    assets: HashMap<String, RegistryAsset>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RegistryAsset {
+    pub base: String,
+    pub address: Option<String>,
+    pub name: Option<String>,
+    pub display: Option<String>,
+    pub symbol: Option<String>,
+    #[serde(default)]
+    pub denom_units: Vec<RegistryDenomUnit>,
+    #[serde(rename = "logo_URIs")]
+    pub logo_uris: Option<RegistryLogoUris>,
+    pub coingecko_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RegistryDenomUnit {
+    pub denom: String,
+    pub exponent: u8,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RegistryLogoUris {
+    pub png: Option<String>,
+    pub svg: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AssetList {
+    assets: Vec<RegistryAsset>,
+}
+
+impl TokenRegistry {
+    pub fn load(path: Option<&Path>) -> anyhow::Result<Self> {
+        let Some(path) = path else {
+            return Ok(Self::default());
+        };
+
+        let content = std::fs::read_to_string(path).with_context(|| {
+            format!("Failed to read token registry {}", path.display())
+        })?;
+        let asset_list: AssetList = serde_json::from_str(&content)
+            .with_context(|| {
+                format!("Failed to parse token registry {}", path.display())
+            })?;
+
+        // Assets can be looked up both by their Namada address and by their
+        // base denom, which is the IBC trace for IBC tokens
+        let assets = asset_list
+            .assets
+            .into_iter()
+            .flat_map(|asset| {
+                let address = asset
+                    .address
+                    .clone()
+                    .map(|address| (address, asset.clone()));
+                let base = (asset.base.clone(), asset);
+
+                address.into_iter().chain(std::iter::once(base))
+            })
+            .collect();
+
+        Ok(Self { assets })
+    }
+
+    pub fn find(
+        &self,
+        address: &str,
+        trace: Option<&str>,
+    ) -> Option<&RegistryAsset> {
+        self.assets
+            .get(address)
+            .or_else(|| trace.and_then(|trace| self.assets.get(trace)))
+    }
+}
+
+impl RegistryAsset {
+    /// Exponent of the display unit of the asset
+    pub fn denomination(&self) -> Option<u8> {
+        let display = self.display.as_ref()?;
+
+        self.denom_units
+            .iter()
+            .find(|unit| &unit.denom == display)
+            .map(|unit| unit.exponent)
+    }
+
+    pub fn logo_uri(&self) -> Option<String> {
+        self.logo_uris
+            .as_ref()
+            .and_then(|uris| uris.png.clone().or_else(|| uris.svg.clone()))
+    }
+}